@@ -6,12 +6,11 @@ use phf::phf_set;
 
 pub(crate) static VALID_CALLEES: phf::Set<&'static str> = phf_set! {
 
-  "String", "Number", "Math", "Object", "Array"
+  "String", "Number", "Math", "Object", "Array", "parseInt", "parseFloat"
 };
 
 pub(crate) static INVALID_METHODS: phf::Set<&'static str> = phf_set! {
   "random",
-  "assign",
   "defineProperties",
   "defineProperty",
   "freeze",
@@ -44,7 +44,7 @@ pub(crate) static LONG_HAND_LOGICAL: phf::Set<&'static str> = phf_set! {
   "background-position-y",
 
   "border-block-color", // Logical Properties
-  "border-block-stylex", // Logical Properties
+  "border-block-style", // Logical Properties
   "border-block-width", // Logical Properties
   "border-block-start-color", // Logical Properties
   "border-block-start-style", // Logical Properties
@@ -311,6 +311,14 @@ pub(crate) static LONG_HAND_LOGICAL: phf::Set<&'static str> = phf_set! {
   "page-break-before",
   "page-break-inside",
 
+  // CSS Anchor Positioning
+  "anchor-name",
+  "position-anchor",
+  "position-area",
+  "position-try-fallbacks",
+  "position-try-order",
+  "position-visibility",
+
   // CSS Positioning
   "inset-block-start", // Logical Properties
   "inset-block-end", // Logical Properties
@@ -430,6 +438,7 @@ pub(crate) static LONG_HAND_LOGICAL: phf::Set<&'static str> = phf_set! {
   "transition-timing-function",
 
   // CSS View Transitions
+  "view-transition-class",
   "view-transition-name",
 
   // CSS Will Change
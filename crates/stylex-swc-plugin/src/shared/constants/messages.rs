@@ -46,6 +46,7 @@ pub(crate) static ONLY_TOP_LEVEL_INCLUDES: &str =
   "stylex.include() is only at the top level of a style definition object.";
 pub(crate) static DUPLICATE_CONDITIONAL: &str =
   "The same pseudo selector or at-rule cannot be used more than once.";
+pub(crate) static DUPLICATE_HASTE_MODULE: &str = "Found multiple files for Haste module";
 #[allow(dead_code)]
 pub(crate) static NO_PROJECT_ROOT_DIRECTORY: &str =
   "The project root directory `rootDir` is not configured.";
@@ -55,6 +56,9 @@ pub(crate) static NON_EXPORT_NAMED_DECLARATION: &str =
 pub(crate) static ANONYMOUS_THEME: &str = "stylex.createTheme() must be bound to a named constant.";
 pub(crate) static ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS:&str =  "Only named parameters are allowed in Dynamic Style functions. Destructuring, spreading or default values are not allowed.";
 pub(crate) static BUILT_IN_FUNCTION: &str = "Evaluation built-in functions not supported";
+pub(crate) static TO_FIXED_DIGITS: &str = "toFixed() digits argument must be between 0 and 100";
+pub(crate) static OBJECT_ASSIGN_TARGET: &str =
+  "Object.assign is only supported with an empty object literal as the first argument";
 
 pub(crate) static NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL: &str =
   "stylex.keyframes() can only accept an object.";
@@ -65,8 +69,68 @@ pub(crate) static NON_STATIC_KEYFRAME_VALUE: &str =
 pub(crate) static NON_OBJECT_KEYFRAME: &str =
   "Every frame within a stylex.keyframes() call must be an object.";
 
+pub(crate) static INVALID_KEYFRAMES_AT_RULE: &str =
+  "Keyframes under an at-rule must be an object of frames.";
+
+pub(crate) static NON_OBJECT_FOR_STYLEX_VIEW_TRANSITION_CLASS_CALL: &str =
+  "stylex.viewTransitionClass() can only accept an object.";
+
+pub(crate) static NON_STATIC_VIEW_TRANSITION_CLASS_VALUE: &str =
+  "Only static values are allowed inside of a stylex.viewTransitionClass() call.";
+
+pub(crate) static INVALID_VIEW_TRANSITION_CLASS_KEY: &str =
+  "stylex.viewTransitionClass() only accepts the \"group\", \"imagePair\", \"old\" and \"new\" keys.";
+
+pub(crate) static NON_OBJECT_VIEW_TRANSITION_STYLE: &str =
+  "Every value within a stylex.viewTransitionClass() call must be an object of styles.";
+
+pub(crate) static NON_OBJECT_FOR_STYLEX_POSITION_TRY_CALL: &str =
+  "stylex.positionTry() can only accept an object.";
+
+pub(crate) static NON_STATIC_POSITION_TRY_VALUE: &str =
+  "Only static values are allowed inside of a stylex.positionTry() call.";
+
+pub(crate) static INVALID_POSITION_TRY_PROPERTY: &str =
+  "stylex.positionTry() only accepts anchor positioning, inset, margin, sizing and self-alignment properties.";
+
+pub(crate) static INVALID_POSITION_TRY_VALUE: &str =
+  "Every value within a stylex.positionTry() call must be a string or a number.";
+
 pub(crate) static LINT_UNCLOSED_FUNCTION: &str = "Rule contains an unclosed function";
 pub(crate) static UNPREFIXED_CUSTOM_PROPERTIES: &str = "Unprefixed custom properties";
 
 pub(crate) static NON_CONTIGUOUS_VARS: &str =
   "All variables passed to `stylex.firstThatWorks` must be contiguous.";
+
+pub(crate) static UNKNOWN_CSS_VARIABLE: &str = "Unknown CSS variable";
+pub(crate) static UNKNOWN_CSS_PROPERTY: &str = "Unknown CSS property";
+pub(crate) static INVALID_CSS_VALUE: &str = "Invalid CSS value";
+pub(crate) static INVALID_MEDIA_QUERY: &str = "Invalid media query";
+pub(crate) static INVALID_AT_RULE: &str = "Invalid at-rule";
+pub(crate) static IMPORTANT_NOT_ALLOWED: &str = "!important is not allowed";
+pub(crate) static MIXED_IMPORTANT_FALLBACKS: &str =
+  "!important must be set on every fallback value or on none of them";
+pub(crate) static INVALID_THEME_OVERRIDE_VALUE: &str =
+  "Theme override value does not match the type of the variable";
+pub(crate) static UNKNOWN_THEME_VARIABLE: &str = "Unknown theme variable";
+pub(crate) static MISSING_THEME_VARIABLES: &str = "Theme does not override every variable";
+pub(crate) static UNCHECKED_THEME_VARIABLES: &str =
+  "Theme overrides cannot be checked, as the module that defines the variables cannot be resolved";
+pub(crate) static UNRESOLVED_BASE_THEME: &str =
+  "Base theme must be a stylex.createTheme() result that can be resolved at compile time";
+pub(crate) static BASE_THEME_VARIABLES_MISMATCH: &str =
+  "Base theme must override the same variables as the theme created from it";
+pub(crate) static INVALID_TYPE_OPTION: &str = "Invalid option for a typed variable";
+pub(crate) static INVALID_INITIAL_VALUE: &str =
+  "initialValue of a typed variable must match its syntax and cannot reference other variables";
+#[cfg(feature = "design-tokens-codegen")]
+pub(crate) static INVALID_DESIGN_TOKENS: &str = "Invalid design tokens";
+#[cfg(feature = "design-tokens-codegen")]
+pub(crate) static UNKNOWN_TOKEN_MODE: &str = "Unknown design token mode";
+#[cfg(feature = "design-tokens-codegen")]
+pub(crate) static UNRESOLVED_TOKEN_ALIAS: &str = "Design token alias cannot be resolved";
+pub(crate) static SYNTAX_MUST_BE_STRING: &str =
+  "stylex.types.syntax() expects a syntax string as its first argument";
+
+pub(crate) static INVALID_CSS_TEMPLATE: &str =
+  "A CSS tagged template can only contain a block of CSS declarations.";
@@ -6,9 +6,11 @@ pub(crate) mod length_units;
 pub(crate) mod long_hand_logical;
 pub(crate) mod long_hand_physical;
 pub mod messages;
+pub(crate) mod named_colors;
 pub(crate) mod number_properties;
 pub(crate) mod priorities;
 pub(crate) mod property_specificity_order;
+pub(crate) mod pseudo_variants;
 pub(crate) mod shorthands_of_longhands;
 pub(crate) mod shorthands_of_shorthands;
 pub(crate) mod time_units;
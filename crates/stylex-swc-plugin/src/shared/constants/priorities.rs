@@ -67,10 +67,15 @@ pub(crate) static AT_RULE_PRIORITIES: phf::Map<&'static str, &'static f64> = phf
   "@supports" => &30.0,
   "@media" => &200.0,
   "@container" => &300.0,
+  "@scope" => &250.0,
+  // Above the conditional at-rules, so starting values override matching conditions
+  "@starting-style" => &400.0,
 };
 
 pub(crate) static PSEUDO_ELEMENT_PRIORITY: f64 = 5000.0;
 
+pub(crate) static IMPORTANT_PRIORITY: f64 = 0.5;
+
 pub(crate) static CAMEL_CASE_PRIORITIES: phf::Map<&'static str, &'static str> = phf_map! {
   "translatex" => "translateX",
   "translatey" => "translateY",
@@ -0,0 +1,21 @@
+use phf::phf_map;
+
+/// Selectors that a pseudo-class or pseudo-element key expands to
+pub(crate) static PSEUDO_VARIANTS: phf::Map<&'static str, &'static [&'static str]> = phf_map! {
+  "::thumb" => &["::-webkit-slider-thumb", "::-moz-range-thumb", "::-ms-thumb"],
+  "::track" => &[
+    "::-webkit-slider-runnable-track",
+    "::-moz-range-track",
+    "::-ms-track",
+  ],
+  "::placeholder" => &["::placeholder", "::-webkit-input-placeholder", "::-moz-placeholder"],
+  "::selection" => &["::selection", "::-moz-selection"],
+  "::backdrop" => &["::backdrop", "::-webkit-backdrop"],
+  "::scrollbar" => &["::-webkit-scrollbar"],
+  "::scrollbar-button" => &["::-webkit-scrollbar-button"],
+  "::scrollbar-corner" => &["::-webkit-scrollbar-corner"],
+  "::scrollbar-thumb" => &["::-webkit-scrollbar-thumb"],
+  "::scrollbar-track" => &["::-webkit-scrollbar-track"],
+  "::scrollbar-track-piece" => &["::-webkit-scrollbar-track-piece"],
+  ":fullscreen" => &[":fullscreen", ":-webkit-full-screen", ":-moz-full-screen"],
+};
@@ -1,6 +1,6 @@
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone, Hash, Copy)]
+#[derive(Debug, PartialEq, Clone, Hash)]
 pub enum CSSSyntax {
   Length,
   Number,
@@ -15,45 +15,129 @@ pub enum CSSSyntax {
   Resolution,
   TransformFunction,
   TransformList,
+  CustomIdent,
+  String,
+  /// The universal syntax `*`, which accepts any value
+  Universal,
+  /// A literal keyword, e.g. `auto` in `<length> | auto`
+  Keyword(String),
+  Multiplier(Box<CSSSyntax>, SyntaxMultiplier),
+  Union(Vec<CSSSyntax>),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Hash)]
+pub enum SyntaxMultiplier {
+  /// `+`, a space-separated list
+  SpaceSeparated,
+  /// `#`, a comma-separated list
+  CommaSeparated,
 }
 
 impl fmt::Display for CSSSyntax {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    match *self {
+    match self {
       CSSSyntax::Angle => write!(f, "<angle>"),
       CSSSyntax::Color => write!(f, "<color>"),
       CSSSyntax::Image => write!(f, "<image>"),
       CSSSyntax::Integer => write!(f, "<integer>"),
       CSSSyntax::Length => write!(f, "<length>"),
-      CSSSyntax::LengthPercentage => write!(f, "<lengthPercentage>"),
+      CSSSyntax::LengthPercentage => write!(f, "<length-percentage>"),
       CSSSyntax::Number => write!(f, "<number>"),
       CSSSyntax::Percentage => write!(f, "<percentage>"),
       CSSSyntax::Resolution => write!(f, "<resolution>"),
       CSSSyntax::Time => write!(f, "<time>"),
-      CSSSyntax::TransformFunction => write!(f, "<transformFunction>"),
-      CSSSyntax::TransformList => write!(f, "<transformList>"),
+      CSSSyntax::TransformFunction => write!(f, "<transform-function>"),
+      CSSSyntax::TransformList => write!(f, "<transform-list>"),
       CSSSyntax::Url => write!(f, "<url>"),
+      CSSSyntax::CustomIdent => write!(f, "<custom-ident>"),
+      CSSSyntax::String => write!(f, "<string>"),
+      CSSSyntax::Universal => write!(f, "*"),
+      CSSSyntax::Keyword(keyword) => write!(f, "{}", keyword),
+      CSSSyntax::Multiplier(syntax, SyntaxMultiplier::SpaceSeparated) => write!(f, "{}+", syntax),
+      CSSSyntax::Multiplier(syntax, SyntaxMultiplier::CommaSeparated) => write!(f, "{}#", syntax),
+      CSSSyntax::Union(syntaxes) => write!(
+        f,
+        "{}",
+        syntaxes
+          .iter()
+          .map(|syntax| syntax.to_string())
+          .collect::<Vec<String>>()
+          .join(" | ")
+      ),
     }
   }
 }
 
 impl From<String> for CSSSyntax {
   fn from(value: String) -> Self {
-    match value.as_str() {
-      "<angle>" => CSSSyntax::Angle,
-      "<color>" => CSSSyntax::Color,
-      "<image>" => CSSSyntax::Image,
-      "<integer>" => CSSSyntax::Integer,
-      "<length>" => CSSSyntax::Length,
-      "<lengthPercentage>" => CSSSyntax::LengthPercentage,
-      "<number>" => CSSSyntax::Number,
-      "<percentage>" => CSSSyntax::Percentage,
-      "<resolution>" => CSSSyntax::Resolution,
-      "<time>" => CSSSyntax::Time,
-      "<transformFunction>" => CSSSyntax::TransformFunction,
-      "<transformList>" => CSSSyntax::TransformList,
-      "<url>" => CSSSyntax::Url,
-      _ => panic!(r#"CSSSyntax "{}" not found"#, value),
+    let value = value.trim();
+
+    if value == "*" {
+      return CSSSyntax::Universal;
     }
+
+    if value.contains('|') {
+      let syntaxes = value
+        .split('|')
+        .map(parse_syntax_component)
+        .collect::<Option<Vec<CSSSyntax>>>()
+        .unwrap_or_else(|| panic!(r#"CSSSyntax "{}" not found"#, value));
+
+      return CSSSyntax::Union(syntaxes);
+    }
+
+    parse_syntax_component(value).unwrap_or_else(|| panic!(r#"CSSSyntax "{}" not found"#, value))
+  }
+}
+
+fn parse_syntax_component(value: &str) -> Option<CSSSyntax> {
+  let value = value.trim();
+
+  let multiplier = if value.ends_with('+') {
+    Some(SyntaxMultiplier::SpaceSeparated)
+  } else if value.ends_with('#') {
+    Some(SyntaxMultiplier::CommaSeparated)
+  } else {
+    None
+  };
+
+  if let Some(multiplier) = multiplier {
+    let syntax = parse_syntax_component(&value[..value.len() - 1])?;
+
+    return match syntax {
+      CSSSyntax::Multiplier(_, _) | CSSSyntax::TransformList => None,
+      _ => Some(CSSSyntax::Multiplier(Box::new(syntax), multiplier)),
+    };
   }
+
+  let syntax = match value {
+    "<angle>" => CSSSyntax::Angle,
+    "<color>" => CSSSyntax::Color,
+    "<image>" => CSSSyntax::Image,
+    "<integer>" => CSSSyntax::Integer,
+    "<length>" => CSSSyntax::Length,
+    "<lengthPercentage>" | "<length-percentage>" => CSSSyntax::LengthPercentage,
+    "<number>" => CSSSyntax::Number,
+    "<percentage>" => CSSSyntax::Percentage,
+    "<resolution>" => CSSSyntax::Resolution,
+    "<time>" => CSSSyntax::Time,
+    "<transformFunction>" | "<transform-function>" => CSSSyntax::TransformFunction,
+    "<transformList>" | "<transform-list>" => CSSSyntax::TransformList,
+    "<url>" => CSSSyntax::Url,
+    "<custom-ident>" => CSSSyntax::CustomIdent,
+    "<string>" => CSSSyntax::String,
+    _ if is_keyword(value) => CSSSyntax::Keyword(value.to_string()),
+    _ => return None,
+  };
+
+  Some(syntax)
+}
+
+fn is_keyword(value: &str) -> bool {
+  let mut chars = value.chars();
+
+  chars
+    .next()
+    .is_some_and(|chr| chr.is_ascii_alphabetic() || chr == '-' || chr == '_')
+    && chars.all(|chr| chr.is_ascii_alphanumeric() || chr == '-' || chr == '_')
 }
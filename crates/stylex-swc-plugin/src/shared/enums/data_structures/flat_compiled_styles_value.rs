@@ -1,7 +1,9 @@
 use swc_core::ecma::ast::Expr;
 
 use crate::shared::structures::{
-  base_css_type::BaseCSSType, included_style::IncludedStyle, injectable_style::InjectableStyle,
+  base_css_type::{BaseCSSType, CSSTypeOptions},
+  included_style::IncludedStyle,
+  injectable_style::InjectableStyle,
   pair::Pair,
 };
 
@@ -16,7 +18,7 @@ pub(crate) enum FlatCompiledStylesValue {
   InjectableStyle(InjectableStyle),
   Bool(bool),
   Tuple(String, Box<Expr>, Option<BaseCSSType>),
-  CSSType(String, CSSSyntax, String),
+  CSSType(String, CSSSyntax, String, CSSTypeOptions),
 }
 
 impl FlatCompiledStylesValue {
@@ -1,8 +1,12 @@
+use crate::shared::structures::stylex_options::TemplateTagBehavior;
+
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
 pub enum ArrayJS {
   Map,
   Filter,
   Join,
+  From,
+  IsArray,
 }
 
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
@@ -11,6 +15,7 @@ pub enum ObjectJS {
   Keys,
   Values,
   FromEntries,
+  Assign,
 }
 
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
@@ -21,10 +26,34 @@ pub enum MathJS {
   Floor,
   Max,
   Min,
+  Abs,
+  Sqrt,
+  Trunc,
+  Sign,
+  Hypot,
 }
 
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
 pub enum StringJS {
   Concat,
   CharCodeAt,
+  Constructor,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+pub enum NumberJS {
+  Constructor,
+  ParseInt,
+  ParseFloat,
+  ToFixed,
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+pub enum TemplateTagJS {
+  // `String.raw`
+  Raw,
+  // A configured tag that combines strings and values as configured
+  Known(TemplateTagBehavior),
+  // A configured tag that parses a CSS declaration block into a style object
+  Css,
 }
@@ -4,8 +4,6 @@ use regex::Regex;
 pub(crate) static INCLUDED_IDENT_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"__included_\d+__").unwrap());
 
-pub(crate) static CSS_RULE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r".*:(.*?)}").unwrap());
-
 pub(crate) static WHITESPACE_NORMALIZER_MATH_SIGNS_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"([\*\/])(\S)").unwrap());
 
@@ -32,3 +30,21 @@ pub(crate) static HASH_WHITESPACE_NORMALIZER_REGEX: Lazy<Regex> =
 
 pub(crate) static LENGTH_UNIT_TESTER_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"^-?\d+(px|%|em|rem|ex|ch|vh|vw|vmin|vmax)?$").unwrap());
+
+pub(crate) static CSS_VARIABLE_REFERENCE_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"var\(\s*(--[\w-]+)\s*(,[^()]*)?\)").unwrap());
+
+pub(crate) static TRIVIAL_INTEGER_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^(0|-?[1-9]\d*)$").unwrap());
+
+pub(crate) static TRIVIAL_LENGTH_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^(-?[1-9]\d*)(px|%|em|rem|ex|ch|vh|vw|vmin|vmax)$").unwrap());
+
+pub(crate) static TRIVIAL_IDENT_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[a-z][a-z0-9-]*$").unwrap());
+
+pub(crate) static IMPORTANT_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"(?i)\s*!\s*important\s*$").unwrap());
+
+pub(crate) static FLOAT_PREFIX_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?").unwrap());
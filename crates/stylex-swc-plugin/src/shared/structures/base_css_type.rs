@@ -1,15 +1,16 @@
 use indexmap::IndexMap;
 use swc_core::ecma::{
-  ast::{Expr, ObjectLit, PropOrSpread},
+  ast::{Expr, Lit, ObjectLit, PropOrSpread},
   utils::ExprExt,
 };
 
 use crate::shared::{
+  constants::messages::INVALID_TYPE_OPTION,
   enums::data_structures::{css_syntax::CSSSyntax, value_with_default::ValueWithDefault},
   utils::{
     ast::factories::{
-      object_expression_factory, object_lit_factory, prop_or_spread_expression_factory,
-      prop_or_spread_string_factory,
+      object_expression_factory, object_lit_factory, prop_or_spread_boolean_factory,
+      prop_or_spread_expression_factory, prop_or_spread_string_factory,
     },
     common::{get_key_str, get_key_values_from_object, get_string_val_from_lit},
   },
@@ -19,9 +20,103 @@ use crate::shared::{
 pub(crate) struct BaseCSSType {
   pub(crate) value: ValueWithDefault,
   pub(crate) syntax: CSSSyntax,
+  pub(crate) options: CSSTypeOptions,
+}
+
+/// `@property` descriptors that can be set per typed variable, e.g.
+/// `stylex.types.length('10px', { inherits: false, initialValue: '0px' })`.
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub(crate) struct CSSTypeOptions {
+  pub(crate) inherits: bool,
+  pub(crate) initial_value: Option<String>,
+  pub(crate) register: bool,
+}
+
+impl Default for CSSTypeOptions {
+  fn default() -> Self {
+    CSSTypeOptions {
+      inherits: true,
+      initial_value: None,
+      register: true,
+    }
+  }
+}
+
+impl CSSTypeOptions {
+  fn set(&mut self, key: &str, value: String) {
+    match key {
+      "inherits" => self.inherits = parse_bool_option(key, &value),
+      "initialValue" => self.initial_value = Some(value),
+      "register" => self.register = parse_bool_option(key, &value),
+      _ => panic!(r#"{}: "{}" is not supported."#, INVALID_TYPE_OPTION, key),
+    }
+  }
+
+  fn to_props(&self) -> Vec<PropOrSpread> {
+    let mut props = vec![];
+
+    if !self.inherits {
+      props.push(prop_or_spread_boolean_factory("inherits", Some(false)));
+    }
+
+    if let Some(initial_value) = &self.initial_value {
+      props.push(prop_or_spread_string_factory("initialValue", initial_value));
+    }
+
+    if !self.register {
+      props.push(prop_or_spread_boolean_factory("register", Some(false)));
+    }
+
+    props
+  }
+}
+
+impl From<Option<ValueWithDefault>> for CSSTypeOptions {
+  fn from(value: Option<ValueWithDefault>) -> Self {
+    let mut options = CSSTypeOptions::default();
+
+    match value {
+      None => {}
+      Some(ValueWithDefault::Map(map)) => {
+        for (key, value) in map {
+          match value {
+            ValueWithDefault::String(value) => options.set(&key, value),
+            ValueWithDefault::Number(value) => options.set(&key, value.to_string()),
+            ValueWithDefault::Map(_) => {
+              panic!(
+                r#"{}: "{}" must be a static value."#,
+                INVALID_TYPE_OPTION, key
+              )
+            }
+          }
+        }
+      }
+      Some(_) => panic!("{}: options must be an object.", INVALID_TYPE_OPTION),
+    }
+
+    options
+  }
+}
+
+fn parse_bool_option(key: &str, value: &str) -> bool {
+  match value {
+    "true" => true,
+    "false" => false,
+    _ => panic!(r#"{}: "{}" must be a boolean."#, INVALID_TYPE_OPTION, key),
+  }
 }
 
 impl BaseCSSType {
+  pub(crate) fn with_options(mut self, options: CSSTypeOptions) -> Self {
+    self.options = options;
+
+    self
+  }
+
+  pub(crate) fn options_to_props(&self) -> Vec<PropOrSpread> {
+    self.options.to_props()
+  }
+
   pub(crate) fn value_to_props(
     value: ValueWithDefault,
     top_key: Option<String>,
@@ -71,6 +166,7 @@ impl From<ObjectLit> for BaseCSSType {
   fn from(obj: ObjectLit) -> BaseCSSType {
     let key_values = get_key_values_from_object(&obj);
     let mut syntax: Option<CSSSyntax> = None;
+    let mut options = CSSTypeOptions::default();
 
     let mut values: IndexMap<String, ValueWithDefault> = IndexMap::new();
 
@@ -142,6 +238,23 @@ impl From<ObjectLit> for BaseCSSType {
             }
           }
         }
+        "inherits" | "register" => {
+          let value = match key_value.value.as_lit() {
+            Some(Lit::Bool(value)) => value.value.to_string(),
+            _ => panic!(r#"{}: "{}" must be a boolean."#, INVALID_TYPE_OPTION, key),
+          };
+
+          options.set(&key, value);
+        }
+        "initialValue" => {
+          let value = key_value
+            .value
+            .as_lit()
+            .and_then(get_string_val_from_lit)
+            .expect("initialValue must be a string");
+
+          options.set(&key, value);
+        }
         _ => {
           panic!(r#"Key "{}" not support by BaseCSSType"#, key)
         }
@@ -158,6 +271,7 @@ impl From<ObjectLit> for BaseCSSType {
     BaseCSSType {
       value: ValueWithDefault::Map(values),
       syntax: syntax.expect("Syntax is required"),
+      options,
     }
   }
 }
@@ -4,7 +4,7 @@ use swc_core::{atoms::Atom, ecma::ast::Expr};
 
 use crate::shared::enums::{
   data_structures::value_with_default::ValueWithDefault,
-  js::{ArrayJS, MathJS, ObjectJS, StringJS},
+  js::{ArrayJS, MathJS, NumberJS, ObjectJS, StringJS},
 };
 
 use super::{
@@ -18,9 +18,10 @@ pub enum CallbackType {
   Object(ObjectJS),
   Math(MathJS),
   String(StringJS),
+  Number(NumberJS),
 }
 
-pub type StylexTypeFn = Rc<dyn Fn(ValueWithDefault) -> Expr + 'static>;
+pub type StylexTypeFn = Rc<dyn Fn(Vec<ValueWithDefault>) -> Expr + 'static>;
 
 pub enum FunctionType {
   ArrayArgs(fn(Vec<Expr>) -> Expr),
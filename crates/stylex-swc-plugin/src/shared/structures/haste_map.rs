@@ -0,0 +1,154 @@
+use std::{
+  cell::RefCell,
+  collections::HashMap,
+  fs::read_dir,
+  path::{Path, PathBuf},
+  rc::Rc,
+};
+
+use regex::Regex;
+use stylex_path_resolver::resolvers::EXTENSIONS;
+
+use crate::shared::constants::messages::DUPLICATE_HASTE_MODULE;
+
+thread_local! {
+  /// Scanned module maps, keyed by root directory and ignore patterns, shared by
+  /// all the files the plugin transforms
+  static HASTE_MAP_CACHE: RefCell<HashMap<(String, Vec<String>), Rc<HasteMap>>> =
+    RefCell::new(HashMap::new());
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct HasteMap {
+  modules: HashMap<String, Vec<PathBuf>>,
+}
+
+impl HasteMap {
+  pub(crate) fn get(root_dir: &str, ignore_patterns: &[String]) -> Rc<HasteMap> {
+    HASTE_MAP_CACHE.with(|cache| {
+      cache
+        .borrow_mut()
+        .entry((root_dir.to_string(), ignore_patterns.to_vec()))
+        .or_insert_with(|| Rc::new(HasteMap::new(Path::new(root_dir), ignore_patterns)))
+        .clone()
+    })
+  }
+
+  fn new(root_dir: &Path, ignore_patterns: &[String]) -> Self {
+    let ignore_patterns = ignore_patterns
+      .iter()
+      .map(|pattern| glob_to_regex(pattern))
+      .collect::<Vec<Regex>>();
+
+    let mut haste_map = HasteMap::default();
+
+    haste_map.scan_dir(root_dir, root_dir, &ignore_patterns);
+
+    haste_map
+  }
+
+  fn scan_dir(&mut self, dir: &Path, root_dir: &Path, ignore_patterns: &[Regex]) {
+    let Ok(entries) = read_dir(dir) else {
+      return;
+    };
+
+    for entry in entries.flatten() {
+      let path = entry.path();
+
+      let relative_path = path
+        .strip_prefix(root_dir)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .replace('\\', "/");
+
+      if ignore_patterns
+        .iter()
+        .any(|pattern| pattern.is_match(&relative_path))
+      {
+        continue;
+      }
+
+      if path.is_dir() {
+        if entry.file_name() != "node_modules" {
+          self.scan_dir(&path, root_dir, ignore_patterns);
+        }
+
+        continue;
+      }
+
+      if let Some(module_name) = get_haste_name(&path) {
+        self.modules.entry(module_name).or_default().push(path);
+      }
+    }
+  }
+
+  pub(crate) fn resolve(&self, import_path: &str) -> Option<&PathBuf> {
+    let module_name = EXTENSIONS
+      .iter()
+      .find_map(|ext| import_path.strip_suffix(ext))
+      .unwrap_or(import_path);
+
+    let paths = self.modules.get(module_name)?;
+
+    if paths.len() > 1 {
+      let mut paths = paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<String>>();
+
+      paths.sort();
+
+      panic!(
+        "{} \"{}\": {}",
+        DUPLICATE_HASTE_MODULE,
+        module_name,
+        paths.join(", ")
+      );
+    }
+
+    paths.first()
+  }
+}
+
+/// The file name that identifies a Haste module, e.g. for theme hashing
+pub(crate) fn get_haste_file_name(path: &Path) -> Option<String> {
+  path
+    .file_name()
+    .map(|file_name| file_name.to_string_lossy().to_string())
+}
+
+pub(crate) fn get_haste_name(path: &Path) -> Option<String> {
+  let file_name = path.file_name()?.to_str()?;
+
+  EXTENSIONS
+    .iter()
+    .find_map(|ext| file_name.strip_suffix(ext))
+    .map(|module_name| module_name.to_string())
+}
+
+fn glob_to_regex(pattern: &str) -> Regex {
+  let mut regex = String::from("^");
+  let mut chars = pattern.chars().peekable();
+
+  while let Some(chr) = chars.next() {
+    match chr {
+      '*' if chars.peek() == Some(&'*') => {
+        chars.next();
+
+        if chars.peek() == Some(&'/') {
+          chars.next();
+          regex.push_str("(?:.*/)?");
+        } else {
+          regex.push_str(".*");
+        }
+      }
+      '*' => regex.push_str("[^/]*"),
+      '?' => regex.push_str("[^/]"),
+      _ => regex.push_str(&regex::escape(&chr.to_string())),
+    }
+  }
+
+  regex.push('$');
+
+  Regex::new(&regex).unwrap_or_else(|_| panic!("Invalid ignore pattern: {}", pattern))
+}
@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+use swc_core::ecma::ast::Expr;
+
+use crate::shared::enums::data_structures::css_syntax::CSSSyntax;
+
+use super::types::{StylesObjectMap, ThemeOverrides};
+
+/// Results of another module compiled with the options of the current one,
+/// keyed by export name
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct ImportedModule {
+  pub(crate) style_maps: HashMap<String, Box<StylesObjectMap>>,
+  pub(crate) theme_overrides: HashMap<String, ThemeOverrides>,
+  pub(crate) defined_vars: HashMap<String, Box<Expr>>,
+  pub(crate) typed_variable_syntaxes: HashMap<String, CSSSyntax>,
+}
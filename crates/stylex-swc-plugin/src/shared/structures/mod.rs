@@ -2,6 +2,8 @@ pub(crate) mod application_order;
 pub(crate) mod base_css_type;
 pub mod evaluate_result;
 pub mod functions;
+pub(crate) mod haste_map;
+pub(crate) mod imported_module;
 pub(crate) mod included_style;
 pub(crate) mod injectable_style;
 pub(crate) mod legacy_expand_shorthands_order;
@@ -23,7 +25,7 @@ pub(crate) mod shorthands_of_shorthands;
 pub(crate) mod state;
 pub mod state_manager;
 pub mod stylex_options;
-pub(crate) mod stylex_state_options;
+pub mod stylex_state_options;
 pub(crate) mod tests;
 pub(crate) mod theme_ref;
 pub(crate) mod types;
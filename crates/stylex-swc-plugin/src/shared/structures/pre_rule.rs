@@ -1,6 +1,9 @@
 use std::fmt::Debug;
 
-use swc_core::ecma::ast::Expr;
+use swc_core::{
+  common::{Span, DUMMY_SP},
+  ecma::ast::Expr,
+};
 
 use crate::shared::utils::{
   common::type_of, core::convert_style_to_class_name::convert_style_to_class_name,
@@ -69,6 +72,7 @@ pub(crate) struct StylesPreRule {
   value: PreRuleValue,
   pseudos: Vec<String>,
   at_rules: Vec<String>,
+  span: Span,
 }
 
 impl StylesPreRule {
@@ -107,8 +111,14 @@ impl StylesPreRule {
       value,
       pseudos,
       at_rules,
+      span: DUMMY_SP,
     }
   }
+  /// Sets the span of the style value, used to report invalid values
+  pub(crate) fn with_span(mut self, span: Span) -> Self {
+    self.span = span;
+    self
+  }
   pub(crate) fn _get_property(&self) -> Option<&str> {
     Some(&self.property)
   }
@@ -131,6 +141,7 @@ impl PreRule for StylesPreRule {
       &mut self.pseudos,
       &mut self.at_rules,
       &state.options.class_name_prefix,
+      self.span,
       state,
     );
 
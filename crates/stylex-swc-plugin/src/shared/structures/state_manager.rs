@@ -1,12 +1,15 @@
 use core::panic;
+use std::cell::{OnceCell, RefCell};
 use std::option::Option;
 use std::path::Path;
+use std::rc::Rc;
 use std::{
   collections::{HashMap, HashSet},
   hash::Hash,
 };
 
 use indexmap::{IndexMap, IndexSet};
+use path_clean::PathClean;
 use stylex_path_resolver::resolvers::{resolve_file_path, resolve_path, EXTENSIONS};
 use swc_core::ecma::ast::{
   CallExpr, Callee, Decl, Expr, ExprStmt, Ident, ImportDecl, ImportDefaultSpecifier,
@@ -21,6 +24,7 @@ use swc_core::{
 use crate::shared::enums::{
   core::TransformationCycle,
   data_structures::{
+    css_syntax::CSSSyntax,
     import_path_resolution::{ImportPathResolution, ImportPathResolutionType},
     style_vars_to_keep::StyleVarsToKeep,
     top_level_expression::{TopLevelExpression, TopLevelExpressionKind},
@@ -31,6 +35,7 @@ use crate::shared::utils::{
   common::{
     extract_filename_from_path, extract_filename_with_ext_from_path, extract_path, round_f64,
   },
+  css::pipeline::CssPipeline,
 };
 use crate::shared::{
   constants::common::DEFAULT_INJECT_PATH,
@@ -39,12 +44,17 @@ use crate::shared::{
   },
 };
 
+use super::haste_map::{get_haste_file_name, get_haste_name, HasteMap};
+use super::imported_module::ImportedModule;
 use super::plugin_pass::PluginPass;
 use super::stylex_options::{CheckModuleResolution, StyleXOptions};
 use super::stylex_state_options::StyleXStateOptions;
 use super::uid_generator::UidGenerator;
 use super::{injectable_style::InjectableStyle, stylex_options::ModuleResolution};
-use super::{meta_data::MetaData, types::StylesObjectMap};
+use super::{
+  meta_data::MetaData,
+  types::{StylesObjectMap, ThemeOverrides},
+};
 use super::{
   named_import_source::{ImportSources, NamedImportSource, RuntimeInjectionState},
   seen_value::SeenValue,
@@ -69,6 +79,8 @@ pub struct StateManager {
   pub(crate) stylex_define_vars_import: AtomHashSet,
   pub(crate) stylex_create_theme_import: AtomHashSet,
   pub(crate) stylex_types_import: AtomHashSet,
+  pub(crate) stylex_view_transition_class_import: AtomHashSet,
+  pub(crate) stylex_position_try_import: AtomHashSet,
   pub(crate) inject_import_inserted: Option<(Box<Ident>, Box<Ident>)>,
   pub(crate) theme_name: Option<String>,
 
@@ -82,6 +94,15 @@ pub struct StateManager {
   pub(crate) style_map: HashMap<String, Box<StylesObjectMap>>,
   pub(crate) style_vars: HashMap<String, Box<VarDeclarator>>,
 
+  // `stylex.createTheme` calls, keyed by variable name
+  pub(crate) theme_overrides: HashMap<String, ThemeOverrides>,
+
+  // `stylex.defineVars` calls, keyed by variable name
+  pub(crate) defined_vars: HashMap<String, Box<Expr>>,
+
+  // other modules compiled to resolve their exports, keyed by file path
+  pub(crate) imported_modules: HashMap<String, ImportedModule>,
+
   // results of `stylex.create` calls that should be kept
   pub(crate) style_vars_to_keep: HashSet<Box<StyleVarsToKeep>>,
   pub(crate) member_object_ident_count_map: AtomHashMap,
@@ -95,9 +116,20 @@ pub struct StateManager {
   pub(crate) prepend_import_module_items: Vec<ModuleItem>,
 
   pub(crate) injected_keyframes: IndexMap<String, Box<InjectableStyle>>,
+  /// Animation names of the keyframes variants by the default animation name and the at-rule
+  pub(crate) keyframes_variants: IndexMap<String, IndexMap<String, String>>,
+  /// Syntaxes of the typed variables by their hashed names
+  pub(crate) typed_variable_syntaxes: HashMap<String, CSSSyntax>,
+  /// Design token groups of the `stylex.defineVars` calls by their export names
+  pub(crate) design_tokens: IndexMap<String, serde_json::Value>,
   pub(crate) top_imports: Vec<ImportDecl>,
 
   pub(crate) cycle: TransformationCycle,
+
+  /// Normalized values by property and raw value
+  pub(crate) css_value_cache: RefCell<HashMap<(String, String), String>>,
+  /// Validators and normalizers of CSS values, built from the options on first use
+  pub(crate) css_pipeline: OnceCell<CssPipeline>,
 }
 
 impl Default for StateManager {
@@ -123,9 +155,14 @@ impl StateManager {
       stylex_define_vars_import: HashSet::new(),
       stylex_create_theme_import: HashSet::new(),
       stylex_types_import: HashSet::new(),
+      stylex_view_transition_class_import: HashSet::new(),
+      stylex_position_try_import: HashSet::new(),
       inject_import_inserted: None,
       style_map: HashMap::new(),
       style_vars: HashMap::new(),
+      theme_overrides: HashMap::new(),
+      defined_vars: HashMap::new(),
+      imported_modules: HashMap::new(),
       style_vars_to_keep: HashSet::new(),
       member_object_ident_count_map: HashMap::new(),
       theme_name: None,
@@ -148,8 +185,14 @@ impl StateManager {
       prepend_import_module_items: vec![],
 
       injected_keyframes: IndexMap::new(),
+      keyframes_variants: IndexMap::new(),
+      typed_variable_syntaxes: HashMap::new(),
+      design_tokens: IndexMap::new(),
 
       cycle: TransformationCycle::Initializing,
+
+      css_value_cache: RefCell::new(HashMap::new()),
+      css_pipeline: OnceCell::new(),
     }
   }
 
@@ -212,6 +255,12 @@ impl StateManager {
   pub(crate) fn get_short_filename(&self) -> String {
     extract_filename_from_path(&self._state.filename)
   }
+  pub(crate) fn get_css_pipeline(&self) -> &CssPipeline {
+    self
+      .css_pipeline
+      .get_or_init(|| CssPipeline::new(&self.options))
+  }
+
   pub(crate) fn get_filename(&self) -> String {
     extract_path(&self._state.filename)
   }
@@ -249,9 +298,20 @@ impl StateManager {
     }
 
     match unstable_module_resolution {
-      CheckModuleResolution::Haste(_) => {
-        let filename = FileName::Real(filename.into());
-        extract_filename_with_ext_from_path(&filename)
+      CheckModuleResolution::Haste(module_resolution) => {
+        // Hash the same module file name that importers resolve to
+        let resolved_file_name = self
+          .get_haste_map(&module_resolution)
+          .and_then(|haste_map| {
+            let haste_name = get_haste_name(Path::new(&filename))?;
+
+            get_haste_file_name(haste_map.resolve(haste_name.as_str())?)
+          });
+
+        resolved_file_name.or_else(|| {
+          let filename = FileName::Real(filename.into());
+          extract_filename_with_ext_from_path(&filename)
+        })
       }
       CheckModuleResolution::CommonJS(module_resolution)
       | CheckModuleResolution::CrossFileParsing(module_resolution) => {
@@ -270,6 +330,17 @@ impl StateManager {
     }
   }
 
+  fn get_haste_map(&self, module_resolution: &ModuleResolution) -> Option<Rc<HasteMap>> {
+    let root_dir = module_resolution.root_dir.as_ref()?;
+
+    let ignore_patterns = module_resolution
+      .ignore_patterns
+      .clone()
+      .unwrap_or_default();
+
+    Some(HasteMap::get(root_dir, &ignore_patterns))
+  }
+
   pub(crate) fn import_path_resolver(&self, import_path: &str) -> ImportPathResolution {
     let source_file_path = self.get_filename();
 
@@ -314,15 +385,60 @@ impl StateManager {
           return ImportPathResolution::False;
         }
 
-        ImportPathResolution::Tuple(
-          ImportPathResolutionType::ThemeNameRef,
-          add_file_extension(import_path, &source_file_path),
-        )
+        let resolved_file_name = self
+          .get_haste_map(module_resolution)
+          .and_then(|haste_map| get_haste_file_name(haste_map.resolve(import_path)?))
+          .unwrap_or_else(|| add_file_extension(import_path, &source_file_path));
+
+        ImportPathResolution::Tuple(ImportPathResolutionType::ThemeNameRef, resolved_file_name)
       }
       _ => unimplemented!("Module resolution is not supported"),
     }
   }
 
+  pub(crate) fn resolve_module_path(&self, import_path: &str) -> Option<String> {
+    let source_file_path = self.get_filename();
+
+    if source_file_path.is_empty() {
+      return None;
+    }
+
+    if import_path.starts_with('.') {
+      let import_path = Path::new(&source_file_path)
+        .parent()?
+        .join(import_path)
+        .clean();
+
+      return std::iter::once("")
+        .chain(EXTENSIONS)
+        .map(|ext| format!("{}{}", import_path.display(), ext))
+        .find(|file_path| Path::new(file_path).is_file());
+    }
+
+    match self.options.unstable_module_resolution.as_ref()? {
+      CheckModuleResolution::CommonJS(module_resolution)
+      | CheckModuleResolution::CrossFileParsing(module_resolution) => {
+        let root_dir = module_resolution.root_dir.as_ref()?;
+
+        let aliases = self.options.aliases.clone().unwrap_or_default();
+
+        let resolved_file_path =
+          try_file_path_resolver(import_path, source_file_path, root_dir.as_str(), &aliases)?;
+
+        Some(
+          Path::new(root_dir)
+            .join(resolved_file_path)
+            .display()
+            .to_string(),
+        )
+      }
+      CheckModuleResolution::Haste(module_resolution) => self
+        .get_haste_map(module_resolution)?
+        .resolve(import_path)
+        .map(|file_path| file_path.display().to_string()),
+    }
+  }
+
   pub(crate) fn get_top_level_expr(
     &self,
     kind: &TopLevelExpressionKind,
@@ -497,9 +613,25 @@ impl StateManager {
       .push(module);
   }
 
-  // pub(crate) fn _get_css_vars(&self) -> HashMap<String, String> {
-  //   self.options.defined_stylex_css_variables.clone()
-  // }
+  /// Drops repeated injections of a result, for calls whose identical results
+  /// share their injected styles, like themes with the same overrides
+  pub(crate) fn dedupe_styles_to_inject(&mut self, ast: &Expr) {
+    if let Some(modules) = self.styles_to_inject.get_mut(ast) {
+      let mut unique_modules: Vec<ModuleItem> = Vec::with_capacity(modules.len());
+
+      for module in modules.drain(..) {
+        if !unique_modules.contains(&module) {
+          unique_modules.push(module);
+        }
+      }
+
+      *modules = unique_modules;
+    }
+  }
+
+  pub(crate) fn get_css_vars(&self) -> &HashMap<String, String> {
+    &self.options.defined_stylex_css_variables
+  }
 
   pub(crate) fn get_treeshake_compensation(&self) -> bool {
     self.options.treeshake_compensation.unwrap_or(false)
@@ -535,6 +667,14 @@ impl StateManager {
     );
     self.stylex_types_import =
       union_hash_set(&self.stylex_types_import, &other.stylex_types_import);
+    self.stylex_view_transition_class_import = union_hash_set(
+      &self.stylex_view_transition_class_import,
+      &other.stylex_view_transition_class_import,
+    );
+    self.stylex_position_try_import = union_hash_set(
+      &self.stylex_position_try_import,
+      &other.stylex_position_try_import,
+    );
     self.inject_import_inserted = self
       .inject_import_inserted
       .clone()
@@ -555,6 +695,14 @@ impl StateManager {
     );
     self.style_map = chain_collect_hash_map(self.style_map.clone(), other.style_map.clone());
     self.style_vars = chain_collect_hash_map(self.style_vars.clone(), other.style_vars.clone());
+    self.theme_overrides =
+      chain_collect_hash_map(self.theme_overrides.clone(), other.theme_overrides.clone());
+    self.defined_vars =
+      chain_collect_hash_map(self.defined_vars.clone(), other.defined_vars.clone());
+    self.imported_modules = chain_collect_hash_map(
+      self.imported_modules.clone(),
+      other.imported_modules.clone(),
+    );
     self.style_vars_to_keep =
       union_hash_set(&self.style_vars_to_keep.clone(), &other.style_vars_to_keep);
     self.member_object_ident_count_map = chain_collect_hash_map(
@@ -581,6 +729,16 @@ impl StateManager {
       self.injected_keyframes.clone(),
       other.injected_keyframes.clone(),
     );
+    self.keyframes_variants = chain_collect_index_map(
+      self.keyframes_variants.clone(),
+      other.keyframes_variants.clone(),
+    );
+    self.typed_variable_syntaxes = chain_collect_hash_map(
+      self.typed_variable_syntaxes.clone(),
+      other.typed_variable_syntaxes.clone(),
+    );
+    self.design_tokens =
+      chain_collect_index_map(self.design_tokens.clone(), other.design_tokens.clone());
     self.top_imports = chain_collect(self.top_imports.clone(), other.top_imports.clone());
   }
 }
@@ -771,7 +929,28 @@ fn file_path_resolver(
     unimplemented!("Extension match found, but handling is unimplemented");
   }
 
-  for ext in EXTENSIONS.iter() {
+  match try_file_path_resolver(relative_file_path, source_file_path, root_path, aliases) {
+    Some(resolved_path) => resolved_path,
+    None => panic!("Cannot resolve file path: {}", relative_file_path),
+  }
+}
+
+fn try_file_path_resolver(
+  relative_file_path: &str,
+  source_file_path: String,
+  root_path: &str,
+  aliases: &HashMap<String, Vec<String>>,
+) -> Option<String> {
+  let extensions = if EXTENSIONS
+    .iter()
+    .any(|ext| relative_file_path.ends_with(ext))
+  {
+    vec![""]
+  } else {
+    EXTENSIONS.to_vec()
+  };
+
+  for ext in extensions {
     let import_path_str = if relative_file_path.starts_with('.') {
       format!("{}{}", relative_file_path, ext)
     } else {
@@ -785,12 +964,12 @@ fn file_path_resolver(
       let resolved_path_str = resolved_path.display().to_string();
 
       if resolved_path_str.contains("/app/@") {
-        return resolved_path_str.replace("/app/@", "/node_modules/@");
+        return Some(resolved_path_str.replace("/app/@", "/node_modules/@"));
       } else {
-        return resolved_path_str;
+        return Some(resolved_path_str);
       }
     }
   }
 
-  panic!("Cannot resolve file path: {}", relative_file_path)
+  None
 }
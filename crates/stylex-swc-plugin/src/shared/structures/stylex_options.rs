@@ -1,8 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc};
 
 use serde::Deserialize;
 
-use crate::shared::constants::common::DEFAULT_INJECT_PATH;
+use crate::shared::{
+  constants::common::{DEFAULT_INJECT_PATH, ROOT_FONT_SIZE},
+  utils::css::{normalizers::CssNormalizer, validators::CssValidator},
+};
 
 use super::named_import_source::{ImportSources, RuntimeInjection};
 
@@ -14,6 +17,7 @@ pub struct StyleXOptionsParams {
   pub runtime_injection: Option<bool>,
   pub class_name_prefix: Option<String>,
   pub defined_stylex_css_variables: Option<HashMap<String, String>>,
+  pub defined_stylex_css_variables_resolution: Option<DefinedCSSVariablesResolution>,
   pub import_sources: Option<Vec<ImportSources>>,
   pub treeshake_compensation: Option<bool>,
   pub gen_conditional_classes: Option<bool>,
@@ -22,6 +26,25 @@ pub struct StyleXOptionsParams {
   pub aliases: Option<HashMap<String, Vec<String>>>,
   #[serde(rename = "unstable_moduleResolution")]
   pub unstable_module_resolution: Option<ModuleResolution>,
+  pub known_template_tags: Option<HashMap<String, TemplateTagBehavior>>,
+  pub css_template_tags: Option<Vec<String>>,
+  pub resolve_imported_styles: Option<bool>,
+  pub disabled_css_normalizers: Option<Vec<String>>,
+  pub disabled_css_validators: Option<Vec<String>>,
+  #[serde(skip)]
+  pub css_normalizers: Option<Vec<Rc<dyn CssNormalizer>>>,
+  #[serde(skip)]
+  pub css_validators: Option<Vec<Rc<dyn CssValidator>>>,
+  pub normalize_colors: Option<bool>,
+  pub property_validation: Option<PropertyValidationMode>,
+  pub allowed_properties: Option<Vec<String>>,
+  pub value_validation: Option<PropertyValidationMode>,
+  pub px_to_rem: Option<PxToRemOptions>,
+  pub legacy_media_query_ranges: Option<bool>,
+  pub pseudo_variants: Option<HashMap<String, Vec<String>>>,
+  pub important_policy: Option<ImportantPolicy>,
+  pub strict_themes: Option<bool>,
+  pub design_tokens: Option<bool>,
 }
 
 impl Default for StyleXOptionsParams {
@@ -32,6 +55,7 @@ impl Default for StyleXOptionsParams {
       runtime_injection: Some(false),
       class_name_prefix: Some("x".to_string()),
       defined_stylex_css_variables: Some(HashMap::new()),
+      defined_stylex_css_variables_resolution: Some(DefinedCSSVariablesResolution::Validate),
       import_sources: None,
       treeshake_compensation: Some(true),
       gen_conditional_classes: Some(false),
@@ -39,6 +63,23 @@ impl Default for StyleXOptionsParams {
       test: Some(false),
       aliases: None,
       unstable_module_resolution: None,
+      known_template_tags: None,
+      css_template_tags: None,
+      resolve_imported_styles: Some(false),
+      disabled_css_normalizers: None,
+      disabled_css_validators: None,
+      css_normalizers: None,
+      css_validators: None,
+      normalize_colors: Some(false),
+      property_validation: Some(PropertyValidationMode::Off),
+      allowed_properties: None,
+      value_validation: Some(PropertyValidationMode::Off),
+      px_to_rem: None,
+      legacy_media_query_ranges: Some(false),
+      pseudo_variants: None,
+      important_policy: Some(ImportantPolicy::Reject),
+      strict_themes: Some(false),
+      design_tokens: Some(false),
     }
   }
 }
@@ -52,6 +93,56 @@ pub enum StyleResolution {
   LegacyExpandShorthands,
 }
 
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all(deserialize = "kebab-case", serialize = "PascalCase"))]
+pub enum DefinedCSSVariablesResolution {
+  /// Only validate `var(--name)` references against the defined variables
+  Validate,
+  /// Add the known static value as a `var()` fallback
+  Fallback,
+  /// Replace the `var()` reference with the known static value
+  Inline,
+}
+
+/// How a known template tag combines its strings and values
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum TemplateTagBehavior {
+  /// Concatenates the cooked strings and values, like an untagged template
+  Concat,
+  /// Concatenates the raw strings and values, like `String.raw`
+  Raw,
+  /// Appends a unit to every value, like a `px` helper
+  Suffix(String),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PxToRemOptions {
+  /// Properties whose `px` values are converted, e.g. `padding` or `lineHeight`
+  pub properties: Vec<String>,
+  /// Convert `px` values in `@media` conditions
+  pub media_queries: bool,
+  /// Convert `px` values of `stylex.defineVars` and `stylex.createTheme`
+  pub define_vars: bool,
+  /// Font size of the root element in `px`, defaults to 16
+  pub root_font_size: f64,
+  /// Number of decimal places to round converted values to
+  pub precision: Option<u32>,
+}
+
+impl Default for PxToRemOptions {
+  fn default() -> Self {
+    PxToRemOptions {
+      properties: vec![],
+      media_queries: false,
+      define_vars: false,
+      root_font_size: ROOT_FONT_SIZE as f64,
+      precision: None,
+    }
+  }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 
 pub enum Aliases {
@@ -65,6 +156,27 @@ pub struct ModuleResolution {
   pub(crate) r#type: String,
   pub(crate) root_dir: Option<String>,
   pub(crate) theme_file_extension: Option<String>,
+  pub(crate) ignore_patterns: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all(deserialize = "kebab-case", serialize = "PascalCase"))]
+pub enum ImportantPolicy {
+  /// Fail the compilation on `!important` declarations
+  Reject,
+  /// Emit `!important` declarations with a higher priority
+  Allow,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all(deserialize = "kebab-case", serialize = "PascalCase"))]
+pub enum PropertyValidationMode {
+  /// Don't validate property names
+  Off,
+  /// Log a warning for unknown property names
+  Warn,
+  /// Fail the compilation on unknown property names
+  Error,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -81,7 +193,8 @@ pub struct StyleXOptions {
   pub test: bool,
   pub use_rem_for_font_size: bool,
   pub class_name_prefix: String,
-  // pub defined_stylex_css_variables: HashMap<String, String>,
+  pub defined_stylex_css_variables: HashMap<String, String>,
+  pub defined_stylex_css_variables_resolution: DefinedCSSVariablesResolution,
   pub style_resolution: StyleResolution,
   pub runtime_injection: RuntimeInjection,
   pub import_sources: Vec<ImportSources>,
@@ -89,6 +202,23 @@ pub struct StyleXOptions {
   pub gen_conditional_classes: bool,
   pub aliases: Option<HashMap<String, Vec<String>>>,
   pub unstable_module_resolution: Option<CheckModuleResolution>,
+  pub known_template_tags: HashMap<String, TemplateTagBehavior>,
+  pub css_template_tags: Vec<String>,
+  pub resolve_imported_styles: bool,
+  pub disabled_css_normalizers: Vec<String>,
+  pub disabled_css_validators: Vec<String>,
+  pub css_normalizers: Vec<Rc<dyn CssNormalizer>>,
+  pub css_validators: Vec<Rc<dyn CssValidator>>,
+  pub normalize_colors: bool,
+  pub property_validation: PropertyValidationMode,
+  pub allowed_properties: Vec<String>,
+  pub value_validation: PropertyValidationMode,
+  pub px_to_rem: Option<PxToRemOptions>,
+  pub legacy_media_query_ranges: bool,
+  pub pseudo_variants: HashMap<String, Vec<String>>,
+  pub important_policy: ImportantPolicy,
+  pub strict_themes: bool,
+  pub design_tokens: bool,
 }
 
 impl StyleXOptions {
@@ -97,6 +227,7 @@ impl StyleXOptions {
       r#type: "haste".to_string(),
       root_dir,
       theme_file_extension: None,
+      ignore_patterns: None,
     }
   }
 
@@ -105,6 +236,7 @@ impl StyleXOptions {
       r#type: "commonjs".to_string(),
       root_dir,
       theme_file_extension: None,
+      ignore_patterns: None,
     }
   }
 }
@@ -116,7 +248,8 @@ impl Default for StyleXOptions {
       use_rem_for_font_size: false,
       runtime_injection: RuntimeInjection::Boolean(false),
       class_name_prefix: "x".to_string(),
-      // defined_stylex_css_variables: HashMap::new(),
+      defined_stylex_css_variables: HashMap::new(),
+      defined_stylex_css_variables_resolution: DefinedCSSVariablesResolution::Validate,
       import_sources: vec![],
       dev: false,
       test: false,
@@ -126,6 +259,23 @@ impl Default for StyleXOptions {
       unstable_module_resolution: Some(CheckModuleResolution::Haste(
         StyleXOptions::get_haste_module_resolution(None),
       )),
+      known_template_tags: HashMap::new(),
+      css_template_tags: vec![],
+      resolve_imported_styles: false,
+      disabled_css_normalizers: vec![],
+      disabled_css_validators: vec![],
+      css_normalizers: vec![],
+      css_validators: vec![],
+      normalize_colors: false,
+      property_validation: PropertyValidationMode::Off,
+      allowed_properties: vec![],
+      value_validation: PropertyValidationMode::Off,
+      px_to_rem: None,
+      legacy_media_query_ranges: false,
+      pseudo_variants: HashMap::new(),
+      important_policy: ImportantPolicy::Reject,
+      strict_themes: false,
+      design_tokens: false,
     }
   }
 }
@@ -156,7 +306,10 @@ impl From<StyleXOptionsParams> for StyleXOptions {
       use_rem_for_font_size: options.use_rem_for_font_size.unwrap_or(false),
       runtime_injection,
       class_name_prefix: options.class_name_prefix.unwrap_or("x".to_string()),
-      // defined_stylex_css_variables: options.defined_stylex_css_variables.unwrap_or_default(),
+      defined_stylex_css_variables: options.defined_stylex_css_variables.unwrap_or_default(),
+      defined_stylex_css_variables_resolution: options
+        .defined_stylex_css_variables_resolution
+        .unwrap_or(DefinedCSSVariablesResolution::Validate),
       import_sources: options.import_sources.unwrap_or_default(),
       dev: options.dev.unwrap_or(false),
       test: options.test.unwrap_or(false),
@@ -164,6 +317,27 @@ impl From<StyleXOptionsParams> for StyleXOptions {
       gen_conditional_classes: options.gen_conditional_classes.unwrap_or(false),
       aliases: options.aliases,
       unstable_module_resolution,
+      known_template_tags: options.known_template_tags.unwrap_or_default(),
+      css_template_tags: options.css_template_tags.unwrap_or_default(),
+      resolve_imported_styles: options.resolve_imported_styles.unwrap_or(false),
+      disabled_css_normalizers: options.disabled_css_normalizers.unwrap_or_default(),
+      disabled_css_validators: options.disabled_css_validators.unwrap_or_default(),
+      css_normalizers: options.css_normalizers.unwrap_or_default(),
+      css_validators: options.css_validators.unwrap_or_default(),
+      normalize_colors: options.normalize_colors.unwrap_or(false),
+      property_validation: options
+        .property_validation
+        .unwrap_or(PropertyValidationMode::Off),
+      allowed_properties: options.allowed_properties.unwrap_or_default(),
+      value_validation: options
+        .value_validation
+        .unwrap_or(PropertyValidationMode::Off),
+      px_to_rem: options.px_to_rem,
+      legacy_media_query_ranges: options.legacy_media_query_ranges.unwrap_or(false),
+      pseudo_variants: options.pseudo_variants.unwrap_or_default(),
+      important_policy: options.important_policy.unwrap_or(ImportantPolicy::Reject),
+      strict_themes: options.strict_themes.unwrap_or(false),
+      design_tokens: options.design_tokens.unwrap_or(false),
     }
   }
 }
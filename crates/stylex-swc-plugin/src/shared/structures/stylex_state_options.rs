@@ -1,12 +1,18 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc};
 
 use serde::Deserialize;
 
-use crate::shared::constants::common::DEFAULT_INJECT_PATH;
+use crate::shared::{
+  constants::common::DEFAULT_INJECT_PATH,
+  utils::css::{normalizers::CssNormalizer, validators::CssValidator},
+};
 
 use super::{
   named_import_source::{ImportSources, RuntimeInjection, RuntimeInjectionState},
-  stylex_options::{CheckModuleResolution, StyleResolution, StyleXOptions},
+  stylex_options::{
+    CheckModuleResolution, DefinedCSSVariablesResolution, ImportantPolicy, PropertyValidationMode,
+    PxToRemOptions, StyleResolution, StyleXOptions, TemplateTagBehavior,
+  },
 };
 
 #[derive(Deserialize, Clone, Debug)]
@@ -15,7 +21,8 @@ pub struct StyleXStateOptions {
   pub test: bool,
   pub use_rem_for_font_size: bool,
   pub class_name_prefix: String,
-  // pub defined_stylex_css_variables: HashMap<String, String>,
+  pub defined_stylex_css_variables: HashMap<String, String>,
+  pub defined_stylex_css_variables_resolution: DefinedCSSVariablesResolution,
   pub style_resolution: StyleResolution,
   pub import_sources: Vec<ImportSources>,
   pub runtime_injection: Option<RuntimeInjectionState>,
@@ -23,6 +30,25 @@ pub struct StyleXStateOptions {
   pub gen_conditional_classes: bool,
   pub aliases: Option<HashMap<String, Vec<String>>>,
   pub unstable_module_resolution: Option<CheckModuleResolution>,
+  pub known_template_tags: HashMap<String, TemplateTagBehavior>,
+  pub css_template_tags: Vec<String>,
+  pub resolve_imported_styles: bool,
+  pub disabled_css_normalizers: Vec<String>,
+  pub disabled_css_validators: Vec<String>,
+  #[serde(skip)]
+  pub css_normalizers: Vec<Rc<dyn CssNormalizer>>,
+  #[serde(skip)]
+  pub css_validators: Vec<Rc<dyn CssValidator>>,
+  pub normalize_colors: bool,
+  pub property_validation: PropertyValidationMode,
+  pub allowed_properties: Vec<String>,
+  pub value_validation: PropertyValidationMode,
+  pub px_to_rem: Option<PxToRemOptions>,
+  pub legacy_media_query_ranges: bool,
+  pub pseudo_variants: HashMap<String, Vec<String>>,
+  pub important_policy: ImportantPolicy,
+  pub strict_themes: bool,
+  pub design_tokens: bool,
 }
 
 impl StyleXStateOptions {
@@ -32,7 +58,8 @@ impl StyleXStateOptions {
       use_rem_for_font_size: false,
       runtime_injection: None,
       class_name_prefix: "x".to_string(),
-      // defined_stylex_css_variables: HashMap::new(),
+      defined_stylex_css_variables: HashMap::new(),
+      defined_stylex_css_variables_resolution: DefinedCSSVariablesResolution::Validate,
       import_sources: vec![],
       dev: false,
       test: false,
@@ -40,6 +67,23 @@ impl StyleXStateOptions {
       gen_conditional_classes: false,
       aliases: None,
       unstable_module_resolution: None,
+      known_template_tags: HashMap::new(),
+      css_template_tags: vec![],
+      resolve_imported_styles: false,
+      disabled_css_normalizers: vec![],
+      disabled_css_validators: vec![],
+      css_normalizers: vec![],
+      css_validators: vec![],
+      normalize_colors: false,
+      property_validation: PropertyValidationMode::Off,
+      allowed_properties: vec![],
+      value_validation: PropertyValidationMode::Off,
+      px_to_rem: None,
+      legacy_media_query_ranges: false,
+      pseudo_variants: HashMap::new(),
+      important_policy: ImportantPolicy::Reject,
+      strict_themes: false,
+      design_tokens: false,
     }
   }
 }
@@ -70,7 +114,8 @@ impl From<StyleXOptions> for StyleXStateOptions {
       use_rem_for_font_size: options.use_rem_for_font_size,
       runtime_injection,
       class_name_prefix: options.class_name_prefix,
-      // defined_stylex_css_variables: options.defined_stylex_css_variables,
+      defined_stylex_css_variables: options.defined_stylex_css_variables,
+      defined_stylex_css_variables_resolution: options.defined_stylex_css_variables_resolution,
       import_sources: options.import_sources,
       dev: options.dev,
       test: options.test,
@@ -78,6 +123,23 @@ impl From<StyleXOptions> for StyleXStateOptions {
       gen_conditional_classes: options.gen_conditional_classes,
       aliases: options.aliases,
       unstable_module_resolution: options.unstable_module_resolution,
+      known_template_tags: options.known_template_tags,
+      css_template_tags: options.css_template_tags,
+      resolve_imported_styles: options.resolve_imported_styles,
+      disabled_css_normalizers: options.disabled_css_normalizers,
+      disabled_css_validators: options.disabled_css_validators,
+      css_normalizers: options.css_normalizers,
+      css_validators: options.css_validators,
+      normalize_colors: options.normalize_colors,
+      property_validation: options.property_validation,
+      allowed_properties: options.allowed_properties,
+      value_validation: options.value_validation,
+      px_to_rem: options.px_to_rem,
+      legacy_media_query_ranges: options.legacy_media_query_ranges,
+      pseudo_variants: options.pseudo_variants,
+      important_policy: options.important_policy,
+      strict_themes: options.strict_themes,
+      design_tokens: options.design_tokens,
     }
   }
 }
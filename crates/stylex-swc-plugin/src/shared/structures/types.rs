@@ -21,3 +21,5 @@ pub(crate) type FunctionMapMemberExpression =
 pub(crate) type FunctionMapIdentifiers = HashMap<Atom, Box<FunctionConfigType>>;
 pub(crate) type StylesObjectMap =
   IndexMap<String, Box<IndexMap<String, Box<FlatCompiledStylesValue>>>>;
+/// Theme name of the overridden variables and the evaluated overrides of a `stylex.createTheme` call
+pub(crate) type ThemeOverrides = (String, Box<Expr>);
@@ -4,5 +4,7 @@ pub(crate) mod stylex_define_vars;
 pub(crate) mod stylex_first_that_works;
 pub(crate) mod stylex_include;
 pub(crate) mod stylex_keyframes;
+pub(crate) mod stylex_position_try;
 pub(crate) mod stylex_types;
+pub(crate) mod stylex_view_transition_class;
 pub(crate) mod tests;
@@ -1,19 +1,35 @@
 use std::cmp::Ordering;
 
 use indexmap::IndexMap;
+use log::warn;
+use swc_core::{
+  common::{Span, Spanned, DUMMY_SP},
+  ecma::ast::{Expr, Lit, ObjectLit, PropOrSpread},
+};
 
 use crate::shared::{
-  constants::common::{COMPILED_KEY, THEME_NAME_KEY},
+  constants::{
+    common::{COMPILED_KEY, THEME_NAME_KEY},
+    messages::{
+      INVALID_THEME_OVERRIDE_VALUE, MISSING_THEME_VARIABLES, UNCHECKED_THEME_VARIABLES,
+      UNKNOWN_THEME_VARIABLE,
+    },
+  },
   enums::data_structures::{
-    evaluate_result_value::EvaluateResultValue, flat_compiled_styles_value::FlatCompiledStylesValue,
+    css_syntax::CSSSyntax, evaluate_result_value::EvaluateResultValue,
+    flat_compiled_styles_value::FlatCompiledStylesValue,
   },
   structures::{
     functions::FunctionMap, injectable_style::InjectableStyle, state_manager::StateManager,
   },
   utils::{
     ast::convertors::expr_to_str,
-    common::{create_hash, get_css_value, get_key_str, get_key_values_from_object},
+    common::{
+      create_hash, get_closest_match, get_css_value, get_key_str, get_key_values_from_object,
+      get_string_val_from_lit, panic_with_span,
+    },
     core::define_vars_utils::{collect_vars_by_at_rules, priority_for_at_rule, wrap_with_at_rules},
+    css::syntax::matches_css_syntax,
     validators::validate_theme_variables,
   },
 };
@@ -48,37 +64,20 @@ pub(crate) fn stylex_create_theme(
   for key_value in variables_key_values.into_iter() {
     let key = get_key_str(&key_value);
 
-    let theme_vars_str_value = match theme_vars {
-      EvaluateResultValue::Expr(expr) => {
-        let theme_vars_key_values = get_key_values_from_object(expr.as_object().unwrap());
-        let theme_vars_item = theme_vars_key_values
-          .iter()
-          .find(|key_value| {
-            let local_key = get_key_str(key_value);
-
-            local_key == key
-          })
-          .expect("Theme variable not found");
-
-        let theme_vars_str_value = expr_to_str(
-          theme_vars_item.value.as_ref(),
-          state,
-          &FunctionMap::default(),
-        );
-
-        theme_vars_str_value
-      }
-      EvaluateResultValue::ThemeRef(theme_ref) => theme_ref.get(key.as_str()).0.clone(),
-      _ => unimplemented!("Unsupported theme vars type"),
-    };
-
-    let name_hash = theme_vars_str_value[6..theme_vars_str_value.len() - 1].to_string();
+    let name_hash = get_theme_var_name_hash(theme_vars, &key, state);
 
     let css_value = get_css_value(key_value);
 
     let value = FlatCompiledStylesValue::Tuple(name_hash, css_value.0, css_value.1);
 
-    collect_vars_by_at_rules(&key, &value, &mut rules_by_at_rule, &[], typed_variables);
+    collect_vars_by_at_rules(
+      &key,
+      &value,
+      &mut rules_by_at_rule,
+      &[],
+      typed_variables,
+      &state.options,
+    );
   }
 
   // Sort @-rules to get a consistent unique hash value
@@ -166,3 +165,280 @@ pub(crate) fn stylex_create_theme(
 
   (resolved_theme_vars, styles_to_inject)
 }
+
+/// Merges the overrides of a theme into the overrides of its base theme.
+/// A variable overridden by the theme replaces the base value with all of its conditions.
+pub(crate) fn merge_theme_overrides(
+  base_overrides: &Expr,
+  overrides: &EvaluateResultValue,
+) -> EvaluateResultValue {
+  let overrides = overrides
+    .as_expr()
+    .and_then(|expr| expr.as_object())
+    .expect("Overrides must be an object");
+
+  let override_keys = get_key_values_from_object(overrides)
+    .iter()
+    .map(get_key_str)
+    .collect::<Vec<String>>();
+
+  let mut props = base_overrides
+    .as_object()
+    .map(|base_overrides| {
+      base_overrides
+        .props
+        .iter()
+        .filter(|prop| {
+          prop
+            .as_prop()
+            .and_then(|prop| prop.as_key_value())
+            .map_or(true, |key_value| {
+              !override_keys.contains(&get_key_str(key_value))
+            })
+        })
+        .cloned()
+        .collect::<Vec<PropOrSpread>>()
+    })
+    .unwrap_or_default();
+
+  props.extend(overrides.props.iter().cloned());
+
+  EvaluateResultValue::Expr(Box::new(Expr::Object(ObjectLit {
+    span: DUMMY_SP,
+    props,
+  })))
+}
+
+fn get_theme_var_name_hash(
+  theme_vars: &mut EvaluateResultValue,
+  key: &str,
+  state: &mut StateManager,
+) -> String {
+  let theme_vars_str_value = match theme_vars {
+    EvaluateResultValue::Expr(expr) => {
+      let theme_vars_key_values = get_key_values_from_object(expr.as_object().unwrap());
+      let theme_vars_item = theme_vars_key_values
+        .iter()
+        .find(|key_value| {
+          let local_key = get_key_str(key_value);
+
+          local_key == key
+        })
+        .unwrap_or_else(|| panic!(r#"{} "{}"."#, UNKNOWN_THEME_VARIABLE, key));
+
+      expr_to_str(
+        theme_vars_item.value.as_ref(),
+        state,
+        &FunctionMap::default(),
+      )
+    }
+    EvaluateResultValue::ThemeRef(theme_ref) => theme_ref.get(key).0.clone(),
+    _ => unimplemented!("Unsupported theme vars type"),
+  };
+
+  theme_vars_str_value[6..theme_vars_str_value.len() - 1].to_string()
+}
+
+/// Reports override keys that are not defined by the theme variables and,
+/// with `strictThemes`, the variables that are not overridden.
+/// Keys can only be checked when the variables object is known at compile time.
+/// Imported variables whose module cannot be resolved are reported with a
+/// warning, or an error with `strictThemes`, as any key would be accepted.
+pub(crate) fn validate_theme_override_keys(
+  theme_vars: &EvaluateResultValue,
+  overrides: &EvaluateResultValue,
+  source: &Expr,
+  vars_name: &str,
+  state: &StateManager,
+) {
+  if let EvaluateResultValue::ThemeRef(_) = theme_vars {
+    let message = format!(r#"{} "{}"."#, UNCHECKED_THEME_VARIABLES, vars_name);
+
+    if state.options.strict_themes {
+      panic_with_span(source.span(), &message);
+    }
+
+    warn!("{}", message);
+
+    return;
+  }
+
+  let Some(theme_vars) = theme_vars.as_expr().and_then(|expr| expr.as_object()) else {
+    return;
+  };
+
+  let Some(overrides) = overrides.as_expr().and_then(|expr| expr.as_object()) else {
+    return;
+  };
+
+  let theme_keys = get_key_values_from_object(theme_vars)
+    .iter()
+    .map(get_key_str)
+    .filter(|key| key != THEME_NAME_KEY)
+    .collect::<Vec<String>>();
+
+  let override_keys = get_key_values_from_object(overrides)
+    .iter()
+    .map(get_key_str)
+    .collect::<Vec<String>>();
+
+  for key in override_keys.iter() {
+    if theme_keys.contains(key) {
+      continue;
+    }
+
+    let max_distance = (key.len() / 4).max(1);
+
+    let suggestion =
+      get_closest_match(key, theme_keys.iter().map(|key| key.as_str()), max_distance)
+        .map(|suggestion| format!(r#" Did you mean "{}"?"#, suggestion))
+        .unwrap_or_default();
+
+    let span = find_source_key(source, key).unwrap_or(source.span());
+
+    panic_with_span(
+      span,
+      &format!(
+        r#"{} "{}" in "{}".{}"#,
+        UNKNOWN_THEME_VARIABLE, key, vars_name, suggestion
+      ),
+    );
+  }
+
+  if !state.options.strict_themes {
+    return;
+  }
+
+  let missing_keys = theme_keys
+    .iter()
+    .filter(|key| !override_keys.contains(key))
+    .map(|key| format!(r#""{}""#, key))
+    .collect::<Vec<String>>();
+
+  if !missing_keys.is_empty() {
+    panic_with_span(
+      source.span(),
+      &format!(
+        r#"{} of "{}". Missing: {}."#,
+        MISSING_THEME_VARIABLES,
+        vars_name,
+        missing_keys.join(", ")
+      ),
+    );
+  }
+}
+
+fn find_source_key(source: &Expr, key: &str) -> Option<Span> {
+  source.as_object()?.props.iter().find_map(|prop| {
+    let key_value = prop.as_prop()?.as_key_value()?;
+
+    (get_key_str(key_value) == key).then_some(key_value.key.span())
+  })
+}
+
+/// Checks the overrides of typed variables against the syntax declared in `defineVars`.
+/// `source` is the overrides argument as written, used to point errors at the invalid value.
+pub(crate) fn validate_theme_overrides(
+  theme_vars: &mut EvaluateResultValue,
+  overrides: &EvaluateResultValue,
+  source: &Expr,
+  state: &mut StateManager,
+) {
+  if state.typed_variable_syntaxes.is_empty() {
+    return;
+  }
+
+  let Some(overrides) = overrides.as_expr().and_then(|expr| expr.as_object()) else {
+    return;
+  };
+
+  for key_value in get_key_values_from_object(overrides) {
+    let key = get_key_str(&key_value);
+
+    let name_hash = get_theme_var_name_hash(theme_vars, &key, state);
+
+    let Some(syntax) = state.typed_variable_syntaxes.get(&name_hash).cloned() else {
+      continue;
+    };
+
+    let (value, _) = get_css_value(key_value);
+
+    validate_override_value(&key, &value, &syntax, &mut vec![key.clone()], source);
+  }
+}
+
+/// Checks the override value, and each of its conditional values, against the syntax.
+/// `path` holds the keys leading to the value, starting with the variable name.
+fn validate_override_value(
+  key: &str,
+  value: &Expr,
+  syntax: &CSSSyntax,
+  path: &mut Vec<String>,
+  source: &Expr,
+) {
+  match value {
+    Expr::Lit(Lit::Null(_)) => {}
+    Expr::Lit(lit) => {
+      let Some(value) = get_string_val_from_lit(lit) else {
+        return;
+      };
+
+      if matches_css_syntax(&value, syntax) {
+        return;
+      }
+
+      let at_rules = path[1..]
+        .iter()
+        .filter(|condition| condition.as_str() != "default")
+        .cloned()
+        .collect::<Vec<String>>();
+
+      let condition = if at_rules.is_empty() {
+        String::default()
+      } else {
+        format!(r#" under "{}""#, at_rules.join(" "))
+      };
+
+      let span = find_source_value(source, path).unwrap_or(source).span();
+
+      panic_with_span(
+        span,
+        &format!(
+          r#"{}: "{}"{} expects {}, got "{}"."#,
+          INVALID_THEME_OVERRIDE_VALUE, key, condition, syntax, value
+        ),
+      );
+    }
+    Expr::Object(obj) => {
+      for key_value in get_key_values_from_object(obj) {
+        path.push(get_key_str(&key_value));
+
+        validate_override_value(key, &key_value.value, syntax, path, source);
+
+        path.pop();
+      }
+    }
+    _ => {}
+  }
+}
+
+/// Finds the expression written at `path` inside of an object literal,
+/// looking through `stylex.types.*` wrappers
+fn find_source_value<'a>(source: &'a Expr, path: &[String]) -> Option<&'a Expr> {
+  let source = match source {
+    Expr::Call(call) => call.args.first().map_or(source, |arg| arg.expr.as_ref()),
+    _ => source,
+  };
+
+  let Some((key, rest)) = path.split_first() else {
+    return Some(source);
+  };
+
+  let value = source.as_object()?.props.iter().find_map(|prop| {
+    let key_value = prop.as_prop()?.as_key_value()?;
+
+    (&get_key_str(key_value) == key).then_some(key_value.value.as_ref())
+  })?;
+
+  find_source_value(value, rest)
+}
@@ -9,7 +9,9 @@ use crate::shared::{
   structures::{injectable_style::InjectableStyle, state_manager::StateManager},
   utils::{
     common::{create_hash, get_css_value},
-    core::define_vars_utils::construct_css_variables_string,
+    core::{
+      define_vars_utils::construct_css_variables_string, design_tokens::create_design_token_group,
+    },
     object::obj_map,
   },
 };
@@ -84,18 +86,45 @@ pub(crate) fn stylex_define_vars(
     },
   );
 
-  let injectable_styles =
-    construct_css_variables_string(&variables_map, &theme_name_hash, &mut typed_variables);
+  if state.options.design_tokens {
+    let theme_name = state.theme_name.clone().unwrap();
+    let export_name = theme_name.rsplit("//").next().unwrap_or(&theme_name);
+
+    let design_tokens = create_design_token_group(&variables_map, &state.options);
+
+    state
+      .design_tokens
+      .insert(export_name.to_string(), design_tokens);
+  }
+
+  let injectable_styles = construct_css_variables_string(
+    &variables_map,
+    &theme_name_hash,
+    &mut typed_variables,
+    &state.options,
+  );
+
+  for typed_variable in typed_variables.values() {
+    if let FlatCompiledStylesValue::CSSType(name_hash, syntax, _, _) = typed_variable.as_ref() {
+      state
+        .typed_variable_syntaxes
+        .insert(name_hash.clone(), syntax.clone());
+    }
+  }
 
   let injectable_types = obj_map(
     ObjMapType::Map(typed_variables),
     state,
     |item, _| -> Box<FlatCompiledStylesValue> {
       let result = match item.as_ref() {
-        FlatCompiledStylesValue::CSSType(name_hash, syntax, initial_value) => {
+        FlatCompiledStylesValue::CSSType(name_hash, syntax, initial_value, options) => {
+          if !options.register {
+            return Box::new(FlatCompiledStylesValue::Null);
+          }
+
           let property = format!(
-            "@property --{} {{ syntax: \"{}\"; inherits: true; initial-value: {} }}",
-            name_hash, syntax, initial_value
+            "@property --{} {{ syntax: \"{}\"; inherits: {}; initial-value: {} }}",
+            name_hash, syntax, options.inherits, initial_value
           );
 
           FlatCompiledStylesValue::InjectableStyle(InjectableStyle {
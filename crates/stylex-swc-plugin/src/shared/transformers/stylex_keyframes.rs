@@ -1,7 +1,11 @@
 use indexmap::IndexMap;
-use swc_core::ecma::ast::Expr;
+use swc_core::{
+  common::Spanned,
+  ecma::ast::{Expr, KeyValueProp, Lit, ObjectLit, PropOrSpread},
+};
 
 use crate::shared::{
+  constants::messages::INVALID_KEYFRAMES_AT_RULE,
   enums::data_structures::{
     evaluate_result_value::EvaluateResultValue,
     flat_compiled_styles_value::FlatCompiledStylesValue, obj_map_type::ObjMapType,
@@ -15,9 +19,15 @@ use crate::shared::{
     state_manager::StateManager,
   },
   utils::{
-    ast::convertors::{expr_to_str, string_to_expression},
-    common::{create_hash, dashify, get_key_str},
-    core::flat_map_expanded_shorthands::flat_map_expanded_shorthands,
+    ast::{
+      convertors::{expr_to_str, string_to_expression},
+      factories::{object_expression_factory, object_lit_factory, prop_or_spread_string_factory},
+    },
+    common::{create_hash, dashify, get_key_str, get_key_values_from_object},
+    core::{
+      define_vars_utils::wrap_with_at_rules,
+      flat_map_expanded_shorthands::flat_map_expanded_shorthands,
+    },
     css::common::{generate_ltr, generate_rtl, transform_value},
     object::{obj_entries, obj_from_entries, obj_map, obj_map_keys, Pipe},
   },
@@ -27,81 +37,222 @@ pub(crate) fn stylex_keyframes(
   frames: &EvaluateResultValue,
   state: &mut StateManager,
 ) -> (String, InjectableStyle) {
-  let mut class_name_prefix = state.options.class_name_prefix.clone();
+  let Some(frames) = frames.as_expr().and_then(|expr| expr.as_object()) else {
+    panic!("Values must be an object")
+  };
 
-  if class_name_prefix.is_empty() {
-    class_name_prefix = "x".to_string();
-  }
+  let frames = object_lit_factory(
+    frames
+      .props
+      .iter()
+      .filter(|prop| !is_at_rule_prop(prop))
+      .cloned()
+      .collect(),
+  );
 
+  compile_keyframes(&frames, None, state)
+}
+
+/// Compiles the frames nested under at-rules, like `@media (prefers-reduced-motion: reduce)`,
+/// into separately named keyframes wrapped in their at-rule.
+/// Returns the animation name and the injectable style of each variant by its at-rule.
+pub(crate) fn stylex_keyframes_variants(
+  frames: &EvaluateResultValue,
+  state: &mut StateManager,
+) -> IndexMap<String, (String, InjectableStyle)> {
   let Some(frames) = frames.as_expr().and_then(|expr| expr.as_object()) else {
     panic!("Values must be an object")
   };
 
-  let extended_object = obj_map(ObjMapType::Object(frames.clone()), state, |frame, state| {
-    let Some((_, frame, _)) = frame.as_tuple() else {
-      panic!("Values must be an object")
-    };
-
-    let pipe_result = Pipe::create(frame)
-      .pipe(|frame| expand_frame_shorthands(frame, state))
-      .pipe(|entries| obj_map_keys(&entries, dashify))
-      .pipe(|entries| {
-        obj_map(
-          ObjMapType::Map(entries),
-          state,
-          |entry, state| match entry.as_ref() {
-            FlatCompiledStylesValue::KeyValue(pair) => {
-              Box::new(FlatCompiledStylesValue::KeyValue(Pair {
-                key: pair.key.clone(),
-                value: transform_value(pair.key.as_str(), pair.value.as_str(), state),
-              }))
-            }
-            _ => panic!("Entry must be a tuple of key and value"),
-          },
-        )
-      })
-      .done();
-
-    let (_, result) = pipe_result.into_iter().next().unwrap_or((
-      String::default(),
-      Box::new(FlatCompiledStylesValue::KeyValue(Pair::new(
-        String::default(),
-        String::default(),
-      ))),
-    ));
-
-    result
-  });
-
-  let ltr_styles = obj_map(
-    ObjMapType::Map(extended_object.clone()),
-    state,
-    |frame, _| {
-      let Some(pair) = frame.as_key_value() else {
-        panic!("Values must be an object")
+  get_key_values_from_object(frames)
+    .into_iter()
+    .filter_map(|key_value| {
+      let at_rule = get_key_str(&key_value);
+
+      if !at_rule.starts_with('@') {
+        return None;
+      }
+
+      let Some(variant_frames) = key_value.value.as_object() else {
+        panic!("{}", INVALID_KEYFRAMES_AT_RULE)
       };
 
-      let ltr_value = generate_ltr(pair);
+      let variant = compile_keyframes(variant_frames, Some(&at_rule), state);
 
-      Box::new(FlatCompiledStylesValue::KeyValue(ltr_value))
-    },
+      Some((at_rule, variant))
+    })
+    .collect()
+}
+
+/// Registers the variants of a keyframes animation, so that `animationName` can refer to them
+pub(crate) fn add_keyframes_variants(
+  animation_name: &str,
+  variants: &IndexMap<String, (String, InjectableStyle)>,
+  state: &mut StateManager,
+) {
+  if variants.is_empty() {
+    return;
+  }
+
+  state.keyframes_variants.insert(
+    animation_name.to_string(),
+    variants
+      .iter()
+      .map(|(at_rule, (variant_name, _))| (at_rule.clone(), variant_name.clone()))
+      .collect(),
   );
+}
 
-  let rtl_styles = obj_map(
-    ObjMapType::Map(extended_object.clone()),
-    state,
-    |frame, _| {
-      let Some(pair) = frame.as_key_value() else {
-        panic!("Values must be an object")
-      };
+/// Replaces the `animationName` values of `stylex.create` namespaces that refer to keyframes
+/// with variants by conditional values, picking the variant under its at-rule
+pub(crate) fn apply_keyframes_variants(
+  namespaces: &EvaluateResultValue,
+  state: &StateManager,
+) -> EvaluateResultValue {
+  if state.keyframes_variants.is_empty() {
+    return namespaces.clone();
+  }
 
-      let rtl_value = generate_rtl(pair);
+  let apply = |key_value: &mut KeyValueProp| {
+    if get_key_str(key_value) == "animationName" {
+      key_value.value = Box::new(with_keyframes_variants(&key_value.value, state));
+    }
+  };
 
-      Box::new(FlatCompiledStylesValue::KeyValue(
-        rtl_value.unwrap_or(pair.clone()),
-      ))
-    },
-  );
+  match namespaces {
+    EvaluateResultValue::Map(namespaces) => {
+      let mut namespaces = namespaces.clone();
+
+      namespaces
+        .values_mut()
+        .flat_map(|namespace| namespace.iter_mut())
+        .for_each(apply);
+
+      EvaluateResultValue::Map(namespaces)
+    }
+    EvaluateResultValue::Expr(expr) if expr.is_object() => {
+      let mut namespaces = expr.clone();
+
+      namespaces
+        .as_mut_object()
+        .into_iter()
+        .flat_map(|namespaces| namespaces.props.iter_mut())
+        .filter_map(|prop| {
+          prop
+            .as_mut_prop()?
+            .as_mut_key_value()?
+            .value
+            .as_mut_object()
+        })
+        .flat_map(|namespace| namespace.props.iter_mut())
+        .filter_map(|prop| prop.as_mut_prop()?.as_mut_key_value())
+        .for_each(apply);
+
+      EvaluateResultValue::Expr(namespaces)
+    }
+    _ => namespaces.clone(),
+  }
+}
+
+fn with_keyframes_variants(value: &Expr, state: &StateManager) -> Expr {
+  match value {
+    Expr::Lit(Lit::Str(strng)) => {
+      let animation_names = strng
+        .value
+        .split(',')
+        .map(|animation_name| animation_name.trim())
+        .collect::<Vec<&str>>();
+
+      let mut at_rules: Vec<&String> = vec![];
+
+      for animation_name in animation_names.iter() {
+        if let Some(variants) = state.keyframes_variants.get(*animation_name) {
+          for at_rule in variants.keys() {
+            if !at_rules.contains(&at_rule) {
+              at_rules.push(at_rule);
+            }
+          }
+        }
+      }
+
+      if at_rules.is_empty() {
+        return value.clone();
+      }
+
+      let mut props = vec![prop_or_spread_string_factory("default", &strng.value)];
+
+      for at_rule in at_rules {
+        let variant_value = animation_names
+          .iter()
+          .map(|animation_name| {
+            state
+              .keyframes_variants
+              .get(*animation_name)
+              .and_then(|variants| variants.get(at_rule))
+              .map_or(*animation_name, |variant_name| variant_name.as_str())
+          })
+          .collect::<Vec<&str>>()
+          .join(", ");
+
+        props.push(prop_or_spread_string_factory(at_rule, &variant_value));
+      }
+
+      object_expression_factory(props)
+    }
+    Expr::Object(object) => {
+      let mut object = object.clone();
+
+      for prop in object.props.iter_mut() {
+        if let Some(key_value) = prop.as_mut_prop().and_then(|prop| prop.as_mut_key_value()) {
+          key_value.value = Box::new(with_keyframes_variants(&key_value.value, state));
+        }
+      }
+
+      Expr::Object(object)
+    }
+    _ => value.clone(),
+  }
+}
+
+fn is_at_rule_prop(prop: &PropOrSpread) -> bool {
+  prop
+    .as_prop()
+    .and_then(|prop| prop.as_key_value())
+    .is_some_and(|key_value| get_key_str(key_value).starts_with('@'))
+}
+
+fn compile_keyframes(
+  frames: &ObjectLit,
+  at_rule: Option<&str>,
+  state: &mut StateManager,
+) -> (String, InjectableStyle) {
+  let mut class_name_prefix = state.options.class_name_prefix.clone();
+
+  if class_name_prefix.is_empty() {
+    class_name_prefix = "x".to_string();
+  }
+
+  let extended_object = get_key_values_from_object(frames)
+    .iter()
+    .map(|frame| (get_key_str(frame), expand_declarations(&frame.value, state)))
+    .collect::<IndexMap<String, Vec<Pair>>>();
+
+  let ltr_styles = extended_object
+    .iter()
+    .map(|(key, declarations)| (key.clone(), declarations.iter().map(generate_ltr).collect()))
+    .collect::<IndexMap<String, Vec<Pair>>>();
+
+  let rtl_styles = extended_object
+    .iter()
+    .map(|(key, declarations)| {
+      let declarations = declarations
+        .iter()
+        .map(|pair| generate_rtl(pair).unwrap_or(pair.clone()))
+        .collect();
+
+      (key.clone(), declarations)
+    })
+    .collect::<IndexMap<String, Vec<Pair>>>();
 
   let ltr_string = construct_keyframes_obj(&ltr_styles);
   let rtl_string = construct_keyframes_obj(&rtl_styles);
@@ -109,14 +260,22 @@ pub(crate) fn stylex_keyframes(
   let animation_name = format!(
     "{}{}-B",
     class_name_prefix,
-    create_hash(&format!("<>{}", ltr_string))
+    create_hash(&format!("<>{}{}", at_rule.unwrap_or_default(), ltr_string))
   );
 
-  let ltr = format!("@keyframes {}{{{}}}", animation_name, ltr_string);
+  let wrap = |keyframes: String| match at_rule {
+    Some(at_rule) => wrap_with_at_rules(&keyframes, at_rule),
+    None => keyframes,
+  };
+
+  let ltr = wrap(format!("@keyframes {}{{{}}}", animation_name, ltr_string));
   let rtl = if ltr_string == rtl_string {
     None
   } else {
-    Some(format!("@keyframes {}{{{}}}", animation_name, rtl_string))
+    Some(wrap(format!(
+      "@keyframes {}{{{}}}",
+      animation_name, rtl_string
+    )))
   };
 
   (
@@ -129,22 +288,45 @@ pub(crate) fn stylex_keyframes(
   )
 }
 
-fn construct_keyframes_obj(frames: &IndexMap<String, Box<FlatCompiledStylesValue>>) -> String {
-  frames
-    .into_iter()
-    .map(|(key, value)| {
-      let value = match value.as_ref() {
-        FlatCompiledStylesValue::KeyValue(pair) => {
-          let Pair { key, value } = pair;
-
-          if key.is_empty() || value.is_empty() {
-            String::default()
-          } else {
-            format!("{}:{};", key, value)
+/// Expands the shorthands of a block of declarations, like a frame,
+/// and dashifies and normalizes the declarations
+pub(crate) fn expand_declarations(declarations: &Expr, state: &mut StateManager) -> Vec<Pair> {
+  let span = declarations.span();
+
+  Pipe::create(declarations)
+    .pipe(|declarations| expand_frame_shorthands(declarations, state))
+    .pipe(|entries| obj_map_keys(&entries, dashify))
+    .pipe(|entries| {
+      obj_map(
+        ObjMapType::Map(entries),
+        state,
+        |entry, state| match entry.as_ref() {
+          FlatCompiledStylesValue::KeyValue(pair) => {
+            Box::new(FlatCompiledStylesValue::KeyValue(Pair {
+              key: pair.key.clone(),
+              value: transform_value(pair.key.as_str(), pair.value.as_str(), span, state),
+            }))
           }
-        }
-        _ => panic!("Value must be a key value pair"),
-      };
+          _ => panic!("Entry must be a tuple of key and value"),
+        },
+      )
+    })
+    .done()
+    .into_values()
+    .filter_map(|declaration| declaration.as_key_value().cloned())
+    .collect()
+}
+
+fn construct_keyframes_obj(frames: &IndexMap<String, Vec<Pair>>) -> String {
+  frames
+    .iter()
+    .map(|(key, declarations)| {
+      let value = declarations
+        .iter()
+        .filter(|Pair { key, value }| !key.is_empty() && !value.is_empty())
+        .map(|Pair { key, value }| format!("{}:{};", key, value))
+        .collect::<Vec<String>>()
+        .join("");
 
       format!("{}{{{}}}", key, value)
     })
@@ -177,13 +359,24 @@ fn expand_frame_shorthands(frame: &Expr, state: &mut StateManager) -> IndexMap<S
 pub(crate) fn get_keyframes_fn() -> FunctionConfig {
   FunctionConfig {
     fn_ptr: FunctionType::StylexExprFn(|expr: Expr, local_state: &mut StateManager| -> Expr {
-      let (animation_name, injected_style) =
-        stylex_keyframes(&EvaluateResultValue::Expr(Box::new(expr)), local_state);
+      let frames = EvaluateResultValue::Expr(Box::new(expr));
+
+      let (animation_name, injected_style) = stylex_keyframes(&frames, local_state);
+
+      let variants = stylex_keyframes_variants(&frames, local_state);
+
+      add_keyframes_variants(&animation_name, &variants, local_state);
 
       local_state
         .injected_keyframes
         .insert(animation_name.clone(), Box::new(injected_style));
 
+      for (variant_name, variant_style) in variants.into_values() {
+        local_state
+          .injected_keyframes
+          .insert(variant_name, Box::new(variant_style));
+      }
+
       let result = string_to_expression(animation_name.as_str());
 
       result
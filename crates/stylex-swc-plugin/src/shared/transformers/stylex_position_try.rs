@@ -0,0 +1,125 @@
+use phf::phf_set;
+
+use crate::shared::{
+  enums::data_structures::evaluate_result_value::EvaluateResultValue,
+  structures::{injectable_style::InjectableStyle, pair::Pair, state_manager::StateManager},
+  utils::{
+    common::create_hash,
+    css::common::{generate_ltr, generate_rtl},
+  },
+};
+
+use super::stylex_keyframes::expand_declarations;
+
+/// The properties allowed within a `@position-try` rule
+pub(crate) static POSITION_TRY_PROPERTIES: phf::Set<&'static str> = phf_set! {
+  // Anchor Positioning
+  "positionAnchor",
+  "positionArea",
+
+  // Inset
+  "inset",
+  "insetBlock",
+  "insetBlockEnd",
+  "insetBlockStart",
+  "insetInline",
+  "insetInlineEnd",
+  "insetInlineStart",
+  "top",
+  "right",
+  "bottom",
+  "left",
+  "start",
+  "end",
+
+  // Margin
+  "margin",
+  "marginBlock",
+  "marginBlockEnd",
+  "marginBlockStart",
+  "marginInline",
+  "marginInlineEnd",
+  "marginInlineStart",
+  "marginTop",
+  "marginRight",
+  "marginBottom",
+  "marginLeft",
+  "marginStart",
+  "marginEnd",
+
+  // Sizing
+  "width",
+  "height",
+  "minWidth",
+  "minHeight",
+  "maxWidth",
+  "maxHeight",
+  "blockSize",
+  "inlineSize",
+  "minBlockSize",
+  "minInlineSize",
+  "maxBlockSize",
+  "maxInlineSize",
+
+  // Self Alignment
+  "alignSelf",
+  "justifySelf",
+  "placeSelf",
+};
+
+pub(crate) fn stylex_position_try(
+  styles: &EvaluateResultValue,
+  state: &mut StateManager,
+) -> (String, InjectableStyle) {
+  let mut class_name_prefix = state.options.class_name_prefix.clone();
+
+  if class_name_prefix.is_empty() {
+    class_name_prefix = "x".to_string();
+  }
+
+  let Some(styles) = styles.as_expr() else {
+    panic!("Values must be an object")
+  };
+
+  let declarations = expand_declarations(styles, state);
+
+  let ltr_string = construct_declarations(declarations.iter().map(generate_ltr));
+  let rtl_string = construct_declarations(
+    declarations
+      .iter()
+      .map(|pair| generate_rtl(pair).unwrap_or(pair.clone())),
+  );
+
+  let position_try_name = format!(
+    "--{}{}",
+    class_name_prefix,
+    create_hash(&format!("<>{}", ltr_string))
+  );
+
+  let ltr = format!("@position-try {}{{{}}}", position_try_name, ltr_string);
+  let rtl = if ltr_string == rtl_string {
+    None
+  } else {
+    Some(format!(
+      "@position-try {}{{{}}}",
+      position_try_name, rtl_string
+    ))
+  };
+
+  (
+    position_try_name,
+    InjectableStyle {
+      ltr,
+      rtl,
+      priority: Some(0.0),
+    },
+  )
+}
+
+fn construct_declarations(declarations: impl Iterator<Item = Pair>) -> String {
+  declarations
+    .filter(|Pair { key, value }| !key.is_empty() && !value.is_empty())
+    .map(|Pair { key, value }| format!("{}:{};", key, value))
+    .collect::<Vec<String>>()
+    .join("")
+}
@@ -1,8 +1,9 @@
 use crate::shared::{
+  constants::messages::SYNTAX_MUST_BE_STRING,
   enums::data_structures::{css_syntax::CSSSyntax, value_with_default::ValueWithDefault},
   structures::{
-    base_css_type::BaseCSSType,
-    functions::{FunctionConfig, FunctionType},
+    base_css_type::{BaseCSSType, CSSTypeOptions},
+    functions::{FunctionConfig, FunctionType, StylexTypeFn},
   },
   utils::ast::factories::{object_expression_factory, prop_or_spread_string_factory},
 };
@@ -27,6 +28,7 @@ impl HasBase for Angle {
       base: BaseCSSType {
         value,
         syntax: CSSSyntax::Angle,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -42,6 +44,7 @@ impl HasBase for Color {
       base: BaseCSSType {
         value,
         syntax: CSSSyntax::Color,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -57,6 +60,7 @@ impl HasBase for Url {
       base: BaseCSSType {
         value,
         syntax: CSSSyntax::Url,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -72,6 +76,7 @@ impl HasBase for Image {
       base: BaseCSSType {
         value,
         syntax: CSSSyntax::Image,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -90,6 +95,7 @@ impl HasBase for Integer {
           "0".to_string(),
         )(value),
         syntax: CSSSyntax::Integer,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -105,6 +111,7 @@ impl HasBase for LengthPercentage {
       base: BaseCSSType {
         value: convert_number_to_percentage(value),
         syntax: CSSSyntax::LengthPercentage,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -120,6 +127,7 @@ impl HasBase for Length {
       base: BaseCSSType {
         value: convert_number_to_length(value),
         syntax: CSSSyntax::Length,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -135,6 +143,7 @@ impl HasBase for Percentage {
       base: BaseCSSType {
         value: convert_number_to_percentage(value),
         syntax: CSSSyntax::Percentage,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -150,6 +159,7 @@ impl HasBase for Num {
       base: BaseCSSType {
         value: convert_number_to_bare_string(value),
         syntax: CSSSyntax::Number,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -165,6 +175,7 @@ impl HasBase for Resolution {
       base: BaseCSSType {
         value,
         syntax: CSSSyntax::Resolution,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -180,6 +191,7 @@ impl HasBase for Time {
       base: BaseCSSType {
         value,
         syntax: CSSSyntax::Time,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -195,6 +207,7 @@ impl HasBase for TransformFunction {
       base: BaseCSSType {
         value,
         syntax: CSSSyntax::TransformFunction,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -210,6 +223,54 @@ impl HasBase for TransformList {
       base: BaseCSSType {
         value,
         syntax: CSSSyntax::TransformList,
+        options: CSSTypeOptions::default(),
+      },
+    }
+  }
+}
+pub struct CustomIdent {
+  base: BaseCSSType,
+}
+
+impl HasBase for CustomIdent {
+  fn new(value: ValueWithDefault) -> Self {
+    CustomIdent {
+      base: BaseCSSType {
+        value,
+        syntax: CSSSyntax::CustomIdent,
+        options: CSSTypeOptions::default(),
+      },
+    }
+  }
+}
+
+pub struct Str {
+  base: BaseCSSType,
+}
+
+impl HasBase for Str {
+  fn new(value: ValueWithDefault) -> Self {
+    Str {
+      base: BaseCSSType {
+        value,
+        syntax: CSSSyntax::String,
+        options: CSSTypeOptions::default(),
+      },
+    }
+  }
+}
+
+pub struct Universal {
+  base: BaseCSSType,
+}
+
+impl HasBase for Universal {
+  fn new(value: ValueWithDefault) -> Self {
+    Universal {
+      base: BaseCSSType {
+        value,
+        syntax: CSSSyntax::Universal,
+        options: CSSTypeOptions::default(),
       },
     }
   }
@@ -350,6 +411,24 @@ impl From<Url> for BaseCSSType {
   }
 }
 
+impl From<CustomIdent> for BaseCSSType {
+  fn from(instance: CustomIdent) -> Self {
+    instance.base
+  }
+}
+
+impl From<Str> for BaseCSSType {
+  fn from(instance: Str) -> Self {
+    instance.base
+  }
+}
+
+impl From<Universal> for BaseCSSType {
+  fn from(instance: Universal) -> Self {
+    instance.base
+  }
+}
+
 impl From<BaseCSSType> for Expr {
   fn from(instance: BaseCSSType) -> Self {
     let syntax_prop =
@@ -357,90 +436,143 @@ impl From<BaseCSSType> for Expr {
 
     let mut props = vec![syntax_prop];
 
+    let options_props = instance.options_to_props();
+
     props.extend(BaseCSSType::value_to_props(instance.value, None));
+    props.extend(options_props);
 
     object_expression_factory(props)
   }
 }
-fn angle(value: ValueWithDefault) -> Expr {
+fn angle(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Angle::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn color(value: ValueWithDefault) -> Expr {
+fn color(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Color::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn image(value: ValueWithDefault) -> Expr {
+fn image(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Image::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn integer(value: ValueWithDefault) -> Expr {
+fn integer(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Integer::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn length(value: ValueWithDefault) -> Expr {
+fn length(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Length::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn length_percentage(value: ValueWithDefault) -> Expr {
+fn length_percentage(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = LengthPercentage::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn num(value: ValueWithDefault) -> Expr {
+fn num(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Num::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn resolution(value: ValueWithDefault) -> Expr {
+fn resolution(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Resolution::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn percentage(value: ValueWithDefault) -> Expr {
+fn percentage(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Percentage::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn time(value: ValueWithDefault) -> Expr {
+fn time(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Time::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn transform_function(value: ValueWithDefault) -> Expr {
+fn transform_function(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = TransformFunction::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn transform_list(value: ValueWithDefault) -> Expr {
+fn transform_list(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = TransformList::new(value).into();
 
-  base_css_type.into()
+  base_css_type.with_options(options).into()
 }
 
-fn url(value: ValueWithDefault) -> Expr {
+fn url(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
   let base_css_type: BaseCSSType = Url::new(value).into();
 
+  base_css_type.with_options(options).into()
+}
+
+fn custom_ident(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
+  let base_css_type: BaseCSSType = CustomIdent::new(value).into();
+
+  base_css_type.with_options(options).into()
+}
+
+fn string(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
+  let base_css_type: BaseCSSType = Str::new(value).into();
+
+  base_css_type.with_options(options).into()
+}
+
+fn universal(value: ValueWithDefault, options: CSSTypeOptions) -> Expr {
+  let base_css_type: BaseCSSType = Universal::new(value).into();
+
+  base_css_type.with_options(options).into()
+}
+
+/// `stylex.types.syntax('<length> | auto', value, options)` registers a variable with
+/// any `@property` syntax, including multipliers and unions.
+fn syntax(args: Vec<ValueWithDefault>) -> Expr {
+  let mut args = args.into_iter();
+
+  let Some(ValueWithDefault::String(syntax)) = args.next() else {
+    panic!("{}", SYNTAX_MUST_BE_STRING)
+  };
+
+  let base_css_type = BaseCSSType {
+    value: into_value_map(
+      args
+        .next()
+        .unwrap_or(ValueWithDefault::Map(IndexMap::new())),
+    ),
+    syntax: CSSSyntax::from(syntax),
+    options: CSSTypeOptions::from(args.next()),
+  };
+
   base_css_type.into()
 }
 
-pub(crate) static FN_MAP: phf::Map<&'static str, fn(value: ValueWithDefault) -> Expr> = phf_map! {
+fn into_value_map(value: ValueWithDefault) -> ValueWithDefault {
+  match value {
+    ValueWithDefault::Map(_) => value,
+    value => ValueWithDefault::Map(IndexMap::from([("default".to_string(), value)])),
+  }
+}
+
+pub(crate) static FN_MAP: phf::Map<
+  &'static str,
+  fn(value: ValueWithDefault, options: CSSTypeOptions) -> Expr,
+> = phf_map! {
   "angle" => angle,
   "color" => color,
   "image" => image,
@@ -454,20 +586,32 @@ pub(crate) static FN_MAP: phf::Map<&'static str, fn(value: ValueWithDefault) ->
   "transformFunction" => transform_function,
   "transformList" => transform_list,
   "url" => url,
-
+  "customIdent" => custom_ident,
+  "string" => string,
+  "any" => universal,
 };
 
 pub(crate) fn get_types_fn() -> FunctionConfig {
   FunctionConfig {
-    fn_ptr: FunctionType::StylexFnsFactory(
-      |prop_name| -> Rc<dyn Fn(ValueWithDefault) -> Expr + 'static> {
-        Rc::new(
-          *FN_MAP
-            .get(prop_name.as_str())
-            .unwrap_or_else(|| panic!(r#"Function "{}" not found"#, prop_name)),
-        )
-      },
-    ),
+    fn_ptr: FunctionType::StylexFnsFactory(|prop_name| -> StylexTypeFn {
+      if prop_name == "syntax" {
+        return Rc::new(syntax);
+      }
+
+      let type_fn = *FN_MAP
+        .get(prop_name.as_str())
+        .unwrap_or_else(|| panic!(r#"Function "{}" not found"#, prop_name));
+
+      Rc::new(move |args: Vec<ValueWithDefault>| {
+        let mut args = args.into_iter();
+
+        let value = args
+          .next()
+          .unwrap_or(ValueWithDefault::Map(IndexMap::new()));
+
+        type_fn(into_value_map(value), CSSTypeOptions::from(args.next()))
+      })
+    }),
     takes_path: false,
   }
 }
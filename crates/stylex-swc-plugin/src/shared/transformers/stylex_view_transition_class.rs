@@ -0,0 +1,104 @@
+use crate::shared::{
+  enums::data_structures::evaluate_result_value::EvaluateResultValue,
+  structures::{injectable_style::InjectableStyle, pair::Pair, state_manager::StateManager},
+  utils::{
+    common::{create_hash, get_key_str, get_key_values_from_object},
+    css::common::{generate_ltr, generate_rtl},
+  },
+};
+
+use super::stylex_keyframes::expand_declarations;
+
+/// Maps the keys of a `stylex.viewTransitionClass` call to their view transition pseudo-elements
+pub(crate) static VIEW_TRANSITION_PSEUDO_ELEMENTS: [(&str, &str); 4] = [
+  ("group", "::view-transition-group"),
+  ("imagePair", "::view-transition-image-pair"),
+  ("old", "::view-transition-old"),
+  ("new", "::view-transition-new"),
+];
+
+pub(crate) fn stylex_view_transition_class(
+  styles: &EvaluateResultValue,
+  state: &mut StateManager,
+) -> (String, InjectableStyle) {
+  let mut class_name_prefix = state.options.class_name_prefix.clone();
+
+  if class_name_prefix.is_empty() {
+    class_name_prefix = "x".to_string();
+  }
+
+  let Some(styles) = styles.as_expr().and_then(|expr| expr.as_object()) else {
+    panic!("Values must be an object")
+  };
+
+  let style_strings = get_key_values_from_object(styles)
+    .iter()
+    .map(|key_value| {
+      let key = get_key_str(key_value);
+
+      let declarations = expand_declarations(&key_value.value, state);
+
+      let ltr_string = construct_declarations(declarations.iter().map(generate_ltr));
+      let rtl_string = construct_declarations(
+        declarations
+          .iter()
+          .map(|pair| generate_rtl(pair).unwrap_or(pair.clone())),
+      );
+
+      (key, ltr_string, rtl_string)
+    })
+    .collect::<Vec<(String, String, String)>>();
+
+  let class_name = format!(
+    "{}{}",
+    class_name_prefix,
+    create_hash(
+      &style_strings
+        .iter()
+        .map(|(key, ltr_string, _)| format!("{}:{{{}}}", key, ltr_string))
+        .collect::<Vec<String>>()
+        .join("")
+    )
+  );
+
+  let construct_rules = |get_declarations: fn(&(String, String, String)) -> &String| {
+    style_strings
+      .iter()
+      .filter_map(|style_string| {
+        let (_, pseudo_element) = VIEW_TRANSITION_PSEUDO_ELEMENTS
+          .iter()
+          .find(|(name, _)| *name == style_string.0)?;
+
+        Some(format!(
+          "{}(*.{}){{{}}}",
+          pseudo_element,
+          class_name,
+          get_declarations(style_string)
+        ))
+      })
+      .collect::<Vec<String>>()
+      .join("")
+  };
+
+  let ltr = construct_rules(|(_, ltr_string, _)| ltr_string);
+  let rtl = construct_rules(|(_, _, rtl_string)| rtl_string);
+
+  let rtl = if ltr == rtl { None } else { Some(rtl) };
+
+  (
+    class_name,
+    InjectableStyle {
+      ltr,
+      rtl,
+      priority: Some(1.0),
+    },
+  )
+}
+
+fn construct_declarations(declarations: impl Iterator<Item = Pair>) -> String {
+  declarations
+    .filter(|Pair { key, value }| !key.is_empty() && !value.is_empty())
+    .map(|Pair { key, value }| format!("{}:{};", key, value))
+    .collect::<Vec<String>>()
+    .join("")
+}
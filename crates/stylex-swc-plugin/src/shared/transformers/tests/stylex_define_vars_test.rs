@@ -821,10 +821,10 @@ mod stylex_define_vars {
   }
 
   fn type_fabric(
-    func: &Rc<dyn Fn(ValueWithDefault) -> Expr>,
+    func: &Rc<dyn Fn(Vec<ValueWithDefault>) -> Expr>,
     types: ValueWithDefault,
   ) -> BaseCSSType {
-    let result = func(types);
+    let result = func(vec![types]);
     let result_object = result.as_object();
     let css_type: BaseCSSType = result_object.unwrap().clone().into();
 
@@ -87,4 +87,24 @@ mod stylex_keyframes {
 
     assert_eq!(result, *expected_result.get(key.as_str()).unwrap())
   }
+
+  #[test]
+  fn keeps_every_declaration_of_a_frame() {
+    let keyframes = default_vars_factory(&[
+      ("from", &[("opacity", "0"), ("transform", "scale(0)")]),
+      ("to", &[("opacity", "1"), ("transform", "scale(1)")]),
+    ]);
+
+    let (key, result) = stylex_keyframes(&keyframes, &mut StateManager::default());
+
+    let expected_result = exprected_css_result_factory(&[(
+      "x1ixh1oh-B",
+      (
+        "@keyframes x1ixh1oh-B{from{opacity:0;transform:scale(0);}to{opacity:1;transform:scale(1);}}",
+        1.0,
+      ),
+    )]);
+
+    assert_eq!(result, *expected_result.get(key.as_str()).unwrap())
+  }
 }
@@ -2,23 +2,35 @@
 mod class_methods {
   use swc_core::ecma::ast::Expr;
 
+  use indexmap::IndexMap;
+
   use crate::shared::{
     enums::data_structures::value_with_default::ValueWithDefault,
-    transformers::stylex_types::FN_MAP,
-    utils::ast::factories::{object_expression_factory, prop_or_spread_string_factory},
+    structures::{base_css_type::CSSTypeOptions, functions::FunctionType},
+    transformers::stylex_types::{get_types_fn, FN_MAP},
+    utils::{
+      ast::factories::{
+        object_expression_factory, prop_or_spread_boolean_factory, prop_or_spread_expr_factory,
+        prop_or_spread_string_factory,
+      },
+      common::dashify,
+    },
   };
 
   fn assert_type_factory(syntax: &str, value: ValueWithDefault, expected_value: &str) {
     let angle = FN_MAP.get(syntax).unwrap();
 
-    let result = angle(value);
+    let result = angle(value, CSSTypeOptions::default());
 
-    assert_eq!(result, type_factory(syntax, expected_value));
+    assert_eq!(
+      result,
+      type_factory(format!("<{}>", dashify(syntax)).as_str(), expected_value)
+    );
   }
 
   fn type_factory(syntax: &str, value: &str) -> Expr {
     object_expression_factory(vec![
-      prop_or_spread_string_factory("syntax", format!("<{}>", syntax).as_str()),
+      prop_or_spread_string_factory("syntax", syntax),
       prop_or_spread_string_factory("value", value),
     ])
   }
@@ -124,4 +136,114 @@ mod class_methods {
 
     assert_type_factory("url", ValueWithDefault::String(value.to_string()), value);
   }
+
+  #[test]
+  fn custom_ident() {
+    assert_type_factory(
+      "customIdent",
+      ValueWithDefault::String("main".to_string()),
+      "main",
+    );
+  }
+
+  #[test]
+  fn string() {
+    assert_type_factory(
+      "string",
+      ValueWithDefault::String("'hello'".to_string()),
+      "'hello'",
+    );
+  }
+
+  #[test]
+  fn any() {
+    let result = FN_MAP.get("any").unwrap()(
+      ValueWithDefault::String("1fr 2fr".to_string()),
+      CSSTypeOptions::default(),
+    );
+
+    assert_eq!(result, type_factory("*", "1fr 2fr"));
+  }
+
+  #[test]
+  fn syntax() {
+    let FunctionType::StylexFnsFactory(types_fn) = get_types_fn().fn_ptr else {
+      unreachable!()
+    };
+
+    let result = types_fn("syntax".to_string())(vec![
+      ValueWithDefault::String("<length>+ | auto".to_string()),
+      ValueWithDefault::String("auto".to_string()),
+    ]);
+
+    assert_eq!(
+      result,
+      object_expression_factory(vec![
+        prop_or_spread_string_factory("syntax", "<length>+ | auto"),
+        prop_or_spread_expr_factory(
+          "value",
+          vec![prop_or_spread_string_factory("default", "auto")]
+        ),
+      ])
+    );
+
+    let result = types_fn("length".to_string())(vec![ValueWithDefault::Map(IndexMap::from([(
+      "default".to_string(),
+      ValueWithDefault::Number(4.0),
+    )]))]);
+
+    assert_eq!(
+      result,
+      object_expression_factory(vec![
+        prop_or_spread_string_factory("syntax", "<length>"),
+        prop_or_spread_expr_factory(
+          "value",
+          vec![prop_or_spread_string_factory("default", "4px")]
+        ),
+      ])
+    );
+  }
+
+  #[test]
+  fn options() {
+    let FunctionType::StylexFnsFactory(types_fn) = get_types_fn().fn_ptr else {
+      unreachable!()
+    };
+
+    let result = types_fn("color".to_string())(vec![
+      ValueWithDefault::String("red".to_string()),
+      ValueWithDefault::Map(IndexMap::from([
+        (
+          "inherits".to_string(),
+          ValueWithDefault::String("false".to_string()),
+        ),
+        (
+          "initialValue".to_string(),
+          ValueWithDefault::String("black".to_string()),
+        ),
+      ])),
+    ]);
+
+    assert_eq!(
+      result,
+      object_expression_factory(vec![
+        prop_or_spread_string_factory("syntax", "<color>"),
+        prop_or_spread_expr_factory(
+          "value",
+          vec![prop_or_spread_string_factory("default", "red")]
+        ),
+        prop_or_spread_boolean_factory("inherits", Some(false)),
+        prop_or_spread_string_factory("initialValue", "black"),
+      ])
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = r#"Invalid option for a typed variable: "inherit" is not supported."#)]
+  fn rejects_unknown_options() {
+    let _ = CSSTypeOptions::from(Some(ValueWithDefault::Map(IndexMap::from([(
+      "inherit".to_string(),
+      ValueWithDefault::String("false".to_string()),
+    )]))));
+  }
 }
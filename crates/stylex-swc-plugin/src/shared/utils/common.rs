@@ -8,7 +8,7 @@ use std::{
 };
 use swc_core::{
   atoms::Atom,
-  common::{FileName, DUMMY_SP},
+  common::{errors::HANDLER, FileName, Span, DUMMY_SP},
   ecma::ast::{
     BinaryOp, Decl, Expr, Ident, ImportDecl, ImportSpecifier, KeyValueProp, Lit, MemberExpr,
     Module, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, Pat, Prop, PropName, PropOrSpread,
@@ -606,3 +606,53 @@ pub(crate) fn sort_numbers_factory() -> impl FnMut(&f64, &f64) -> std::cmp::Orde
 pub(crate) fn char_code_at(s: &str, index: usize) -> Option<u32> {
   s.chars().nth(index).map(|c| c as u32)
 }
+
+pub(crate) fn levenshtein_distance(a: &str, b: &str) -> usize {
+  let b_chars = b.chars().collect::<Vec<char>>();
+  let mut previous_row = (0..=b_chars.len()).collect::<Vec<usize>>();
+
+  for (i, a_char) in a.chars().enumerate() {
+    let mut current_row = vec![i + 1];
+
+    for (j, b_char) in b_chars.iter().enumerate() {
+      let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+
+      current_row.push(
+        (previous_row[j] + substitution_cost)
+          .min(previous_row[j + 1] + 1)
+          .min(current_row[j] + 1),
+      );
+    }
+
+    previous_row = current_row;
+  }
+
+  previous_row[b_chars.len()]
+}
+
+/// Returns the candidate closest to `target` by edit distance,
+/// as long as it is within `max_distance` edits.
+pub(crate) fn get_closest_match<'a, I>(
+  target: &str,
+  candidates: I,
+  max_distance: usize,
+) -> Option<String>
+where
+  I: IntoIterator<Item = &'a str>,
+{
+  candidates
+    .into_iter()
+    .map(|candidate| (levenshtein_distance(target, candidate), candidate))
+    .filter(|(distance, _)| *distance <= max_distance)
+    .min_by(|(a_distance, a), (b_distance, b)| a_distance.cmp(b_distance).then(a.cmp(b)))
+    .map(|(_, candidate)| candidate.to_string())
+}
+
+/// Reports the error at `span` when a diagnostics handler is available, then aborts the transform.
+pub(crate) fn panic_with_span(span: Span, message: &str) -> ! {
+  if !span.is_dummy() && HANDLER.is_set() {
+    HANDLER.with(|handler| handler.struct_span_err(span, message).emit());
+  }
+
+  panic!("{}", message)
+}
@@ -1,13 +1,18 @@
 use convert_case::{Case, Casing};
+use swc_core::common::Span;
 
 use crate::shared::{
-  constants::messages::{ILLEGAL_PROP_VALUE, NON_CONTIGUOUS_VARS},
+  constants::messages::{
+    ILLEGAL_PROP_VALUE, IMPORTANT_NOT_ALLOWED, MIXED_IMPORTANT_FALLBACKS, NON_CONTIGUOUS_VARS,
+  },
+  regex::IMPORTANT_REGEX,
   structures::{
     injectable_style::InjectableStyle, pre_rule::PreRuleValue, state_manager::StateManager,
+    stylex_options::ImportantPolicy,
   },
   utils::{
-    common::{create_hash, dashify},
-    css::common::{generate_rule, transform_value},
+    common::{create_hash, dashify, panic_with_span},
+    css::common::{generate_rule, normalize_at_rule, transform_value},
   },
 };
 
@@ -16,6 +21,7 @@ pub(crate) fn convert_style_to_class_name(
   pseudos: &mut [String],
   at_rules: &mut [String],
   prefix: &str,
+  span: Span,
   state: &StateManager,
 ) -> (String, String, InjectableStyle) {
   let (key, raw_value) = obj_entry;
@@ -26,6 +32,10 @@ pub(crate) fn convert_style_to_class_name(
     dashify(key).to_case(Case::Kebab)
   };
 
+  for at_rule in at_rules.iter_mut() {
+    *at_rule = normalize_at_rule(at_rule, span, &state.options);
+  }
+
   let sorted_pseudos = &mut pseudos.to_vec();
   sorted_pseudos.sort();
 
@@ -43,14 +53,45 @@ pub(crate) fn convert_style_to_class_name(
     modifier_hash_string
   };
 
-  let value = match raw_value {
-    PreRuleValue::String(value) => PreRuleValue::String(transform_value(key, value, state)),
-    PreRuleValue::Vec(vec) => PreRuleValue::Vec(
-      vec
+  let (value, important) = match raw_value {
+    PreRuleValue::String(value) => {
+      let (value, important) = strip_important(key, value, state);
+
+      (
+        PreRuleValue::String(transform_value(key, &value, span, state)),
+        important,
+      )
+    }
+    PreRuleValue::Vec(vec) => {
+      let (values, flags): (Vec<String>, Vec<bool>) = vec
         .iter()
-        .map(|each_value| transform_value(key, each_value.as_str(), state))
-        .collect::<Vec<String>>(),
-    ),
+        .map(|each_value| strip_important(key, each_value, state))
+        .unzip();
+
+      // `!important` applies to the whole declaration, so it cannot be set on
+      // only some of the fallbacks
+      if flags.iter().any(|flag| *flag != flags[0]) {
+        panic_with_span(
+          span,
+          &format!(
+            r#"{} in "{}: [{}]"."#,
+            MIXED_IMPORTANT_FALLBACKS,
+            key,
+            vec.join(", ")
+          ),
+        );
+      }
+
+      let values = values
+        .iter()
+        .map(|value| transform_value(key, value, span, state))
+        .collect::<Vec<String>>();
+
+      (
+        PreRuleValue::Vec(values),
+        flags.first().copied().unwrap_or(false),
+      )
+    }
     PreRuleValue::Expr(_) => panic!("{}", ILLEGAL_PROP_VALUE),
     PreRuleValue::Null => panic!("{}", ILLEGAL_PROP_VALUE),
   };
@@ -71,10 +112,11 @@ pub(crate) fn convert_style_to_class_name(
   };
 
   let string_to_hash = format!(
-    "<>{}{}{}",
+    "<>{}{}{}{}",
     dashed_key,
     value.join(", "),
-    modifier_hash_string
+    modifier_hash_string,
+    if important { "!important" } else { "" }
   );
 
   let class_name_hashed = format!("{}{}", prefix, create_hash(string_to_hash.as_str()));
@@ -85,11 +127,27 @@ pub(crate) fn convert_style_to_class_name(
     &value,
     pseudos,
     at_rules,
+    important,
+    &state.options,
   );
 
   (key.to_string(), class_name_hashed, css_rules)
 }
 
+/// Removes a trailing `!important` from the value, so that it is neither normalized
+/// nor hashed as part of the value, and reports whether it was present.
+fn strip_important(key: &str, value: &str, state: &StateManager) -> (String, bool) {
+  if !IMPORTANT_REGEX.is_match(value) {
+    return (value.to_string(), false);
+  }
+
+  if state.options.important_policy == ImportantPolicy::Reject {
+    panic!(r#"{} in "{}: {}"."#, IMPORTANT_NOT_ALLOWED, key, value);
+  }
+
+  (IMPORTANT_REGEX.replace(value, "").to_string(), true)
+}
+
 fn variable_fallbacks(values: Vec<String>) -> Vec<String> {
   let first_var = values
     .iter()
@@ -1,26 +1,43 @@
 use std::ops::Mul;
 
 use indexmap::IndexMap;
-use swc_core::ecma::ast::{Expr, Lit};
+use swc_core::{
+  common::Spanned,
+  ecma::ast::{Expr, Lit},
+};
 
 use crate::shared::{
-  constants::common::SPLIT_TOKEN,
+  constants::{common::SPLIT_TOKEN, messages::INVALID_INITIAL_VALUE},
   enums::data_structures::{
     flat_compiled_styles_value::FlatCompiledStylesValue, value_with_default::ValueWithDefault,
   },
-  structures::injectable_style::InjectableStyle,
-  utils::common::{create_hash, get_key_str, get_key_values_from_object, get_string_val_from_lit},
+  structures::{injectable_style::InjectableStyle, stylex_state_options::StyleXStateOptions},
+  utils::{
+    common::{create_hash, get_key_str, get_key_values_from_object, get_string_val_from_lit},
+    css::{
+      common::{convert_px_to_rem_in_value, normalize_at_rule},
+      syntax::matches_css_syntax,
+    },
+  },
 };
 
 pub(crate) fn construct_css_variables_string(
   variables: &IndexMap<String, Box<FlatCompiledStylesValue>>,
   theme_name_hash: &String,
   typed_variables: &mut IndexMap<String, Box<FlatCompiledStylesValue>>,
+  options: &StyleXStateOptions,
 ) -> IndexMap<String, Box<InjectableStyle>> {
   let mut rules_by_at_rule: IndexMap<String, Vec<String>> = IndexMap::new();
 
   for (key, value) in variables.iter() {
-    collect_vars_by_at_rules(key, value, &mut rules_by_at_rule, &[], typed_variables);
+    collect_vars_by_at_rules(
+      key,
+      value,
+      &mut rules_by_at_rule,
+      &[],
+      typed_variables,
+      options,
+    );
   }
 
   let mut result: IndexMap<String, Box<InjectableStyle>> = IndexMap::new();
@@ -57,6 +74,7 @@ pub(crate) fn collect_vars_by_at_rules(
   collection: &mut IndexMap<String, Vec<String>>,
   at_rules: &[String],
   typed_variables: &mut IndexMap<String, Box<FlatCompiledStylesValue>>,
+  options: &StyleXStateOptions,
 ) {
   let Some((hash_name, value, css_type)) = value.as_tuple() else {
     panic!("Props must be an key value pair")
@@ -65,14 +83,27 @@ pub(crate) fn collect_vars_by_at_rules(
   if let Some(css_type) = css_type {
     let values = css_type.value.as_map().expect("Value must be an map");
 
-    let initial_value = get_nitial_value_of_css_type(values);
+    let initial_value = match &css_type.options.initial_value {
+      Some(initial_value) => {
+        if initial_value.contains("var(") || !matches_css_syntax(initial_value, &css_type.syntax) {
+          panic!(
+            r#"{}: "{}" is not a valid initial value for "{}"."#,
+            INVALID_INITIAL_VALUE, initial_value, css_type.syntax
+          );
+        }
+
+        initial_value.clone()
+      }
+      None => get_nitial_value_of_css_type(values),
+    };
 
     typed_variables.insert(
       hash_name.clone(),
       Box::new(FlatCompiledStylesValue::CSSType(
         hash_name.clone(),
-        css_type.syntax,
-        initial_value.clone(),
+        css_type.syntax.clone(),
+        initial_value,
+        css_type.options.clone(),
       )),
     );
   }
@@ -84,7 +115,15 @@ pub(crate) fn collect_vars_by_at_rules(
         return;
       }
 
-      let val = get_string_val_from_lit(lit).expect("Value must be a string");
+      let mut val = get_string_val_from_lit(lit).expect("Value must be a string");
+
+      if let Some(px_to_rem) = options
+        .px_to_rem
+        .as_ref()
+        .filter(|px_to_rem| px_to_rem.define_vars)
+      {
+        val = convert_px_to_rem_in_value(val.as_str(), px_to_rem);
+      }
 
       let key = if at_rules.is_empty() {
         "default".to_string()
@@ -111,11 +150,13 @@ pub(crate) fn collect_vars_by_at_rules(
       }
 
       for key_value in key_values.iter() {
-        let at_rule = get_key_str(key_value);
+        let mut at_rule = get_key_str(key_value);
 
         let extended_at_rules = if at_rule == "default" {
           at_rules.to_vec()
         } else {
+          at_rule = normalize_at_rule(at_rule.as_str(), key_value.key.span(), options);
+
           let mut new_at_rule = at_rules.to_vec();
           new_at_rule.push(at_rule.clone());
           new_at_rule
@@ -129,6 +170,7 @@ pub(crate) fn collect_vars_by_at_rules(
           collection,
           &extended_at_rules,
           typed_variables,
+          options,
         );
       }
     }
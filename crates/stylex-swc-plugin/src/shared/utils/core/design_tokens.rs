@@ -0,0 +1,119 @@
+use indexmap::IndexMap;
+use serde_json::{json, Map, Value};
+
+use crate::shared::{
+  constants::common::SPLIT_TOKEN,
+  enums::data_structures::{
+    css_syntax::CSSSyntax, flat_compiled_styles_value::FlatCompiledStylesValue,
+  },
+  structures::stylex_state_options::StyleXStateOptions,
+};
+
+use super::define_vars_utils::collect_vars_by_at_rules;
+
+/// Vendor key of the `$extensions` entry that carries StyleX specific data
+pub(crate) static DESIGN_TOKENS_EXTENSION: &str = "com.stylexjs";
+
+/// Builds a Design Tokens Community Group group for the variables of a
+/// `stylex.defineVars` call, keyed by the variable names.
+pub(crate) fn create_design_token_group(
+  variables: &IndexMap<String, Box<FlatCompiledStylesValue>>,
+  options: &StyleXStateOptions,
+) -> Value {
+  let mut group = Map::new();
+
+  for (key, value) in variables {
+    let Some((hash_name, _, css_type)) = value.as_tuple() else {
+      continue;
+    };
+
+    let mut values_by_at_rule: IndexMap<String, Vec<String>> = IndexMap::new();
+
+    collect_vars_by_at_rules(
+      key,
+      value,
+      &mut values_by_at_rule,
+      &[],
+      &mut IndexMap::new(),
+      options,
+    );
+
+    let declaration_prefix = format!("--{}:", hash_name);
+
+    let mut values = values_by_at_rule
+      .into_iter()
+      .filter_map(|(at_rule, declarations)| {
+        let value = declarations
+          .first()?
+          .strip_prefix(declaration_prefix.as_str())?
+          .strip_suffix(';')?
+          .to_string();
+
+        Some((at_rule.replace(SPLIT_TOKEN, " "), value))
+      })
+      .collect::<IndexMap<String, String>>();
+
+    let Some(default_value) = values.shift_remove("default") else {
+      continue;
+    };
+
+    let syntax = css_type.as_ref().map(|css_type| &css_type.syntax);
+    let token_type = syntax.and_then(get_token_type);
+
+    let mut extension = Map::new();
+
+    extension.insert("variable".to_string(), json!(format!("--{}", hash_name)));
+
+    if let Some(syntax) = syntax {
+      extension.insert("syntax".to_string(), json!(syntax.to_string()));
+    }
+
+    if !values.is_empty() {
+      let conditions = values
+        .into_iter()
+        .map(|(at_rule, value)| (at_rule, get_token_value(value, token_type)))
+        .collect::<Map<String, Value>>();
+
+      extension.insert("conditions".to_string(), Value::Object(conditions));
+    }
+
+    let mut token = Map::new();
+
+    token.insert(
+      "$value".to_string(),
+      get_token_value(default_value, token_type),
+    );
+
+    if let Some(token_type) = token_type {
+      token.insert("$type".to_string(), json!(token_type));
+    }
+
+    token.insert(
+      "$extensions".to_string(),
+      json!({ DESIGN_TOKENS_EXTENSION: extension }),
+    );
+
+    group.insert(key.clone(), Value::Object(token));
+  }
+
+  Value::Object(group)
+}
+
+fn get_token_type(syntax: &CSSSyntax) -> Option<&'static str> {
+  match syntax {
+    CSSSyntax::Color => Some("color"),
+    CSSSyntax::Length => Some("dimension"),
+    CSSSyntax::Number | CSSSyntax::Integer => Some("number"),
+    CSSSyntax::Time => Some("duration"),
+    _ => None,
+  }
+}
+
+fn get_token_value(value: String, token_type: Option<&str>) -> Value {
+  match token_type {
+    Some("number") => value
+      .parse::<f64>()
+      .map_or(json!(value), |number| json!(number)),
+    _ => json!(value),
+  }
+}
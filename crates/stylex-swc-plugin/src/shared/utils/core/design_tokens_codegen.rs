@@ -0,0 +1,477 @@
+use convert_case::{Case, Casing};
+use indexmap::IndexMap;
+use serde_json::Value;
+use swc_compiler_base::{print, PrintArgs, SourceMapsConfig};
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::ast::{
+    CallExpr, Callee, Decl, ExportDecl, Expr, ExprOrSpread, Ident, ImportDecl, ImportPhase,
+    ImportSpecifier, ImportStarAsSpecifier, MemberExpr, MemberProp, Module, ModuleDecl, ModuleItem,
+    Pat, PropOrSpread, Str, VarDecl, VarDeclKind, VarDeclarator,
+  },
+};
+
+use crate::shared::{
+  constants::messages::{INVALID_DESIGN_TOKENS, UNKNOWN_TOKEN_MODE, UNRESOLVED_TOKEN_ALIAS},
+  enums::data_structures::css_syntax::CSSSyntax,
+  utils::ast::{
+    convertors::string_to_expression,
+    factories::{
+      binding_ident_factory, ident_factory, object_expression_factory,
+      prop_or_spread_expression_factory,
+    },
+  },
+};
+
+use super::design_tokens::DESIGN_TOKENS_EXTENSION;
+
+/// Options of the `stylex.defineVars` module generated from design tokens
+pub struct DefineVarsModuleOptions {
+  pub import_source: String,
+  /// At-rules of the `$modes` of the tokens, e.g. `dark` to `@media (prefers-color-scheme: dark)`
+  pub modes: IndexMap<String, String>,
+}
+
+impl Default for DefineVarsModuleOptions {
+  fn default() -> Self {
+    DefineVarsModuleOptions {
+      import_source: "@stylexjs/stylex".to_string(),
+      modes: IndexMap::from([
+        (
+          "light".to_string(),
+          "@media (prefers-color-scheme: light)".to_string(),
+        ),
+        (
+          "dark".to_string(),
+          "@media (prefers-color-scheme: dark)".to_string(),
+        ),
+        (
+          "reducedMotion".to_string(),
+          "@media (prefers-reduced-motion: reduce)".to_string(),
+        ),
+        (
+          "highContrast".to_string(),
+          "@media (prefers-contrast: more)".to_string(),
+        ),
+      ]),
+    }
+  }
+}
+
+static GENERATED_MODULE_PREAMBLE: &str =
+  "// This file is generated from design tokens, do not edit it manually.\n";
+
+/// Generates a module with a `stylex.defineVars` export for every top level
+/// group of a Design Tokens Community Group JSON document.
+///
+/// Token keys are emitted in sorted order, so regenerating the module from the
+/// same tokens always gives the same output.
+pub fn generate_define_vars_module(tokens: &str, options: &DefineVarsModuleOptions) -> String {
+  let root: Value = serde_json::from_str(tokens)
+    .unwrap_or_else(|error| panic!("{}: {}", INVALID_DESIGN_TOKENS, error));
+
+  let Value::Object(groups) = &root else {
+    panic!("{}: the document must be an object.", INVALID_DESIGN_TOKENS)
+  };
+
+  let stylex_ident = ident_factory("stylex");
+
+  let mut body = vec![ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+    span: DUMMY_SP,
+    specifiers: vec![ImportSpecifier::Namespace(ImportStarAsSpecifier {
+      span: DUMMY_SP,
+      local: stylex_ident.clone(),
+    })],
+    src: Box::new(Str {
+      span: DUMMY_SP,
+      raw: None,
+      value: options.import_source.as_str().into(),
+    }),
+    type_only: false,
+    with: None,
+    phase: ImportPhase::Evaluation,
+  }))];
+
+  for (group_name, group) in groups.iter().filter(|(key, _)| !key.starts_with('$')) {
+    if !group.is_object() || group.get("$value").is_some() {
+      panic!(
+        r#"{}: top level "{}" must be a group of tokens."#,
+        INVALID_DESIGN_TOKENS, group_name
+      );
+    }
+
+    let mut props = vec![];
+
+    collect_token_props(&root, group, &[], None, options, &mut props);
+
+    let define_vars_call = stylex_call(
+      &stylex_ident,
+      &["defineVars"],
+      vec![object_expression_factory(props)],
+    );
+
+    body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+      span: DUMMY_SP,
+      decl: Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+          span: DUMMY_SP,
+          name: Pat::Ident(binding_ident_factory(ident_factory(
+            group_name.to_case(Case::Camel).as_str(),
+          ))),
+          init: Some(Box::new(define_vars_call)),
+          definite: false,
+        }],
+      })),
+    })));
+  }
+
+  let module = Module {
+    span: DUMMY_SP,
+    body,
+    shebang: None,
+  };
+
+  print(
+    Default::default(),
+    &module,
+    PrintArgs {
+      source_map: SourceMapsConfig::Bool(false),
+      preamble: GENERATED_MODULE_PREAMBLE,
+      ..Default::default()
+    },
+  )
+  .expect("Failed to print the generated module")
+  .code
+}
+
+fn collect_token_props(
+  root: &Value,
+  group: &Value,
+  path: &[String],
+  inherited_type: Option<&str>,
+  options: &DefineVarsModuleOptions,
+  props: &mut Vec<PropOrSpread>,
+) {
+  let Value::Object(entries) = group else {
+    return;
+  };
+
+  let token_type = entries
+    .get("$type")
+    .and_then(Value::as_str)
+    .or(inherited_type);
+
+  for (key, token) in entries.iter().filter(|(key, _)| !key.starts_with('$')) {
+    let mut token_path = path.to_vec();
+    token_path.push(key.clone());
+
+    if token.get("$value").is_none() {
+      collect_token_props(root, token, &token_path, token_type, options, props);
+
+      continue;
+    }
+
+    let var_name = if key.starts_with("--") {
+      key.clone()
+    } else {
+      token_path.join("-").to_case(Case::Camel)
+    };
+
+    let token_type = token.get("$type").and_then(Value::as_str).or(token_type);
+
+    props.push(prop_or_spread_expression_factory(
+      var_name.as_str(),
+      token_to_expression(root, token, token_type, options),
+    ));
+  }
+}
+
+fn token_to_expression(
+  root: &Value,
+  token: &Value,
+  token_type: Option<&str>,
+  options: &DefineVarsModuleOptions,
+) -> Expr {
+  let default_value = token_value_to_string(root, &token["$value"], token_type, 0);
+
+  let extension = token
+    .get("$extensions")
+    .and_then(|extensions| extensions.get(DESIGN_TOKENS_EXTENSION));
+
+  let mut conditions: Vec<(Vec<String>, String)> = vec![];
+
+  if let Some(Value::Object(modes)) = token.get("$modes") {
+    for (mode, value) in modes {
+      let at_rule = if mode.starts_with('@') {
+        mode.clone()
+      } else {
+        options
+          .modes
+          .get(mode)
+          .cloned()
+          .unwrap_or_else(|| panic!(r#"{}: "{}"."#, UNKNOWN_TOKEN_MODE, mode))
+      };
+
+      conditions.push((
+        vec![at_rule],
+        token_value_to_string(root, value, token_type, 0),
+      ));
+    }
+  }
+
+  if let Some(Value::Object(extension_conditions)) =
+    extension.and_then(|extension| extension.get("conditions"))
+  {
+    for (condition, value) in extension_conditions {
+      conditions.push((
+        split_at_rules(condition),
+        token_value_to_string(root, value, token_type, 0),
+      ));
+    }
+  }
+
+  let has_nested_conditions = conditions.iter().any(|(at_rules, _)| at_rules.len() > 1);
+
+  let value = if conditions.is_empty() {
+    string_to_expression(default_value.as_str())
+  } else {
+    let mut tree = ConditionTree::Value(default_value);
+
+    for (at_rules, value) in conditions {
+      tree.insert(&at_rules, value);
+    }
+
+    tree.into_expression()
+  };
+
+  let syntax = extension
+    .and_then(|extension| extension.get("syntax"))
+    .and_then(Value::as_str);
+
+  // Typed variables only accept flat conditions, so nested ones are kept untyped
+  if has_nested_conditions {
+    return value;
+  }
+
+  let stylex_ident = ident_factory("stylex");
+
+  match (syntax, token_type.and_then(get_types_fn_name)) {
+    (Some(syntax), _) => match get_types_fn_name_by_syntax(syntax) {
+      Some(types_fn_name) => stylex_call(&stylex_ident, &["types", types_fn_name], vec![value]),
+      None => stylex_call(
+        &stylex_ident,
+        &["types", "syntax"],
+        vec![string_to_expression(syntax), value],
+      ),
+    },
+    (None, Some(types_fn_name)) => {
+      stylex_call(&stylex_ident, &["types", types_fn_name], vec![value])
+    }
+    (None, None) => value,
+  }
+}
+
+fn get_types_fn_name(token_type: &str) -> Option<&'static str> {
+  match token_type {
+    "color" => Some("color"),
+    "dimension" => Some("length"),
+    "number" => Some("number"),
+    "duration" => Some("time"),
+    _ => None,
+  }
+}
+
+fn get_types_fn_name_by_syntax(syntax: &str) -> Option<&'static str> {
+  match CSSSyntax::from(syntax.to_string()) {
+    CSSSyntax::Angle => Some("angle"),
+    CSSSyntax::Color => Some("color"),
+    CSSSyntax::Image => Some("image"),
+    CSSSyntax::Integer => Some("integer"),
+    CSSSyntax::Length => Some("length"),
+    CSSSyntax::LengthPercentage => Some("lengthPercentage"),
+    CSSSyntax::Number => Some("number"),
+    CSSSyntax::Percentage => Some("percentage"),
+    CSSSyntax::Resolution => Some("resolution"),
+    CSSSyntax::Time => Some("time"),
+    CSSSyntax::TransformFunction => Some("transformFunction"),
+    CSSSyntax::TransformList => Some("transformList"),
+    CSSSyntax::Url => Some("url"),
+    CSSSyntax::CustomIdent => Some("customIdent"),
+    CSSSyntax::String => Some("string"),
+    CSSSyntax::Universal => Some("any"),
+    CSSSyntax::Keyword(_) | CSSSyntax::Multiplier(_, _) | CSSSyntax::Union(_) => None,
+  }
+}
+
+/// Conditions of exported tokens join nested at-rules with spaces,
+/// e.g. `@media (prefers-color-scheme: dark) @supports (color: oklab(0 0 0))`
+fn split_at_rules(condition: &str) -> Vec<String> {
+  let mut at_rules = vec![];
+  let mut depth = 0;
+  let mut start = 0;
+
+  for (index, chr) in condition.char_indices() {
+    match chr {
+      '(' => depth += 1,
+      ')' => depth -= 1,
+      '@' if depth == 0 && index > start => {
+        at_rules.push(condition[start..index].trim().to_string());
+        start = index;
+      }
+      _ => {}
+    }
+  }
+
+  at_rules.push(condition[start..].trim().to_string());
+
+  at_rules
+}
+
+fn token_value_to_string(
+  root: &Value,
+  value: &Value,
+  token_type: Option<&str>,
+  depth: usize,
+) -> String {
+  match value {
+    Value::String(value) => match value
+      .strip_prefix('{')
+      .and_then(|value| value.strip_suffix('}'))
+    {
+      Some(alias) => {
+        if depth > 16 {
+          panic!(
+            r#"{}: "{{{}}}" is circular."#,
+            UNRESOLVED_TOKEN_ALIAS, alias
+          );
+        }
+
+        let aliased_value = alias
+          .split('.')
+          .try_fold(root, |value, key| value.get(key))
+          .and_then(|token| token.get("$value"))
+          .unwrap_or_else(|| panic!(r#"{}: "{{{}}}"."#, UNRESOLVED_TOKEN_ALIAS, alias));
+
+        token_value_to_string(root, aliased_value, token_type, depth + 1)
+      }
+      None => value.clone(),
+    },
+    Value::Number(value) => value.to_string(),
+    Value::Bool(value) => value.to_string(),
+    Value::Array(values) => {
+      let values = values
+        .iter()
+        .map(|value| match (token_type, value) {
+          (Some("fontFamily"), Value::String(font)) if font.contains(' ') => {
+            format!(r#""{}""#, font)
+          }
+          _ => token_value_to_string(root, value, token_type, depth),
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+      match token_type {
+        Some("cubicBezier") => format!("cubic-bezier({})", values),
+        _ => values,
+      }
+    }
+    Value::Object(object) => match (object.get("value"), object.get("unit")) {
+      (Some(value), Some(Value::String(unit))) => {
+        format!(
+          "{}{}",
+          token_value_to_string(root, value, token_type, depth),
+          unit
+        )
+      }
+      _ => panic!(
+        "{}: composite values are not supported, got {}.",
+        INVALID_DESIGN_TOKENS, value
+      ),
+    },
+    Value::Null => panic!("{}: a token value cannot be null.", INVALID_DESIGN_TOKENS),
+  }
+}
+
+/// Values of a token by nested at-rules
+enum ConditionTree {
+  Value(String),
+  Conditions(IndexMap<String, ConditionTree>),
+}
+
+impl ConditionTree {
+  fn insert(&mut self, at_rules: &[String], value: String) {
+    let Some((at_rule, rest)) = at_rules.split_first() else {
+      match self {
+        ConditionTree::Conditions(conditions) => {
+          conditions.insert("default".to_string(), ConditionTree::Value(value));
+        }
+        ConditionTree::Value(_) => *self = ConditionTree::Value(value),
+      }
+
+      return;
+    };
+
+    if let ConditionTree::Value(default_value) = self {
+      *self = ConditionTree::Conditions(IndexMap::from([(
+        "default".to_string(),
+        ConditionTree::Value(default_value.clone()),
+      )]));
+    }
+
+    let ConditionTree::Conditions(conditions) = self else {
+      unreachable!()
+    };
+
+    let default_value = match conditions.get("default") {
+      Some(ConditionTree::Value(default_value)) => default_value.clone(),
+      _ => unreachable!("Conditions always have a default value"),
+    };
+
+    conditions
+      .entry(at_rule.clone())
+      .or_insert(ConditionTree::Value(default_value))
+      .insert(rest, value);
+  }
+
+  fn into_expression(self) -> Expr {
+    match self {
+      ConditionTree::Value(value) => string_to_expression(value.as_str()),
+      ConditionTree::Conditions(conditions) => object_expression_factory(
+        conditions
+          .into_iter()
+          .map(|(key, tree)| {
+            prop_or_spread_expression_factory(key.as_str(), tree.into_expression())
+          })
+          .collect(),
+      ),
+    }
+  }
+}
+
+fn stylex_call(stylex_ident: &Ident, members: &[&str], args: Vec<Expr>) -> Expr {
+  let callee = members
+    .iter()
+    .fold(Expr::Ident(stylex_ident.clone()), |obj, member| {
+      Expr::from(MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(obj),
+        prop: MemberProp::Ident(ident_factory(member)),
+      })
+    });
+
+  Expr::from(CallExpr {
+    span: DUMMY_SP,
+    callee: Callee::Expr(Box::new(callee)),
+    args: args
+      .into_iter()
+      .map(|expr| ExprOrSpread {
+        spread: None,
+        expr: Box::new(expr),
+      })
+      .collect(),
+    type_args: None,
+  })
+}
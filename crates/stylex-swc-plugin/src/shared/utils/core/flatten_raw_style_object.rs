@@ -1,8 +1,11 @@
 use indexmap::IndexMap;
 use regex::Regex;
-use swc_core::ecma::{
-  ast::{Expr, KeyValueProp, Prop, PropName, PropOrSpread},
-  utils::quote_str,
+use swc_core::{
+  common::Spanned,
+  ecma::{
+    ast::{Expr, KeyValueProp, Prop, PropName, PropOrSpread},
+    utils::quote_str,
+  },
 };
 
 use crate::shared::{
@@ -43,6 +46,7 @@ pub(crate) fn flatten_raw_style_object(
 
   for property in style.iter() {
     let key = get_key_str(property);
+    let value_span = property.value.span();
 
     let key_regex = Regex::new(r"var\(--[a-z0-9]+\)").unwrap();
     let css_property_key = if key_regex.is_match(&key) {
@@ -120,12 +124,15 @@ pub(crate) fn flatten_raw_style_object(
               PreRuleValue::Null // Default value when `values` is empty.
             };
 
-            let pre_rule = PreRules::StylesPreRule(StylesPreRule::new(
-              property.as_str(),
-              pre_rule_value,
-              Some(pseudos.clone()),
-              Some(at_rules.clone()),
-            ));
+            let pre_rule = PreRules::StylesPreRule(
+              StylesPreRule::new(
+                property.as_str(),
+                pre_rule_value,
+                Some(pseudos.clone()),
+                Some(at_rules.clone()),
+              )
+              .with_span(value_span),
+            );
             flattened.insert(property.clone(), pre_rule);
           }
         }
@@ -149,12 +156,15 @@ pub(crate) fn flatten_raw_style_object(
             let property = property.to_string();
 
             if let Some(pair_value) = pre_rule {
-              let pre_rule = PreRules::StylesPreRule(StylesPreRule::new(
-                property.as_str(),
-                PreRuleValue::String(pair_value.to_string()),
-                Some(pseudos.clone()),
-                Some(at_rules.clone()),
-              ));
+              let pre_rule = PreRules::StylesPreRule(
+                StylesPreRule::new(
+                  property.as_str(),
+                  PreRuleValue::String(pair_value.to_string()),
+                  Some(pseudos.clone()),
+                  Some(at_rules.clone()),
+                )
+                .with_span(value_span),
+              );
 
               flattened.insert(property, pre_rule);
             } else {
@@ -167,12 +177,15 @@ pub(crate) fn flatten_raw_style_object(
         let handled_tpl = handle_tpl_to_expression(tpl, state, fns);
         let result = expr_tpl_to_string(handled_tpl.as_tpl().unwrap(), state, fns);
 
-        let pre_rule = PreRules::StylesPreRule(StylesPreRule::new(
-          css_property_key.as_str(),
-          PreRuleValue::String(result),
-          Some(pseudos.clone()),
-          Some(at_rules.clone()),
-        ));
+        let pre_rule = PreRules::StylesPreRule(
+          StylesPreRule::new(
+            css_property_key.as_str(),
+            PreRuleValue::String(result),
+            Some(pseudos.clone()),
+            Some(at_rules.clone()),
+          )
+          .with_span(value_span),
+        );
 
         flattened.insert(css_property_key, pre_rule);
       }
@@ -0,0 +1,272 @@
+use std::{collections::HashMap, fs::read_to_string, path::Path};
+
+use swc_core::{
+  common::{comments::NoopComments, sync::Lrc, FileName, SourceMap},
+  ecma::{
+    ast::{
+      Decl, EsVersion, ExportSpecifier, Expr, Ident, ImportDecl, ImportSpecifier, Module,
+      ModuleDecl, ModuleExportName, ModuleItem, Pat,
+    },
+    parser::{lexer::Lexer, EsSyntax, Parser, StringInput, Syntax, TsSyntax},
+    visit::FoldWith,
+  },
+};
+
+use crate::{
+  shared::{
+    enums::data_structures::import_path_resolution::{
+      ImportPathResolution, ImportPathResolutionType,
+    },
+    structures::{
+      imported_module::ImportedModule,
+      plugin_pass::PluginPass,
+      state_manager::StateManager,
+      types::{StylesObjectMap, ThemeOverrides},
+    },
+    utils::common::get_import_by_ident,
+  },
+  transform::ModuleTransformVisitor,
+};
+
+pub(crate) fn get_imported_style_map(
+  ident: &Ident,
+  state: &mut StateManager,
+) -> Option<Box<StylesObjectMap>> {
+  if !state.options.resolve_imported_styles {
+    return None;
+  }
+
+  let import_decl = get_import_by_ident(ident, state)?;
+
+  let export_name = get_export_name(&import_decl, ident)?;
+
+  let file_path = state.resolve_module_path(import_decl.src.value.as_ref())?;
+
+  get_imported_module(&file_path, state)
+    .style_maps
+    .get(&export_name)
+    .cloned()
+}
+
+/// Resolves a theme imported from a theme file, like `*.stylex.js`,
+/// to the overrides of the `stylex.createTheme` call it was created with.
+pub(crate) fn get_imported_theme_overrides(
+  ident: &Ident,
+  state: &mut StateManager,
+) -> Option<ThemeOverrides> {
+  let (file_path, export_name) = resolve_theme_import(ident, state)?;
+
+  get_imported_module(&file_path, state)
+    .theme_overrides
+    .get(&export_name)
+    .cloned()
+}
+
+/// Resolves variables imported from a theme file, like `*.stylex.js`,
+/// to the result of the `stylex.defineVars` call they were created with.
+/// The syntaxes of the typed variables of the module are registered as well.
+pub(crate) fn get_imported_defined_vars(
+  ident: &Ident,
+  state: &mut StateManager,
+) -> Option<Box<Expr>> {
+  let (file_path, export_name) = resolve_theme_import(ident, state)?;
+
+  let imported_module = get_imported_module(&file_path, state);
+
+  let defined_vars = imported_module.defined_vars.get(&export_name).cloned();
+  let typed_variable_syntaxes = imported_module.typed_variable_syntaxes.clone();
+
+  state
+    .typed_variable_syntaxes
+    .extend(typed_variable_syntaxes);
+
+  defined_vars
+}
+
+/// File path and export name of an identifier imported from a theme file
+fn resolve_theme_import(ident: &Ident, state: &mut StateManager) -> Option<(String, String)> {
+  let import_decl = get_import_by_ident(ident, state)?;
+
+  let import_path = import_decl.src.value.as_ref();
+
+  let ImportPathResolution::Tuple(ImportPathResolutionType::ThemeNameRef, _) =
+    state.import_path_resolver(import_path)
+  else {
+    return None;
+  };
+
+  let export_name = get_export_name(&import_decl, ident)?;
+
+  let file_path = state.resolve_module_path(import_path)?;
+
+  Some((file_path, export_name))
+}
+
+/// Compiles a module once per file path, all its exports are resolved from
+/// the same result
+fn get_imported_module<'a>(file_path: &str, state: &'a mut StateManager) -> &'a ImportedModule {
+  if !state.imported_modules.contains_key(file_path) {
+    let imported_module = compile_module(file_path, state).unwrap_or_default();
+
+    state
+      .imported_modules
+      .insert(file_path.to_string(), imported_module);
+  }
+
+  &state.imported_modules[file_path]
+}
+
+fn get_export_name(import_decl: &ImportDecl, ident: &Ident) -> Option<String> {
+  import_decl
+    .specifiers
+    .iter()
+    .find_map(|specifier| match specifier {
+      ImportSpecifier::Named(named) if named.local.sym == ident.sym => {
+        Some(match &named.imported {
+          Some(imported) => module_export_name_to_string(imported),
+          None => named.local.sym.to_string(),
+        })
+      }
+      ImportSpecifier::Default(default) if default.local.sym == ident.sym => {
+        Some("default".to_string())
+      }
+      _ => None,
+    })
+}
+
+/// Compiles another module with the options of the current one
+fn compile_module(file_path: &str, state: &StateManager) -> Option<ImportedModule> {
+  let source = read_to_string(file_path).ok()?;
+
+  let module = parse_module(file_path, source)?;
+
+  let exported_names = get_exported_names(&module);
+
+  if exported_names.is_empty() {
+    return None;
+  }
+
+  let mut options = state.options.clone();
+
+  options.resolve_imported_styles = false;
+
+  let module_state = StateManager {
+    options,
+    stylex_import: state.stylex_import.clone(),
+    _state: Box::new(PluginPass {
+      cwd: state._state.cwd.clone(),
+      filename: FileName::Real(file_path.into()),
+    }),
+    ..StateManager::default()
+  };
+
+  let mut visitor = ModuleTransformVisitor::new_with_state(NoopComments, module_state);
+
+  module.fold_with(&mut visitor);
+
+  let module_state = visitor.state;
+
+  Some(ImportedModule {
+    style_maps: collect_exports(&exported_names, &module_state.style_map),
+    theme_overrides: collect_exports(&exported_names, &module_state.theme_overrides),
+    defined_vars: collect_exports(&exported_names, &module_state.defined_vars),
+    typed_variable_syntaxes: module_state.typed_variable_syntaxes,
+  })
+}
+
+fn collect_exports<T: Clone>(
+  exported_names: &HashMap<String, String>,
+  values: &HashMap<String, T>,
+) -> HashMap<String, T> {
+  exported_names
+    .iter()
+    .filter_map(|(exported, local)| {
+      values
+        .get(local)
+        .map(|value| (exported.clone(), value.clone()))
+    })
+    .collect()
+}
+
+fn parse_module(file_path: &str, source: String) -> Option<Module> {
+  let syntax = match Path::new(file_path)
+    .extension()
+    .and_then(|extension| extension.to_str())
+  {
+    Some("ts") => Syntax::Typescript(TsSyntax::default()),
+    Some("tsx") => Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    _ => Syntax::Es(EsSyntax {
+      jsx: true,
+      ..Default::default()
+    }),
+  };
+
+  let source_map: Lrc<SourceMap> = Default::default();
+
+  let source_file = source_map.new_source_file(FileName::Real(file_path.into()), source);
+
+  let lexer = Lexer::new(
+    syntax,
+    EsVersion::latest(),
+    StringInput::from(&*source_file),
+    None,
+  );
+
+  Parser::new_from(lexer).parse_module().ok()
+}
+
+fn get_exported_names(module: &Module) -> HashMap<String, String> {
+  let mut exported_names = HashMap::new();
+
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(module_decl) = item else {
+      continue;
+    };
+
+    match module_decl {
+      ModuleDecl::ExportDecl(export_decl) => {
+        if let Decl::Var(var_decl) = &export_decl.decl {
+          for decl in &var_decl.decls {
+            if let Pat::Ident(binding_ident) = &decl.name {
+              let name = binding_ident.sym.to_string();
+
+              exported_names.insert(name.clone(), name);
+            }
+          }
+        }
+      }
+      ModuleDecl::ExportNamed(named_export) if named_export.src.is_none() => {
+        for specifier in &named_export.specifiers {
+          if let ExportSpecifier::Named(named) = specifier {
+            let local = module_export_name_to_string(&named.orig);
+
+            let exported = match &named.exported {
+              Some(exported) => module_export_name_to_string(exported),
+              None => local.clone(),
+            };
+
+            exported_names.insert(exported, local);
+          }
+        }
+      }
+      ModuleDecl::ExportDefaultExpr(export_default) => {
+        if let Expr::Ident(ident) = export_default.expr.as_ref() {
+          exported_names.insert("default".to_string(), ident.sym.to_string());
+        }
+      }
+      _ => {}
+    }
+  }
+
+  exported_names
+}
+
+fn module_export_name_to_string(name: &ModuleExportName) -> String {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.to_string(),
+    ModuleExportName::Str(strng) => strng.value.to_string(),
+  }
+}
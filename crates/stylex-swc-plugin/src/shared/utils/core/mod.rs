@@ -1,10 +1,14 @@
 pub(crate) mod attrs;
 pub(crate) mod convert_style_to_class_name;
 pub(crate) mod define_vars_utils;
+pub mod design_tokens;
+#[cfg(feature = "design-tokens-codegen")]
+pub mod design_tokens_codegen;
 pub(crate) mod dev_class_name;
 pub mod evaluate_stylex_create_arg;
 pub(crate) mod flat_map_expanded_shorthands;
 pub(crate) mod flatten_raw_style_object;
+pub(crate) mod imported_styles;
 pub(crate) mod js_to_expr;
 pub(crate) mod make_string_expression;
 pub(crate) mod member_expression;
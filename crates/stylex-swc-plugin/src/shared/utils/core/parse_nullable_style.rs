@@ -4,7 +4,10 @@ use swc_core::ecma::ast::{Expr, Ident, Lit, MemberExpr, MemberProp};
 use crate::shared::{
   enums::data_structures::flat_compiled_styles_value::FlatCompiledStylesValue,
   structures::state_manager::StateManager,
-  utils::common::{get_string_val_from_lit, reduce_ident_count},
+  utils::{
+    common::{get_string_val_from_lit, reduce_ident_count},
+    core::imported_styles::get_imported_style_map,
+  },
 };
 
 #[derive(Debug, PartialEq, Clone)]
@@ -74,6 +77,19 @@ pub(crate) fn parse_nullable_style(
             }
             MemberProp::PrivateName(_) => {}
           }
+        } else if let Some(style) = get_imported_style_map(obj_ident, state) {
+          let prop_name = match &member.prop {
+            MemberProp::Ident(prop_ident) => Some(prop_ident.sym.to_string()),
+            MemberProp::Computed(computed) => {
+              computed.expr.as_lit().and_then(get_string_val_from_lit)
+            }
+            MemberProp::PrivateName(_) => None,
+          };
+
+          if let Some(style_value) = prop_name.and_then(|prop_name| style.get(&prop_name).cloned())
+          {
+            return StyleObject::Style(*style_value);
+          }
         }
       }
 
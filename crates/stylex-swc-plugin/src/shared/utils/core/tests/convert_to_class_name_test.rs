@@ -1,12 +1,20 @@
 #[cfg(test)]
 mod convert_style_to_class_name {
+  use swc_core::common::DUMMY_SP;
+
   use crate::shared::{
     structures::{pre_rule::PreRuleValue, state_manager::StateManager},
     utils::core::convert_style_to_class_name::convert_style_to_class_name,
   };
   fn convert(styles: (&str, &PreRuleValue)) -> String {
-    let result =
-      convert_style_to_class_name(styles, &mut [], &mut [], "", &StateManager::default());
+    let result = convert_style_to_class_name(
+      styles,
+      &mut [],
+      &mut [],
+      "",
+      DUMMY_SP,
+      &StateManager::default(),
+    );
 
     extract_body(result.2.ltr)
   }
@@ -0,0 +1,62 @@
+use swc_core::css::ast::{AtRulePrelude, Rule};
+
+use crate::shared::constants::messages::{INVALID_AT_RULE, INVALID_PSEUDO_OR_AT_RULE};
+
+use super::common::swc_parse_css;
+
+/// At-rules that can be used as conditions of a style value
+static CONDITIONAL_AT_RULES: [&str; 5] = [
+  "@media",
+  "@supports",
+  "@container",
+  "@starting-style",
+  "@scope",
+];
+
+fn get_at_rule_name(at_rule: &str) -> &str {
+  let end = at_rule[1..]
+    .find(|chr: char| !(chr.is_ascii_alphanumeric() || chr == '-' || chr == '_'))
+    .map_or(at_rule.len(), |index| index + 1);
+
+  &at_rule[..end]
+}
+
+/// Validates the name and the prelude of a conditional at-rule
+pub(crate) fn validate_at_rule(at_rule: &str) {
+  let name = get_at_rule_name(at_rule);
+
+  assert!(
+    CONDITIONAL_AT_RULES.contains(&name),
+    "{}",
+    INVALID_PSEUDO_OR_AT_RULE
+  );
+
+  let is_valid = match name {
+    "@starting-style" => at_rule.trim_end() == name,
+    "@scope" => matches!(
+      parse_at_rule_prelude(at_rule),
+      Some(Some(AtRulePrelude::ScopePrelude(_)))
+    ),
+    "@container" => matches!(
+      parse_at_rule_prelude(at_rule),
+      Some(Some(AtRulePrelude::ContainerPrelude(_)))
+    ),
+    _ => true,
+  };
+
+  assert!(is_valid, r#"{} "{}"."#, INVALID_AT_RULE, at_rule);
+}
+
+// Returns `None` when the at-rule can't be parsed and `Some(None)` when it has no prelude
+fn parse_at_rule_prelude(at_rule: &str) -> Option<Option<AtRulePrelude>> {
+  let (stylesheet, errors) = swc_parse_css(format!("{} {{}}", at_rule).as_str());
+
+  if !errors.is_empty() {
+    return None;
+  }
+
+  match stylesheet.ok()?.rules.pop()? {
+    Rule::AtRule(rule) => Some(rule.prelude.map(|prelude| *prelude)),
+    _ => None,
+  }
+}
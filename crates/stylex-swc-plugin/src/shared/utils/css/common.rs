@@ -4,42 +4,64 @@ use crate::shared::{
   constants::{
     long_hand_logical::LONG_HAND_LOGICAL,
     long_hand_physical::LONG_HAND_PHYSICAL,
-    messages::LINT_UNCLOSED_FUNCTION,
+    messages::{INVALID_CSS_TEMPLATE, LINT_UNCLOSED_FUNCTION, UNKNOWN_CSS_VARIABLE},
     number_properties::NUMBER_PROPERTY_SUFFIXIES,
     priorities::{
-      AT_RULE_PRIORITIES, CAMEL_CASE_PRIORITIES, PSEUDO_CLASS_PRIORITIES, PSEUDO_ELEMENT_PRIORITY,
+      AT_RULE_PRIORITIES, CAMEL_CASE_PRIORITIES, IMPORTANT_PRIORITY, PSEUDO_CLASS_PRIORITIES,
+      PSEUDO_ELEMENT_PRIORITY,
     },
+    pseudo_variants::PSEUDO_VARIANTS,
     shorthands_of_longhands::SHORTHANDS_OF_LONGHANDS,
     shorthands_of_shorthands::SHORTHANDS_OF_SHORTHANDS,
     unitless_number_properties::UNITLESS_NUMBER_PROPERTIES,
   },
   structures::{
-    injectable_style::InjectableStyle, pair::Pair, state_manager::StateManager,
+    injectable_style::InjectableStyle,
+    pair::Pair,
+    state_manager::StateManager,
+    stylex_options::{DefinedCSSVariablesResolution, PxToRemOptions},
     stylex_state_options::StyleXStateOptions,
   },
-  utils::css::{
-    normalizers::{base::base_normalizer, whitespace_normalizer::whitespace_normalizer},
-    validators::unprefixed_custom_properties::unprefixed_custom_properties_validator,
+  utils::{
+    ast::factories::{object_expression_factory, prop_or_spread_string_factory},
+    common::dashify,
+    common::get_closest_match,
+    css::{
+      at_rule::validate_at_rule,
+      media_query::normalize_media_query,
+      normalizers::{base::get_rem_properties, whitespace_normalizer::whitespace_normalizer},
+      pipeline::CssPipeline,
+    },
   },
 };
 
 use regex::Regex;
 use swc_core::{
-  common::{input::StringInput, source_map::Pos, BytePos},
+  common::{
+    input::StringInput, source_map::Pos, util::take::Take, BytePos, Span, Spanned, DUMMY_SP,
+  },
   css::{
-    ast::{Ident, Stylesheet},
+    ast::{
+      ComponentValue, Declaration, DeclarationName, Dimension, Ident, Integer, Length, Number,
+      Percentage, QualifiedRule, Rule, Stylesheet, Token, TokenAndSpan,
+    },
     codegen::{
       writer::basic::{BasicCssWriter, BasicCssWriterConfig},
       CodeGenerator, CodegenConfig, Emit,
     },
-    parser::{error::Error, parse_string_input, parser::ParserConfig},
+    parser::{error::Error, lexer::Lexer, parse_string_input, parser::ParserConfig},
   },
+  ecma::ast::Expr,
 };
 
 use std::collections::HashMap;
 
 use crate::shared::{
-  constants::cursor_flip::CURSOR_FLIP, regex::LENGTH_UNIT_TESTER_REGEX,
+  constants::cursor_flip::CURSOR_FLIP,
+  regex::{
+    CSS_VARIABLE_REFERENCE_REGEX, LENGTH_UNIT_TESTER_REGEX, TRIVIAL_IDENT_REGEX,
+    TRIVIAL_INTEGER_REGEX, TRIVIAL_LENGTH_REGEX,
+  },
   structures::pre_rule::PreRules,
 };
 
@@ -315,44 +337,62 @@ pub(crate) fn split_value(
   (top, right, bottom, left)
 }
 
-const THUMB_VARIANTS: [&str; 3] = [
-  "::-webkit-slider-thumb",
-  "::-moz-range-thumb",
-  "::-ms-thumb",
-];
+fn get_pseudo_variants(pseudo: &str, options: &StyleXStateOptions) -> Option<Vec<String>> {
+  if let Some(variants) = options.pseudo_variants.get(pseudo) {
+    return Some(variants.clone());
+  }
+
+  PSEUDO_VARIANTS
+    .get(pseudo)
+    .map(|variants| variants.iter().map(|variant| variant.to_string()).collect())
+}
 
 pub(crate) fn generate_css_rule(
   class_name: &str,
   decls: String,
   pseudos: &mut [String],
   at_rules: &mut [String],
+  options: &StyleXStateOptions,
 ) -> String {
-  let pseudo = pseudos
-    .iter()
-    .filter(|&p| p != "::thumb")
-    .collect::<Vec<&String>>();
-  let pseudo_strs: Vec<&str> = pseudo.iter().map(|s| s.as_str()).collect();
-  let pseudo = pseudo_strs.join("");
-  let mut selector_for_at_rules = format!(
-    ".{}{}{}",
+  let base_selector = format!(
+    ".{}{}",
     class_name,
     at_rules
       .iter()
       .map(|_| format!(".{}", class_name))
       .collect::<Vec<String>>()
-      .join(""),
-    pseudo
+      .join("")
   );
 
-  if pseudos.contains(&"::thumb".to_string()) {
-    selector_for_at_rules = THUMB_VARIANTS
-      .iter()
-      .map(|suffix| format!("{}{}", selector_for_at_rules, suffix))
-      .collect::<Vec<String>>()
-      .join(", ");
-  }
+  // Pseudo-elements with vendor variants are moved to the end of the selector
+  let mut sorted_pseudos = pseudos.to_vec();
+  sorted_pseudos.sort_by_key(|pseudo| {
+    pseudo.starts_with("::") && get_pseudo_variants(pseudo, options).is_some()
+  });
+
+  let selectors = sorted_pseudos
+    .iter()
+    .fold(vec![base_selector], |selectors, pseudo| {
+      let variants = get_pseudo_variants(pseudo, options).unwrap_or_else(|| vec![pseudo.clone()]);
+
+      selectors
+        .iter()
+        .flat_map(|selector| {
+          variants
+            .iter()
+            .map(move |variant| format!("{}{}", selector, variant))
+        })
+        .collect()
+    });
+
+  let selector_for_at_rules = selectors.join(", ");
+
+  // `@starting-style` is always the innermost at-rule, so the output doesn't
+  // depend on how the conditions were nested
+  let mut nested_at_rules = at_rules.to_vec();
+  nested_at_rules.sort_by_key(|at_rule| !at_rule.starts_with("@starting-style"));
 
-  at_rules.iter().fold(
+  nested_at_rules.iter().fold(
     format!("{}{{{}}}", selector_for_at_rules, decls),
     |acc, at_rule| format!("{}{{{}}}", at_rule, acc),
   )
@@ -364,8 +404,11 @@ pub(crate) fn generate_rule(
   values: &Vec<String>,
   pseudos: &mut [String],
   at_rules: &mut [String],
+  important: bool,
+  options: &StyleXStateOptions,
 ) -> InjectableStyle {
   let mut pairs: Vec<Pair> = vec![];
+  let important_suffix = if important { "!important" } else { "" };
 
   for value in values {
     pairs.push(Pair {
@@ -380,26 +423,33 @@ pub(crate) fn generate_rule(
 
   let ltr_decls = ltr_pairs
     .iter()
-    .map(|pair| format!("{}:{}", pair.key, pair.value))
+    .map(|pair| format!("{}:{}{}", pair.key, pair.value, important_suffix))
     .collect::<Vec<String>>()
     .join(";");
 
   let rtl_decls = rtl_pairs
     .iter()
-    .map(|pair| format!("{}:{}", pair.key, pair.value))
+    .map(|pair| format!("{}:{}{}", pair.key, pair.value, important_suffix))
     .collect::<Vec<String>>()
     .join(";");
 
-  let ltr_rule = generate_css_rule(class_name, ltr_decls, pseudos, at_rules);
+  let ltr_rule = generate_css_rule(class_name, ltr_decls, pseudos, at_rules, options);
   let rtl_rule = if rtl_decls.is_empty() {
     None
   } else {
-    Some(generate_css_rule(class_name, rtl_decls, pseudos, at_rules))
+    Some(generate_css_rule(
+      class_name, rtl_decls, pseudos, at_rules, options,
+    ))
   };
 
   let priority = get_priority(key)
     + pseudos.iter().map(|p| get_priority(p)).sum::<f64>()
-    + at_rules.iter().map(|a| get_priority(a)).sum::<f64>();
+    + at_rules.iter().map(|a| get_priority(a)).sum::<f64>()
+    + if important {
+      get_priority("!important")
+    } else {
+      0.0
+    };
 
   InjectableStyle {
     priority: Some(priority),
@@ -413,6 +463,10 @@ pub(crate) fn get_priority(key: &str) -> f64 {
     return 1.0;
   };
 
+  if key == "!important" {
+    return IMPORTANT_PRIORITY;
+  };
+
   if key.starts_with("@supports") {
     return **AT_RULE_PRIORITIES
       .get("@supports")
@@ -429,6 +483,16 @@ pub(crate) fn get_priority(key: &str) -> f64 {
       .expect("No priority found");
   };
 
+  if key.starts_with("@scope") {
+    return **AT_RULE_PRIORITIES.get("@scope").expect("No priority found");
+  };
+
+  if key.starts_with("@starting-style") {
+    return **AT_RULE_PRIORITIES
+      .get("@starting-style")
+      .expect("No priority found");
+  };
+
   if key.starts_with("::") {
     return PSEUDO_ELEMENT_PRIORITY;
   };
@@ -464,7 +528,7 @@ pub(crate) fn get_priority(key: &str) -> f64 {
   3000.0
 }
 
-pub(crate) fn transform_value(key: &str, value: &str, state: &StateManager) -> String {
+pub(crate) fn transform_value(key: &str, value: &str, span: Span, state: &StateManager) -> String {
   let css_property_value = value.trim();
 
   let value = match &css_property_value.parse::<f64>() {
@@ -476,6 +540,14 @@ pub(crate) fn transform_value(key: &str, value: &str, state: &StateManager) -> S
     Err(_) => css_property_value.to_string(),
   };
 
+  let cache_key = (key.to_string(), value);
+
+  if let Some(result) = state.css_value_cache.borrow().get(&cache_key) {
+    return resolve_defined_css_variables(result, state);
+  }
+
+  let (key, value) = (cache_key.0.as_str(), cache_key.1.as_str());
+
   if key == "content" || key == "hyphenateCharacter" || key == "hyphenate-character" {
     let val = value.trim();
     if Regex::new(r"^attr\([a-zA-Z0-9-]+\)$")
@@ -492,18 +564,167 @@ pub(crate) fn transform_value(key: &str, value: &str, state: &StateManager) -> S
     return val.to_string();
   }
 
-  let result = normalize_css_property_value(key, value.as_ref(), &state.options);
+  let result =
+    normalize_css_property_value(key, value, span, state.get_css_pipeline(), &state.options);
+
+  state
+    .css_value_cache
+    .borrow_mut()
+    .insert(cache_key.clone(), result.clone());
+
+  resolve_defined_css_variables(result.as_str(), state)
+}
+
+pub(crate) fn resolve_defined_css_variables(value: &str, state: &StateManager) -> String {
+  let defined_variables = state.get_css_vars();
+
+  if defined_variables.is_empty() || !value.contains("var(") {
+    return value.to_string();
+  }
+
+  let get_defined_value = |name: &str| {
+    defined_variables
+      .get(name)
+      .or_else(|| defined_variables.get(&name[2..]))
+  };
+
+  CSS_VARIABLE_REFERENCE_REGEX
+    .replace_all(value, |caps: &regex::Captures| {
+      let reference = caps.get(0).unwrap().as_str();
+      let name = caps.get(1).unwrap().as_str();
+      let has_fallback = caps.get(2).is_some();
+
+      let Some(defined_value) = get_defined_value(name) else {
+        // Only references that are close to a known variable are treated as typos,
+        // variables from `stylex.defineVars` or other sources are left untouched.
+        let known_names = defined_variables
+          .keys()
+          .map(|key| {
+            if key.starts_with("--") {
+              key.clone()
+            } else {
+              format!("--{}", key)
+            }
+          })
+          .collect::<Vec<String>>();
+
+        let max_distance = (name.len() / 4).max(1);
+
+        if let Some(suggestion) = get_closest_match(
+          name,
+          known_names.iter().map(|key| key.as_str()),
+          max_distance,
+        ) {
+          panic!(
+            r#"{} "{}". Did you mean "{}"?"#,
+            UNKNOWN_CSS_VARIABLE, name, suggestion
+          );
+        }
+
+        return reference.to_string();
+      };
+
+      if has_fallback {
+        return reference.to_string();
+      }
+
+      match state.options.defined_stylex_css_variables_resolution {
+        DefinedCSSVariablesResolution::Validate => reference.to_string(),
+        DefinedCSSVariablesResolution::Fallback => format!("var({},{})", name, defined_value),
+        DefinedCSSVariablesResolution::Inline => defined_value.to_string(),
+      }
+    })
+    .to_string()
+}
+
+pub(crate) fn convert_px_to_rem(px: f64, options: &PxToRemOptions) -> f64 {
+  let rem = px / options.root_font_size;
+
+  match options.precision {
+    Some(precision) => {
+      let factor = 10f64.powi(precision as i32);
+
+      (rem * factor).round() / factor
+    }
+    None => rem,
+  }
+}
+
+// Converts px dimension tokens only, so px inside strings, urls and identifiers is kept
+pub(crate) fn convert_px_to_rem_in_value(value: &str, options: &PxToRemOptions) -> String {
+  let input = StringInput::new(
+    value,
+    BytePos::from_usize(0),
+    BytePos::from_usize(value.len()),
+  );
+  let lexer = Lexer::new(input, None, ParserConfig::default());
+
+  let mut result = String::with_capacity(value.len());
+  let mut last_index = 0;
+
+  for TokenAndSpan { token, span } in lexer {
+    let Token::Dimension(dimension) = token else {
+      continue;
+    };
+
+    if !dimension.unit.eq_ignore_ascii_case("px") || dimension.value == 0.0 {
+      continue;
+    }
+
+    let (start, end) = (span.lo.to_usize(), span.hi.to_usize());
+
+    result.push_str(&value[last_index..start]);
+    result.push_str(&format!(
+      "{}rem",
+      convert_px_to_rem(dimension.value, options)
+    ));
+
+    last_index = end;
+  }
+
+  result.push_str(&value[last_index..]);
 
   result
 }
+
+pub(crate) fn is_media_query(at_rule: &str) -> bool {
+  at_rule.starts_with("@media")
+}
+
+pub(crate) fn normalize_at_rule(at_rule: &str, span: Span, options: &StyleXStateOptions) -> String {
+  if !is_media_query(at_rule) {
+    validate_at_rule(at_rule);
+
+    return at_rule.to_string();
+  }
+
+  let at_rule = normalize_media_query(at_rule, span, options);
+
+  match options
+    .px_to_rem
+    .as_ref()
+    .filter(|px_to_rem| px_to_rem.media_queries)
+  {
+    Some(px_to_rem) => convert_px_to_rem_in_value(at_rule.as_str(), px_to_rem),
+    None => at_rule,
+  }
+}
+
 pub fn swc_parse_css(source: &str) -> (Result<Stylesheet, Error>, Vec<Error>) {
-  let config = ParserConfig {
-    allow_wrong_line_comments: false,
-    css_modules: false,
-    legacy_nesting: false,
-    legacy_ie: false,
-  };
+  let input = StringInput::new(
+    source,
+    BytePos::from_usize(0),
+    BytePos::from_usize(source.len()),
+  );
+  let mut errors: Vec<Error> = vec![];
 
+  (
+    parse_string_input(input, None, css_parser_config(), &mut errors),
+    errors,
+  )
+}
+
+fn swc_parse_css_declaration(source: &str) -> (Result<Declaration, Error>, Vec<Error>) {
   let input = StringInput::new(
     source,
     BytePos::from_usize(0),
@@ -511,27 +732,58 @@ pub fn swc_parse_css(source: &str) -> (Result<Stylesheet, Error>, Vec<Error>) {
   );
   let mut errors: Vec<Error> = vec![];
 
-  (parse_string_input(input, None, config, &mut errors), errors)
+  (
+    parse_string_input(input, None, css_parser_config(), &mut errors),
+    errors,
+  )
+}
+
+fn css_parser_config() -> ParserConfig {
+  ParserConfig {
+    allow_wrong_line_comments: false,
+    css_modules: false,
+    legacy_nesting: false,
+    legacy_ie: false,
+  }
 }
 
 pub(crate) fn normalize_css_property_value(
   css_property: &str,
   css_property_value: &str,
+  span: Span,
+  pipeline: &CssPipeline,
   options: &StyleXStateOptions,
 ) -> String {
-  let css_property = if css_property.starts_with("--") {
+  let parsed_property = if css_property.starts_with("--") {
     "color"
   } else {
     css_property
   };
 
-  let css_rule = if css_property.starts_with(':') {
-    format!("{0} {1}", css_property, css_property_value)
-  } else {
-    format!("* {{ {0}: {1} }}", css_property, css_property_value)
-  };
+  if pipeline.has_only_base_normalizer() {
+    if let Some(value) = parse_trivial_value(parsed_property, css_property_value, options) {
+      pipeline.validate(
+        css_property,
+        &create_declaration_stylesheet(Declaration {
+          span: DUMMY_SP,
+          name: DeclarationName::Ident(Ident {
+            span: DUMMY_SP,
+            value: parsed_property.into(),
+            raw: None,
+          }),
+          value: vec![value],
+          important: None,
+        }),
+        span,
+        options,
+      );
 
-  let (parsed_css, errors) = swc_parse_css(css_rule.as_str());
+      return css_property_value.to_string();
+    }
+  }
+
+  let (parsed_declaration, errors) =
+    swc_parse_css_declaration(format!("{}: {}", parsed_property, css_property_value).as_str());
 
   if !errors.is_empty() {
     let mut error_message = errors.first().unwrap().message().to_string();
@@ -543,49 +795,153 @@ pub(crate) fn normalize_css_property_value(
     panic!("{}", error_message)
   }
 
-  let ast_normalized = match parsed_css {
-    Ok(ast) => {
-      let (parsed_css_property_value, _) = swc_parse_css(css_rule.as_str());
+  let declaration = match parsed_declaration {
+    Ok(declaration) => declaration,
+    Err(err) => panic!("{}", err.message()),
+  };
 
-      // let validators: Vec<Validator> = vec![
-      //   unprefixed_custom_properties_validator,
-      //   // Add other validator functions here...
-      // ];
+  // Validators and normalizers work on stylesheets, so the parsed declaration is wrapped
+  // in a rule without printing and parsing it again
+  let ast = create_declaration_stylesheet(declaration);
 
-      // let normalizers: Vec<Normalizer> = vec![
-      //   base_normalizer,
-      //   // Add other normalizer functions here...
-      // ];
+  pipeline.validate(css_property, &ast, span, options);
 
-      // for validator in validators {
-      //   validator(ast.clone());
-      // }
+  let parsed_ast = pipeline.normalize(css_property, ast, options);
 
-      unprefixed_custom_properties_validator(ast);
+  let result = whitespace_normalizer(stringify_declaration_value(&parsed_ast));
 
-      let parsed_ast = base_normalizer(
-        parsed_css_property_value.unwrap(),
-        options.use_rem_for_font_size,
-      );
+  convert_css_function_to_camel_case(result.as_str())
+}
 
-      // for normalizer in normalizers {
-      //   parsed_ast = normalizer(parsed_ast, options.use_rem_for_font_size);
-      // }
+// Parses values that no base normalization changes, i.e. integers, non-zero lengths and lowercase keywords
+fn parse_trivial_value(
+  property: &str,
+  value: &str,
+  options: &StyleXStateOptions,
+) -> Option<ComponentValue> {
+  if property.starts_with(':') {
+    return None;
+  }
 
-      let result = whitespace_normalizer(stringify(&parsed_ast));
+  if TRIVIAL_INTEGER_REGEX.is_match(value) {
+    return Some(ComponentValue::Integer(Box::new(Integer {
+      span: DUMMY_SP,
+      value: value.parse().ok()?,
+      raw: None,
+    })));
+  }
 
-      convert_css_function_to_camel_case(result.as_str())
-    }
-    Err(err) => {
-      panic!("{}", err.message())
-    }
+  if let Some(captures) = TRIVIAL_LENGTH_REGEX.captures(value) {
+    let number = Number {
+      span: DUMMY_SP,
+      value: captures[1].parse().ok()?,
+      raw: None,
+    };
+
+    return match &captures[2] {
+      "%" => Some(ComponentValue::Percentage(Box::new(Percentage {
+        span: DUMMY_SP,
+        value: number,
+      }))),
+      "px" if get_rem_properties(options).contains(&dashify(property)) => None,
+      unit => Some(ComponentValue::Dimension(Box::new(Dimension::Length(
+        Length {
+          span: DUMMY_SP,
+          value: number,
+          unit: Ident {
+            span: DUMMY_SP,
+            value: unit.into(),
+            raw: None,
+          },
+        },
+      )))),
+    };
+  }
+
+  if TRIVIAL_IDENT_REGEX.is_match(value) {
+    return Some(ComponentValue::Ident(Box::new(Ident {
+      span: DUMMY_SP,
+      value: value.into(),
+      raw: None,
+    })));
+  }
+
+  None
+}
+
+fn create_declaration_stylesheet(declaration: Declaration) -> Stylesheet {
+  let mut rule = QualifiedRule::dummy();
+
+  rule.block.value = vec![ComponentValue::Declaration(Box::new(declaration))];
+
+  Stylesheet {
+    span: DUMMY_SP,
+    rules: vec![Rule::QualifiedRule(Box::new(rule))],
+  }
+}
+
+pub(crate) fn css_declarations_to_object(css_block: &str) -> Expr {
+  let css_rule = format!("* {{ {} }}", css_block);
+
+  let (parsed_css, errors) = swc_parse_css(css_rule.as_str());
+
+  let stylesheet = match parsed_css {
+    Ok(stylesheet) if errors.is_empty() => stylesheet,
+    _ => panic!("{}", INVALID_CSS_TEMPLATE),
+  };
+
+  let Some(Rule::QualifiedRule(qualified_rule)) = stylesheet.rules.first() else {
+    panic!("{}", INVALID_CSS_TEMPLATE)
   };
 
-  ast_normalized
+  let mut props = vec![];
+
+  for component_value in qualified_rule.block.value.iter() {
+    let ComponentValue::Declaration(declaration) = component_value else {
+      panic!("{}", INVALID_CSS_TEMPLATE)
+    };
+
+    let key = match &declaration.name {
+      DeclarationName::Ident(ident) => camelize(ident.value.as_ref()),
+      DeclarationName::DashedIdent(dashed_ident) => format!("--{}", dashed_ident.value),
+    };
+
+    let (Some(first), Some(last)) = (declaration.value.first(), declaration.value.last()) else {
+      panic!("{}", INVALID_CSS_TEMPLATE)
+    };
+
+    let mut value = css_rule[first.span_lo().to_usize()..last.span_hi().to_usize()]
+      .trim()
+      .to_string();
+
+    if declaration.important.is_some() {
+      value.push_str(" !important");
+    }
+
+    props.push(prop_or_spread_string_factory(key.as_str(), value.as_str()));
+  }
+
+  object_expression_factory(props)
 }
 
-// type Normalizer = fn(Stylesheet, bool) -> Stylesheet;
-// type Validator = fn(Stylesheet);
+fn camelize(property: &str) -> String {
+  property
+    .split('-')
+    .enumerate()
+    .map(|(index, part)| {
+      if index == 0 {
+        return part.to_string();
+      }
+
+      let mut chars = part.chars();
+
+      match chars.next() {
+        Some(first) => format!("{}{}", first.to_uppercase(), chars.as_str()),
+        None => String::default(),
+      }
+    })
+    .collect()
+}
 
 pub(crate) fn get_number_suffix(key: &str) -> String {
   if UNITLESS_NUMBER_PROPERTIES.contains(key) {
@@ -618,13 +974,35 @@ fn convert_css_function_to_camel_case(function: &str) -> String {
   format!("{}{}", camel_case_name, args)
 }
 
-pub fn stringify(node: &Stylesheet) -> String {
+// Prints the value of the only declaration in the stylesheet
+fn stringify_declaration_value(node: &Stylesheet) -> String {
+  let declaration = node
+    .rules
+    .iter()
+    .find_map(|rule| match rule {
+      Rule::QualifiedRule(rule) => rule
+        .block
+        .value
+        .iter()
+        .find_map(|value| value.as_declaration()),
+      _ => None,
+    })
+    .expect("Stylesheet must contain a declaration");
+
   let mut buf = String::new();
   let writer = BasicCssWriter::new(&mut buf, None, BasicCssWriterConfig::default());
   let mut codegen = CodeGenerator::new(writer, CodegenConfig { minify: true });
 
-  codegen.emit(&node).unwrap();
+  codegen.emit(declaration).unwrap();
+
+  let value = buf
+    .split_once(':')
+    .map_or(String::default(), |(_, value)| value.to_string());
+
+  unescape_stringified(value)
+}
 
+pub(crate) fn unescape_stringified(buf: String) -> String {
   let mut result = buf.replace('\'', "");
 
   if result.contains("--\\") {
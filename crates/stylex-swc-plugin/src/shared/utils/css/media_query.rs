@@ -0,0 +1,593 @@
+use swc_core::common::Span;
+
+use crate::shared::{
+  constants::messages::INVALID_MEDIA_QUERY, structures::stylex_state_options::StyleXStateOptions,
+  utils::common::panic_with_span,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  LeftParen,
+  RightParen,
+  Comma,
+  Colon,
+  Slash,
+  Operator(String),
+  Word(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MediaFeature {
+  Boolean(String),
+  Plain(String, String),
+  Range(String, String, String),
+  Interval(String, String, String, String, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum MediaCondition {
+  Feature(MediaFeature),
+  Not(Box<MediaCondition>),
+  And(Vec<MediaCondition>),
+  Or(Vec<MediaCondition>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct MediaQuery {
+  modifier: Option<String>,
+  media_type: Option<String>,
+  condition: Option<MediaCondition>,
+}
+
+/// Validates a `@media` at-rule and prints it in a canonical form, so that
+/// equivalent queries produce the same class name
+pub(crate) fn normalize_media_query(
+  at_rule: &str,
+  span: Span,
+  options: &StyleXStateOptions,
+) -> String {
+  let query_list = at_rule["@media".len()..].trim();
+
+  let tokens = tokenize(query_list).unwrap_or_else(|| invalid_media_query(at_rule, span));
+
+  let queries = MediaQueryParser::new(tokens)
+    .parse()
+    .unwrap_or_else(|| invalid_media_query(at_rule, span));
+
+  let queries = queries
+    .into_iter()
+    .map(|query| {
+      if options.legacy_media_query_ranges {
+        MediaQuery {
+          condition: query.condition.map(rewrite_ranges),
+          ..query
+        }
+      } else {
+        query
+      }
+    })
+    .map(|query| print_query(&query))
+    .collect::<Vec<String>>();
+
+  format!("@media {}", queries.join(", "))
+}
+
+fn invalid_media_query(at_rule: &str, span: Span) -> ! {
+  panic_with_span(span, &format!(r#"{} "{}"."#, INVALID_MEDIA_QUERY, at_rule))
+}
+
+fn tokenize(input: &str) -> Option<Vec<Token>> {
+  let chars = input.chars().collect::<Vec<char>>();
+  let mut tokens = vec![];
+  let mut index = 0;
+
+  while index < chars.len() {
+    let chr = chars[index];
+
+    match chr {
+      _ if chr.is_whitespace() => index += 1,
+      '(' => {
+        tokens.push(Token::LeftParen);
+        index += 1;
+      }
+      ')' => {
+        tokens.push(Token::RightParen);
+        index += 1;
+      }
+      ',' => {
+        tokens.push(Token::Comma);
+        index += 1;
+      }
+      ':' => {
+        tokens.push(Token::Colon);
+        index += 1;
+      }
+      '/' => {
+        tokens.push(Token::Slash);
+        index += 1;
+      }
+      '<' | '>' | '=' => {
+        let mut operator = chr.to_string();
+
+        if chr != '=' && chars.get(index + 1) == Some(&'=') {
+          operator.push('=');
+        }
+
+        index += operator.len();
+        tokens.push(Token::Operator(operator));
+      }
+      _ => {
+        let mut word = String::new();
+
+        while index < chars.len() && !is_delimiter(chars[index]) {
+          word.push(chars[index]);
+          index += 1;
+        }
+
+        // Functions such as `calc()` are kept as a single value
+        if chars.get(index) == Some(&'(') && !word.is_empty() {
+          let mut depth = 0;
+
+          loop {
+            let chr = *chars.get(index)?;
+
+            match chr {
+              '(' => depth += 1,
+              ')' => depth -= 1,
+              _ => {}
+            }
+
+            word.push(chr);
+            index += 1;
+
+            if depth == 0 {
+              break;
+            }
+          }
+        }
+
+        tokens.push(Token::Word(word));
+      }
+    }
+  }
+
+  Some(tokens)
+}
+
+fn is_delimiter(chr: char) -> bool {
+  chr.is_whitespace() || matches!(chr, '(' | ')' | ',' | ':' | '/' | '<' | '>' | '=')
+}
+
+struct MediaQueryParser {
+  tokens: Vec<Token>,
+  position: usize,
+}
+
+impl MediaQueryParser {
+  fn new(tokens: Vec<Token>) -> Self {
+    MediaQueryParser {
+      tokens,
+      position: 0,
+    }
+  }
+
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.position)
+  }
+
+  fn next(&mut self) -> Option<Token> {
+    let token = self.tokens.get(self.position).cloned();
+
+    self.position += 1;
+
+    token
+  }
+
+  fn peek_keyword(&self, keyword: &str) -> bool {
+    matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+  }
+
+  fn expect(&mut self, expected: Token) -> Option<()> {
+    (self.next()? == expected).then_some(())
+  }
+
+  fn parse(&mut self) -> Option<Vec<MediaQuery>> {
+    let mut queries = vec![self.parse_query()?];
+
+    while let Some(token) = self.next() {
+      if token != Token::Comma {
+        return None;
+      }
+
+      queries.push(self.parse_query()?);
+    }
+
+    Some(queries)
+  }
+
+  fn parse_query(&mut self) -> Option<MediaQuery> {
+    if let Some(Token::LeftParen) = self.peek() {
+      return Some(MediaQuery {
+        modifier: None,
+        media_type: None,
+        condition: Some(self.parse_condition(true)?),
+      });
+    }
+
+    if self.peek_keyword("not") && self.tokens.get(self.position + 1) == Some(&Token::LeftParen) {
+      return Some(MediaQuery {
+        modifier: None,
+        media_type: None,
+        condition: Some(self.parse_condition(true)?),
+      });
+    }
+
+    let modifier = if self.peek_keyword("not") || self.peek_keyword("only") {
+      match self.next()? {
+        Token::Word(word) => Some(word.to_lowercase()),
+        _ => None,
+      }
+    } else {
+      None
+    };
+
+    let media_type = match self.next()? {
+      Token::Word(word) if is_ident(&word) && !is_reserved_keyword(&word) => word.to_lowercase(),
+      _ => return None,
+    };
+
+    let condition = if self.peek_keyword("and") {
+      self.next();
+
+      Some(self.parse_condition(false)?)
+    } else {
+      None
+    };
+
+    Some(MediaQuery {
+      modifier,
+      media_type: Some(media_type),
+      condition,
+    })
+  }
+
+  fn parse_condition(&mut self, allow_or: bool) -> Option<MediaCondition> {
+    if self.peek_keyword("not") {
+      self.next();
+
+      return Some(MediaCondition::Not(Box::new(self.parse_in_parens()?)));
+    }
+
+    let mut conditions = vec![self.parse_in_parens()?];
+    let mut combinator: Option<String> = None;
+
+    while self.peek_keyword("and") || (allow_or && self.peek_keyword("or")) {
+      let Some(Token::Word(keyword)) = self.next() else {
+        return None;
+      };
+
+      let keyword = keyword.to_lowercase();
+
+      if combinator.get_or_insert_with(|| keyword.clone()) != &keyword {
+        return None;
+      }
+
+      conditions.push(self.parse_in_parens()?);
+    }
+
+    Some(match combinator.as_deref() {
+      None => conditions.remove(0),
+      Some("and") => MediaCondition::And(conditions),
+      _ => MediaCondition::Or(conditions),
+    })
+  }
+
+  fn parse_in_parens(&mut self) -> Option<MediaCondition> {
+    self.expect(Token::LeftParen)?;
+
+    let is_nested_condition = matches!(self.peek(), Some(Token::LeftParen))
+      || (self.peek_keyword("not")
+        && self.tokens.get(self.position + 1) == Some(&Token::LeftParen));
+
+    let condition = if is_nested_condition {
+      self.parse_condition(true)?
+    } else {
+      MediaCondition::Feature(self.parse_feature()?)
+    };
+
+    self.expect(Token::RightParen)?;
+
+    Some(condition)
+  }
+
+  fn parse_feature(&mut self) -> Option<MediaFeature> {
+    let mut parts: Vec<Vec<Token>> = vec![vec![]];
+    let mut operators = vec![];
+    let mut has_colon = false;
+
+    while let Some(token) = self.peek() {
+      match token {
+        Token::RightParen => break,
+        Token::LeftParen | Token::Comma => return None,
+        Token::Operator(operator) => {
+          operators.push(operator.clone());
+          parts.push(vec![]);
+        }
+        Token::Colon => {
+          if has_colon || !operators.is_empty() {
+            return None;
+          }
+
+          has_colon = true;
+          parts.push(vec![]);
+        }
+        _ => parts.last_mut()?.push(token.clone()),
+      }
+
+      self.position += 1;
+    }
+
+    if parts.iter().any(|part| part.is_empty()) {
+      return None;
+    }
+
+    if has_colon {
+      let name = get_feature_name(&parts[0])?;
+
+      return Some(MediaFeature::Plain(name, print_value(&parts[1])?));
+    }
+
+    match operators.as_slice() {
+      [] => Some(MediaFeature::Boolean(get_feature_name(&parts[0])?)),
+      [operator] => {
+        if let Some(name) = get_range_feature_name(&parts[0]) {
+          Some(MediaFeature::Range(
+            name,
+            operator.clone(),
+            print_value(&parts[1])?,
+          ))
+        } else {
+          Some(MediaFeature::Range(
+            get_range_feature_name(&parts[1])?,
+            flip_operator(operator).to_string(),
+            print_value(&parts[0])?,
+          ))
+        }
+      }
+      [start_operator, end_operator] => {
+        let name = get_range_feature_name(&parts[1])?;
+        let start = print_value(&parts[0])?;
+        let end = print_value(&parts[2])?;
+
+        let is_ascending = |operator: &str| operator.starts_with('<');
+        let is_descending = |operator: &str| operator.starts_with('>');
+
+        if is_ascending(start_operator) && is_ascending(end_operator) {
+          Some(MediaFeature::Interval(
+            start,
+            start_operator.clone(),
+            name,
+            end_operator.clone(),
+            end,
+          ))
+        } else if is_descending(start_operator) && is_descending(end_operator) {
+          Some(MediaFeature::Interval(
+            end,
+            flip_operator(end_operator).to_string(),
+            name,
+            flip_operator(start_operator).to_string(),
+            start,
+          ))
+        } else {
+          None
+        }
+      }
+      _ => None,
+    }
+  }
+}
+
+fn is_ident(word: &str) -> bool {
+  let word = word.trim_start_matches('-');
+
+  word
+    .chars()
+    .next()
+    .is_some_and(|chr| chr.is_ascii_alphabetic())
+    && word
+      .chars()
+      .all(|chr| chr.is_ascii_alphanumeric() || chr == '-' || chr == '_')
+}
+
+fn is_reserved_keyword(word: &str) -> bool {
+  matches!(
+    word.to_lowercase().as_str(),
+    "not" | "only" | "and" | "or" | "layer"
+  )
+}
+
+// Custom media features such as `(--Foo)` are case-sensitive, so only the
+// standard feature names are lowercased
+fn get_feature_name(tokens: &[Token]) -> Option<String> {
+  match tokens {
+    [Token::Word(word)] if is_ident(word) && !is_reserved_keyword(word) => {
+      if word.starts_with("--") {
+        Some(word.clone())
+      } else {
+        Some(word.to_lowercase())
+      }
+    }
+    _ => None,
+  }
+}
+
+// `min-` and `max-` prefixed features cannot be used with range syntax
+fn get_range_feature_name(tokens: &[Token]) -> Option<String> {
+  get_feature_name(tokens).filter(|name| !name.starts_with("min-") && !name.starts_with("max-"))
+}
+
+fn print_value(tokens: &[Token]) -> Option<String> {
+  let mut value = String::new();
+
+  for (index, token) in tokens.iter().enumerate() {
+    match token {
+      Token::Word(word) => {
+        if index > 0 && !matches!(tokens[index - 1], Token::Slash) {
+          value.push(' ');
+        }
+
+        value.push_str(word);
+      }
+      Token::Slash if index > 0 && index < tokens.len() - 1 => value.push('/'),
+      _ => return None,
+    }
+  }
+
+  Some(value)
+}
+
+fn flip_operator(operator: &str) -> &str {
+  match operator {
+    "<" => ">",
+    "<=" => ">=",
+    ">" => "<",
+    ">=" => "<=",
+    _ => operator,
+  }
+}
+
+// Rewrites inclusive range features to the `min-` and `max-` form supported by older browsers
+fn rewrite_ranges(condition: MediaCondition) -> MediaCondition {
+  match condition {
+    MediaCondition::Feature(MediaFeature::Range(name, operator, value)) => {
+      match operator.as_str() {
+        ">=" => MediaCondition::Feature(MediaFeature::Plain(format!("min-{}", name), value)),
+        "<=" => MediaCondition::Feature(MediaFeature::Plain(format!("max-{}", name), value)),
+        "=" => MediaCondition::Feature(MediaFeature::Plain(name, value)),
+        _ => MediaCondition::Feature(MediaFeature::Range(name, operator, value)),
+      }
+    }
+    MediaCondition::Feature(MediaFeature::Interval(
+      start,
+      start_operator,
+      name,
+      end_operator,
+      end,
+    )) if start_operator == "<=" && end_operator == "<=" => MediaCondition::And(vec![
+      MediaCondition::Feature(MediaFeature::Plain(format!("min-{}", name), start)),
+      MediaCondition::Feature(MediaFeature::Plain(format!("max-{}", name), end)),
+    ]),
+    MediaCondition::Not(condition) => MediaCondition::Not(Box::new(rewrite_ranges(*condition))),
+    MediaCondition::And(conditions) => MediaCondition::And(
+      conditions
+        .into_iter()
+        .map(rewrite_ranges)
+        .flat_map(|condition| match condition {
+          MediaCondition::And(conditions) => conditions,
+          condition => vec![condition],
+        })
+        .collect(),
+    ),
+    MediaCondition::Or(conditions) => {
+      MediaCondition::Or(conditions.into_iter().map(rewrite_ranges).collect())
+    }
+    condition => condition,
+  }
+}
+
+fn print_query(query: &MediaQuery) -> String {
+  let mut parts = vec![];
+
+  if let Some(modifier) = &query.modifier {
+    parts.push(modifier.clone());
+  }
+
+  if let Some(media_type) = &query.media_type {
+    parts.push(media_type.clone());
+  }
+
+  if let Some(condition) = &query.condition {
+    if query.media_type.is_some() {
+      parts.push("and".to_string());
+    }
+
+    parts.push(print_condition(condition));
+  }
+
+  parts.join(" ")
+}
+
+fn print_condition(condition: &MediaCondition) -> String {
+  match condition {
+    MediaCondition::Feature(feature) => print_feature(feature),
+    MediaCondition::Not(condition) => format!("not {}", print_in_parens(condition)),
+    MediaCondition::And(conditions) => print_conditions(conditions, " and "),
+    MediaCondition::Or(conditions) => print_conditions(conditions, " or "),
+  }
+}
+
+// Conditions are sorted because `and` and `or` are commutative
+fn print_conditions(conditions: &[MediaCondition], separator: &str) -> String {
+  let mut printed = conditions
+    .iter()
+    .map(|condition| (get_sort_key(condition), print_in_parens(condition)))
+    .collect::<Vec<((String, u8), String)>>();
+
+  printed.sort();
+  printed.dedup_by(|a, b| a.1 == b.1);
+
+  printed
+    .into_iter()
+    .map(|(_, condition)| condition)
+    .collect::<Vec<String>>()
+    .join(separator)
+}
+
+// Features are grouped by name with lower bounds first, which keeps the
+// common `(min-width: ...) and (max-width: ...)` order intact
+fn get_sort_key(condition: &MediaCondition) -> (String, u8) {
+  let get_bound_key = |name: &str| {
+    if let Some(name) = name.strip_prefix("min-") {
+      (name.to_string(), 0)
+    } else if let Some(name) = name.strip_prefix("max-") {
+      (name.to_string(), 2)
+    } else {
+      (name.to_string(), 1)
+    }
+  };
+
+  match condition {
+    MediaCondition::Feature(MediaFeature::Boolean(name)) => (name.clone(), 1),
+    MediaCondition::Feature(MediaFeature::Plain(name, _)) => get_bound_key(name),
+    MediaCondition::Feature(MediaFeature::Range(name, operator, _)) => {
+      let rank = match operator.chars().next() {
+        Some('>') => 0,
+        Some('<') => 2,
+        _ => 1,
+      };
+
+      (name.clone(), rank)
+    }
+    MediaCondition::Feature(MediaFeature::Interval(_, _, name, _, _)) => (name.clone(), 1),
+    _ => (print_condition(condition), 1),
+  }
+}
+
+fn print_in_parens(condition: &MediaCondition) -> String {
+  match condition {
+    MediaCondition::Feature(feature) => print_feature(feature),
+    _ => format!("({})", print_condition(condition)),
+  }
+}
+
+fn print_feature(feature: &MediaFeature) -> String {
+  match feature {
+    MediaFeature::Boolean(name) => format!("({})", name),
+    MediaFeature::Plain(name, value) => format!("({}: {})", name, value),
+    MediaFeature::Range(name, operator, value) => format!("({} {} {})", name, operator, value),
+    MediaFeature::Interval(start, start_operator, name, end_operator, end) => format!(
+      "({} {} {} {} {})",
+      start, start_operator, name, end_operator, end
+    ),
+  }
+}
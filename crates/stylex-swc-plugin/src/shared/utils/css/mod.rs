@@ -1,5 +1,9 @@
+pub(crate) mod at_rule;
 pub(crate) mod common;
-pub(crate) mod normalizers;
+pub(crate) mod media_query;
+pub mod normalizers;
 pub(crate) mod parser;
+pub(crate) mod pipeline;
+pub(crate) mod syntax;
 pub(crate) mod tests;
-pub(crate) mod validators;
+pub mod validators;
@@ -9,28 +9,17 @@ use swc_core::{
   },
 };
 
-use crate::shared::{constants::common::ROOT_FONT_SIZE, utils::common::dashify};
+use crate::shared::{
+  structures::{stylex_options::PxToRemOptions, stylex_state_options::StyleXStateOptions},
+  utils::{common::dashify, css::common::convert_px_to_rem},
+};
 
-struct CssFolder {
-  use_rem_for_font_size: bool,
-  parent_key: Option<String>,
-}
+use super::CssNormalizer;
 
-impl CssFolder {
-  fn convert_font_size_to_rem_normalizer<'a>(
-    &'a mut self,
-    declaration: &'a mut Declaration,
-  ) -> &'a mut Declaration {
-    if let DeclarationName::Ident(ident) = &declaration.name {
-      if ident.value.eq("fontSize") || self.parent_key.as_deref() == Some("fontSize") {
-        self.parent_key = Some("fontSize".into());
-        declaration.value = declaration.value.clone().fold_children_with(self);
-        self.parent_key = None;
-      }
-    }
-
-    declaration
-  }
+struct CssFolder {
+  rem_properties: Vec<String>,
+  px_to_rem: PxToRemOptions,
+  convert_to_rem: bool,
 }
 
 impl Fold for CssFolder {
@@ -44,11 +33,16 @@ impl Fold for CssFolder {
   fn fold_declaration(&mut self, mut declaration: Declaration) -> Declaration {
     let declaration = kebab_case_normalizer(&mut declaration);
 
-    if self.use_rem_for_font_size {
-      self.convert_font_size_to_rem_normalizer(declaration);
-    }
+    self.convert_to_rem = match &declaration.name {
+      DeclarationName::Ident(ident) => self.rem_properties.contains(&dashify(&ident.value)),
+      DeclarationName::DashedIdent(_) => false,
+    };
 
-    declaration.clone().fold_children_with(self)
+    let declaration = declaration.clone().fold_children_with(self);
+
+    self.convert_to_rem = false;
+
+    declaration
   }
 
   fn fold_dimension(&mut self, mut dimension: Dimension) -> Dimension {
@@ -59,13 +53,10 @@ impl Fold for CssFolder {
   }
 
   fn fold_length(&mut self, mut length: Length) -> Length {
-    if self.parent_key == Some("fontSize".into())
-      && length.unit.value.eq("px")
-      && length.value.value != 0.0
-    {
+    if self.convert_to_rem && length.unit.value.eq("px") && length.value.value != 0.0 {
       length = Length {
         value: Number {
-          value: length.value.value / ROOT_FONT_SIZE as f64,
+          value: convert_px_to_rem(length.value.value, &self.px_to_rem),
           raw: None,
           span: length.span,
         },
@@ -149,10 +140,56 @@ fn kebab_case_normalizer(declaration: &mut Declaration) -> &mut Declaration {
   declaration
 }
 
-pub(crate) fn base_normalizer(ast: Stylesheet, use_rem_for_font_size: bool) -> Stylesheet {
+#[derive(Debug)]
+pub(crate) struct BaseNormalizer;
+
+impl CssNormalizer for BaseNormalizer {
+  fn name(&self) -> &str {
+    "base"
+  }
+
+  fn normalize(
+    &self,
+    _property: &str,
+    ast: Stylesheet,
+    options: &StyleXStateOptions,
+  ) -> Stylesheet {
+    fold_with_rem_properties(
+      ast,
+      get_rem_properties(options),
+      options.px_to_rem.clone().unwrap_or_default(),
+    )
+  }
+}
+
+pub(crate) fn get_rem_properties(options: &StyleXStateOptions) -> Vec<String> {
+  let mut rem_properties = vec![];
+
+  if options.use_rem_for_font_size {
+    rem_properties.push("font-size".to_string());
+  }
+
+  if let Some(px_to_rem) = &options.px_to_rem {
+    rem_properties.extend(
+      px_to_rem
+        .properties
+        .iter()
+        .map(|property| dashify(property)),
+    );
+  }
+
+  rem_properties
+}
+
+fn fold_with_rem_properties(
+  ast: Stylesheet,
+  rem_properties: Vec<String>,
+  px_to_rem: PxToRemOptions,
+) -> Stylesheet {
   let mut folder = CssFolder {
-    use_rem_for_font_size,
-    parent_key: None,
+    rem_properties,
+    px_to_rem,
+    convert_to_rem: false,
   };
   ast.fold_with(&mut folder)
 }
@@ -0,0 +1,359 @@
+use phf::phf_set;
+use swc_core::{
+  common::DUMMY_SP,
+  css::{
+    ast::{
+      AbsoluteColorBase, AlphaValue, Color, ComponentValue, Declaration, DeclarationName,
+      DelimiterValue, Function, FunctionName, HexColor, Hue, Ident, Stylesheet,
+    },
+    visit::{Fold, FoldWith},
+  },
+};
+
+use crate::shared::{
+  constants::named_colors::NAMED_COLORS, structures::stylex_state_options::StyleXStateOptions,
+  utils::common::dashify,
+};
+
+use super::CssNormalizer;
+
+static COLOR_SHORTHAND_PROPERTIES: phf::Set<&'static str> = phf_set! {
+  "background",
+  "border",
+  "border-top",
+  "border-right",
+  "border-bottom",
+  "border-left",
+  "border-block",
+  "border-block-start",
+  "border-block-end",
+  "border-inline",
+  "border-inline-start",
+  "border-inline-end",
+  "box-shadow",
+  "column-rule",
+  "fill",
+  "outline",
+  "stroke",
+  "text-decoration",
+  "text-emphasis",
+  "text-shadow",
+};
+
+// Functions whose arguments are resolved at runtime or mix colors in a specific color space
+static SKIPPED_FUNCTIONS: phf::Set<&'static str> = phf_set! {
+  "var",
+  "env",
+  "attr",
+  "color-mix",
+  "light-dark",
+};
+
+#[derive(Debug)]
+pub(crate) struct ColorNormalizer;
+
+impl CssNormalizer for ColorNormalizer {
+  fn name(&self) -> &str {
+    "color"
+  }
+
+  fn normalize(
+    &self,
+    property: &str,
+    ast: Stylesheet,
+    _options: &StyleXStateOptions,
+  ) -> Stylesheet {
+    // Custom properties are parsed as colors, but their values can be any tokens
+    if property.starts_with("--") {
+      return ast;
+    }
+
+    color_normalizer(ast)
+  }
+}
+
+pub(crate) fn color_normalizer(ast: Stylesheet) -> Stylesheet {
+  ast.fold_with(&mut ColorFolder {
+    named_colors_allowed: false,
+  })
+}
+
+struct ColorFolder {
+  named_colors_allowed: bool,
+}
+
+impl Fold for ColorFolder {
+  fn fold_declaration(&mut self, declaration: Declaration) -> Declaration {
+    let DeclarationName::Ident(ident) = &declaration.name else {
+      return declaration;
+    };
+
+    let property = dashify(ident.value.as_ref()).to_lowercase();
+
+    self.named_colors_allowed =
+      property.ends_with("color") || COLOR_SHORTHAND_PROPERTIES.contains(property.as_str());
+
+    let declaration = declaration.fold_children_with(self);
+
+    self.named_colors_allowed = false;
+
+    declaration
+  }
+
+  fn fold_function(&mut self, function: Function) -> Function {
+    let name = get_function_name(&function);
+
+    if SKIPPED_FUNCTIONS.contains(name.as_str()) {
+      return function;
+    }
+
+    if name.ends_with("gradient") {
+      let named_colors_allowed = self.named_colors_allowed;
+
+      self.named_colors_allowed = true;
+
+      let function = function.fold_children_with(self);
+
+      self.named_colors_allowed = named_colors_allowed;
+
+      return function;
+    }
+
+    function.fold_children_with(self)
+  }
+
+  fn fold_color(&mut self, color: Color) -> Color {
+    let rgba = match &color {
+      Color::AbsoluteColorBase(AbsoluteColorBase::HexColor(hex_color)) => {
+        parse_hex_color(hex_color.value.as_ref())
+      }
+      Color::AbsoluteColorBase(AbsoluteColorBase::Function(function)) => {
+        match get_function_name(function).as_str() {
+          "rgb" | "rgba" => parse_rgb_function(function),
+          "hsl" | "hsla" => parse_hsl_function(function),
+          _ => None,
+        }
+      }
+      _ => None,
+    };
+
+    match rgba {
+      Some(rgba) => create_color(rgba),
+      None => color.fold_children_with(self),
+    }
+  }
+
+  fn fold_component_value(&mut self, value: ComponentValue) -> ComponentValue {
+    if let ComponentValue::Ident(ident) = &value {
+      if self.named_colors_allowed {
+        if let Some(hex_color) = NAMED_COLORS.get(ident.value.to_lowercase().as_str()) {
+          if let Some(rgba) = parse_hex_color(&hex_color[1..]) {
+            return ComponentValue::Color(Box::new(create_color(rgba)));
+          }
+        }
+      }
+    }
+
+    value.fold_children_with(self)
+  }
+}
+
+fn get_function_name(function: &Function) -> String {
+  match &function.name {
+    FunctionName::Ident(ident) => ident.value.to_lowercase(),
+    FunctionName::DashedIdent(ident) => format!("--{}", ident.value),
+  }
+}
+
+fn parse_hex_color(value: &str) -> Option<[u8; 4]> {
+  if !value.chars().all(|chr| chr.is_ascii_hexdigit()) {
+    return None;
+  }
+
+  let digits = match value.len() {
+    3 | 4 => value.chars().flat_map(|chr| [chr, chr]).collect::<String>(),
+    6 | 8 => value.to_string(),
+    _ => return None,
+  };
+
+  let mut rgba = [255u8; 4];
+
+  for (index, channel) in rgba.iter_mut().enumerate().take(digits.len() / 2) {
+    *channel = u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16).ok()?;
+  }
+
+  Some(rgba)
+}
+
+fn parse_rgb_function(function: &Function) -> Option<[u8; 4]> {
+  let (channels, is_opaque) = get_color_function_arguments(function)?;
+
+  if !is_opaque {
+    return None;
+  }
+
+  let mut rgba = [255u8; 4];
+
+  for (channel, value) in rgba.iter_mut().zip(channels.iter()) {
+    let value = match value {
+      ComponentValue::Number(number) => number.value,
+      ComponentValue::Percentage(percentage) => percentage.value.value * 2.55,
+      _ => return None,
+    };
+
+    *channel = to_channel(value)?;
+  }
+
+  Some(rgba)
+}
+
+fn parse_hsl_function(function: &Function) -> Option<[u8; 4]> {
+  let (channels, is_opaque) = get_color_function_arguments(function)?;
+
+  if !is_opaque {
+    return None;
+  }
+
+  let hue = match &channels[0] {
+    ComponentValue::Number(number) => number.value,
+    ComponentValue::Hue(hue) => match hue.as_ref() {
+      Hue::Number(number) => number.value,
+      Hue::Angle(angle) => match angle.unit.value.to_lowercase().as_str() {
+        "deg" => angle.value.value,
+        "grad" => angle.value.value * 0.9,
+        "rad" => angle.value.value.to_degrees(),
+        "turn" => angle.value.value * 360.0,
+        _ => return None,
+      },
+    },
+    _ => return None,
+  };
+
+  let get_fraction = |value: &ComponentValue| match value {
+    ComponentValue::Percentage(percentage) => Some(percentage.value.value / 100.0),
+    ComponentValue::Number(number) => Some(number.value / 100.0),
+    _ => None,
+  };
+
+  let saturation = get_fraction(&channels[1])?.clamp(0.0, 1.0);
+  let lightness = get_fraction(&channels[2])?.clamp(0.0, 1.0);
+
+  let hue = hue.rem_euclid(360.0);
+
+  let get_channel = |offset: f64| {
+    let k = (offset + hue / 30.0) % 12.0;
+    let amount = saturation * lightness.min(1.0 - lightness);
+
+    lightness - amount * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+  };
+
+  Some([
+    to_channel(get_channel(0.0) * 255.0)?,
+    to_channel(get_channel(8.0) * 255.0)?,
+    to_channel(get_channel(4.0) * 255.0)?,
+    255,
+  ])
+}
+
+// Returns the three color channels and whether the alpha channel is fully opaque
+fn get_color_function_arguments(function: &Function) -> Option<(Vec<ComponentValue>, bool)> {
+  let mut channels = vec![];
+  let mut alpha = None;
+  let mut is_alpha = false;
+
+  for value in function.value.iter() {
+    match value {
+      ComponentValue::Delimiter(delimiter) => {
+        if delimiter.value == DelimiterValue::Solidus {
+          is_alpha = true;
+        }
+      }
+      ComponentValue::AlphaValue(alpha_value) => {
+        alpha = Some(match alpha_value.as_ref() {
+          AlphaValue::Number(number) => number.value,
+          AlphaValue::Percentage(percentage) => percentage.value.value / 100.0,
+        });
+      }
+      ComponentValue::Number(_) | ComponentValue::Percentage(_) | ComponentValue::Hue(_) => {
+        if is_alpha || channels.len() == 3 {
+          alpha = Some(match value {
+            ComponentValue::Number(number) => number.value,
+            ComponentValue::Percentage(percentage) => percentage.value.value / 100.0,
+            _ => return None,
+          });
+        } else {
+          channels.push(value.clone());
+        }
+      }
+      _ => return None,
+    }
+  }
+
+  if channels.len() != 3 {
+    return None;
+  }
+
+  Some((channels, alpha.map_or(true, |alpha| alpha >= 1.0)))
+}
+
+// Only exact channel values are converted so the normalization never changes the rendered color
+fn to_channel(value: f64) -> Option<u8> {
+  let rounded = value.round();
+
+  if (value - rounded).abs() > 1e-6 || !(0.0..=255.0).contains(&rounded) {
+    return None;
+  }
+
+  Some(rounded as u8)
+}
+
+fn create_color(rgba: [u8; 4]) -> Color {
+  let hex_color = format_hex_color(rgba);
+
+  if rgba[3] == 255 {
+    let named_color = NAMED_COLORS
+      .entries()
+      .filter(|(_, value)| format_hex_color(parse_hex_color(&value[1..]).unwrap()) == hex_color)
+      .map(|(name, _)| *name)
+      .min_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+
+    if let Some(named_color) = named_color {
+      if named_color.len() < hex_color.len() + 1 {
+        return Color::AbsoluteColorBase(AbsoluteColorBase::NamedColorOrTransparent(Ident {
+          span: DUMMY_SP,
+          value: named_color.into(),
+          raw: None,
+        }));
+      }
+    }
+  }
+
+  Color::AbsoluteColorBase(AbsoluteColorBase::HexColor(HexColor {
+    span: DUMMY_SP,
+    value: hex_color.into(),
+    raw: None,
+  }))
+}
+
+fn format_hex_color(rgba: [u8; 4]) -> String {
+  let channels = if rgba[3] == 255 {
+    &rgba[..3]
+  } else {
+    &rgba[..]
+  };
+
+  let is_shortenable = channels
+    .iter()
+    .all(|channel| channel >> 4 == channel & 0x0f);
+
+  channels
+    .iter()
+    .map(|channel| {
+      if is_shortenable {
+        format!("{:x}", channel & 0x0f)
+      } else {
+        format!("{:02x}", channel)
+      }
+    })
+    .collect()
+}
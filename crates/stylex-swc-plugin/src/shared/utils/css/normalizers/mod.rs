@@ -1,3 +1,17 @@
+use std::fmt::Debug;
+
+use swc_core::css::ast::Stylesheet;
+
+use crate::shared::structures::stylex_state_options::StyleXStateOptions;
+
 pub(crate) mod base;
+pub(crate) mod color;
 pub(crate) mod tests;
 pub(crate) mod whitespace_normalizer;
+
+pub trait CssNormalizer: Debug {
+  /// Name used to disable the normalizer with `disabledCssNormalizers`
+  fn name(&self) -> &str;
+
+  fn normalize(&self, property: &str, ast: Stylesheet, options: &StyleXStateOptions) -> Stylesheet;
+}
@@ -1,8 +1,22 @@
 #[cfg(test)]
 
 mod normalizers {
-  use crate::shared::utils::css::common::{stringify, swc_parse_css};
-  use crate::shared::utils::css::normalizers::base::base_normalizer;
+  use swc_core::css::ast::Stylesheet;
+
+  use crate::shared::structures::stylex_state_options::StyleXStateOptions;
+  use crate::shared::utils::css::common::swc_parse_css;
+  use crate::shared::utils::css::normalizers::{
+    base::BaseNormalizer, tests::stringify, CssNormalizer,
+  };
+
+  fn base_normalizer(ast: Stylesheet, use_rem_for_font_size: bool) -> Stylesheet {
+    let options = StyleXStateOptions {
+      use_rem_for_font_size,
+      ..StyleXStateOptions::_new()
+    };
+
+    BaseNormalizer.normalize("", ast, &options)
+  }
 
   #[test]
   fn should_normalize() {
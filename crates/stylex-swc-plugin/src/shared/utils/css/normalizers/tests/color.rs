@@ -0,0 +1,182 @@
+#[cfg(test)]
+
+mod color_normalizer {
+  use swc_core::common::DUMMY_SP;
+
+  use crate::shared::structures::stylex_state_options::StyleXStateOptions;
+  use crate::shared::utils::css::common::{normalize_css_property_value, swc_parse_css};
+  use crate::shared::utils::css::normalizers::color::color_normalizer;
+  use crate::shared::utils::css::normalizers::tests::stringify;
+  use crate::shared::utils::css::pipeline::CssPipeline;
+
+  fn normalize(css: &str) -> String {
+    stringify(&color_normalizer(swc_parse_css(css).0.unwrap()))
+  }
+
+  #[test]
+  fn should_shorten_hex_colors() {
+    assert_eq!(normalize("* { color: #FFFFFF }"), "*{color:#fff}");
+    assert_eq!(normalize("* { color: #FFF }"), "*{color:#fff}");
+    assert_eq!(normalize("* { color: #1A2B3C }"), "*{color:#1a2b3c}");
+    assert_eq!(normalize("* { color: #11223344 }"), "*{color:#1234}");
+    assert_eq!(normalize("* { color: #112233FF }"), "*{color:#123}");
+  }
+
+  #[test]
+  fn should_use_shortest_representation() {
+    assert_eq!(normalize("* { color: #FF0000 }"), "*{color:red}");
+    assert_eq!(normalize("* { color: white }"), "*{color:#fff}");
+    assert_eq!(normalize("* { color: WHITE }"), "*{color:#fff}");
+    assert_eq!(normalize("* { color: tan }"), "*{color:tan}");
+    assert_eq!(normalize("* { color: fuchsia }"), "*{color:#f0f}");
+  }
+
+  #[test]
+  fn should_convert_color_functions() {
+    assert_eq!(normalize("* { color: rgb(255 255 255) }"), "*{color:#fff}");
+    assert_eq!(
+      normalize("* { color: rgb(255, 255, 255) }"),
+      "*{color:#fff}"
+    );
+    assert_eq!(normalize("* { color: rgba(255, 0, 0, 1) }"), "*{color:red}");
+    assert_eq!(normalize("* { color: rgb(100% 0% 0%) }"), "*{color:red}");
+    assert_eq!(normalize("* { color: hsl(0deg 100% 50%) }"), "*{color:red}");
+    assert_eq!(
+      normalize("* { color: hsl(120, 100%, 50%) }"),
+      "*{color:#0f0}"
+    );
+    assert_eq!(
+      normalize("* { color: hsl(0.5turn 100% 50%) }"),
+      "*{color:#0ff}"
+    );
+  }
+
+  #[test]
+  fn should_keep_colors_that_cannot_be_converted_exactly() {
+    assert_eq!(
+      normalize("* { color: rgba(255, 0, 0, .5) }"),
+      "*{color:rgba(255,0,0,.5)}"
+    );
+    assert_eq!(
+      normalize("* { color: rgb(255 0 0 / 50%) }"),
+      "*{color:rgb(255 0 0/50%)}"
+    );
+    assert_eq!(
+      normalize("* { color: hsl(120, 100%, 25%) }"),
+      "*{color:hsl(120,100%,25%)}"
+    );
+  }
+
+  #[test]
+  fn should_normalize_colors_inside_shorthands_and_gradients() {
+    assert_eq!(
+      normalize("* { border: 1px solid #FFFFFF }"),
+      "*{border:1px solid#fff}"
+    );
+    assert_eq!(
+      normalize("* { background-image: linear-gradient(white, #000000) }"),
+      "*{background-image:linear-gradient(#fff,#000)}"
+    );
+  }
+
+  #[test]
+  fn should_ignore_dynamic_colors_and_other_properties() {
+    assert_eq!(
+      normalize("* { color: var(--color, white) }"),
+      "*{color:var(--color,white)}"
+    );
+    assert_eq!(
+      normalize("* { color: currentColor }"),
+      "*{color:currentColor}"
+    );
+    assert_eq!(
+      normalize("* { color: color-mix(in srgb, white, #FFFFFF) }"),
+      "*{color:color-mix(in srgb,white,#FFF)}"
+    );
+    assert_eq!(
+      normalize("* { animation-name: white }"),
+      "*{animation-name:white}"
+    );
+  }
+
+  #[test]
+  fn should_only_run_when_enabled() {
+    let options = StyleXStateOptions::_new();
+
+    assert_eq!(
+      normalize_css_property_value(
+        "color",
+        "#FFFFFF",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "#FFF"
+    );
+
+    let options = StyleXStateOptions {
+      normalize_colors: true,
+      ..StyleXStateOptions::_new()
+    };
+
+    assert_eq!(
+      normalize_css_property_value(
+        "color",
+        "#FFFFFF",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "#fff"
+    );
+    assert_eq!(
+      normalize_css_property_value(
+        "backgroundColor",
+        "rgb(255 255 255)",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "#fff"
+    );
+  }
+
+  #[test]
+  fn should_not_normalize_custom_properties() {
+    let options = StyleXStateOptions {
+      normalize_colors: true,
+      ..StyleXStateOptions::_new()
+    };
+
+    assert_eq!(
+      normalize_css_property_value(
+        "--background",
+        "white",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "white"
+    );
+    assert_eq!(
+      normalize_css_property_value(
+        "--ease",
+        "tan orange",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "tan orange"
+    );
+    assert_eq!(
+      normalize_css_property_value(
+        "--shadow",
+        "0 0 4px #FFFFFF",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "0 0 4px #FFF"
+    );
+  }
+}
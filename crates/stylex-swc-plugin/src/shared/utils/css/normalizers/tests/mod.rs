@@ -1 +1,25 @@
 mod base;
+mod color;
+
+#[cfg(test)]
+use swc_core::css::{
+  ast::Stylesheet,
+  codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+  },
+};
+
+#[cfg(test)]
+use crate::shared::utils::css::common::unescape_stringified;
+
+#[cfg(test)]
+fn stringify(node: &Stylesheet) -> String {
+  let mut buf = String::new();
+  let writer = BasicCssWriter::new(&mut buf, None, BasicCssWriterConfig::default());
+  let mut codegen = CodeGenerator::new(writer, CodegenConfig { minify: true });
+
+  codegen.emit(node).unwrap();
+
+  unescape_stringified(buf)
+}
@@ -0,0 +1,90 @@
+use std::rc::Rc;
+
+use swc_core::{common::Span, css::ast::Stylesheet};
+
+use crate::shared::structures::stylex_state_options::StyleXStateOptions;
+
+use super::{
+  normalizers::{base::BaseNormalizer, color::ColorNormalizer, CssNormalizer},
+  validators::{
+    property_names::PropertyNamesValidator, property_values::PropertyValuesValidator,
+    unprefixed_custom_properties::UnprefixedCustomPropertiesValidator, CssValidator,
+  },
+};
+
+#[derive(Clone, Debug)]
+pub(crate) struct CssPipeline {
+  validators: Vec<Rc<dyn CssValidator>>,
+  normalizers: Vec<Rc<dyn CssNormalizer>>,
+}
+
+impl CssPipeline {
+  pub(crate) fn new(options: &StyleXStateOptions) -> Self {
+    let built_in_validators: Vec<Rc<dyn CssValidator>> = vec![
+      Rc::new(UnprefixedCustomPropertiesValidator),
+      Rc::new(PropertyNamesValidator),
+      Rc::new(PropertyValuesValidator),
+    ];
+
+    let mut built_in_normalizers: Vec<Rc<dyn CssNormalizer>> = vec![Rc::new(BaseNormalizer)];
+
+    if options.normalize_colors {
+      built_in_normalizers.push(Rc::new(ColorNormalizer));
+    }
+
+    let validators = built_in_validators
+      .into_iter()
+      .chain(options.css_validators.iter().cloned())
+      .filter(|validator| {
+        !options
+          .disabled_css_validators
+          .iter()
+          .any(|name| name == validator.name())
+      })
+      .collect();
+
+    let normalizers = built_in_normalizers
+      .into_iter()
+      .chain(options.css_normalizers.iter().cloned())
+      .filter(|normalizer| {
+        !options
+          .disabled_css_normalizers
+          .iter()
+          .any(|name| name == normalizer.name())
+      })
+      .collect();
+
+    CssPipeline {
+      validators,
+      normalizers,
+    }
+  }
+
+  pub(crate) fn validate(
+    &self,
+    property: &str,
+    ast: &Stylesheet,
+    span: Span,
+    options: &StyleXStateOptions,
+  ) {
+    for validator in self.validators.iter() {
+      validator.validate(property, ast, span, options);
+    }
+  }
+
+  /// Whether values that the base normalizer leaves untouched can skip normalization
+  pub(crate) fn has_only_base_normalizer(&self) -> bool {
+    matches!(self.normalizers.as_slice(), [normalizer] if normalizer.name() == "base")
+  }
+
+  pub(crate) fn normalize(
+    &self,
+    property: &str,
+    ast: Stylesheet,
+    options: &StyleXStateOptions,
+  ) -> Stylesheet {
+    self.normalizers.iter().fold(ast, |ast, normalizer| {
+      normalizer.normalize(property, ast, options)
+    })
+  }
+}
@@ -0,0 +1,254 @@
+use swc_core::css::ast::{ComponentValue, DelimiterValue, Dimension, FunctionName, Rule};
+
+use crate::shared::{
+  constants::named_colors::NAMED_COLORS,
+  enums::data_structures::css_syntax::{CSSSyntax, SyntaxMultiplier},
+};
+
+use super::common::swc_parse_css;
+
+pub(crate) static GLOBAL_KEYWORDS: [&str; 5] =
+  ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+static MATH_FUNCTIONS: [&str; 20] = [
+  "calc", "min", "max", "clamp", "round", "mod", "rem", "abs", "sign", "sin", "cos", "tan", "asin",
+  "acos", "atan", "atan2", "pow", "sqrt", "hypot", "log",
+];
+
+static COLOR_FUNCTIONS: [&str; 12] = [
+  "rgb",
+  "rgba",
+  "hsl",
+  "hsla",
+  "hwb",
+  "lab",
+  "lch",
+  "oklab",
+  "oklch",
+  "color",
+  "color-mix",
+  "light-dark",
+];
+
+// System colors are matched case-insensitively, so they are stored in lowercase
+static SYSTEM_COLORS: [&str; 19] = [
+  "accentcolor",
+  "accentcolortext",
+  "activetext",
+  "buttonborder",
+  "buttonface",
+  "buttontext",
+  "canvas",
+  "canvastext",
+  "field",
+  "fieldtext",
+  "graytext",
+  "highlight",
+  "highlighttext",
+  "linktext",
+  "mark",
+  "marktext",
+  "selecteditem",
+  "selecteditemtext",
+  "visitedtext",
+];
+
+static IMAGE_FUNCTIONS: [&str; 10] = [
+  "linear-gradient",
+  "radial-gradient",
+  "conic-gradient",
+  "repeating-linear-gradient",
+  "repeating-radial-gradient",
+  "repeating-conic-gradient",
+  "image",
+  "image-set",
+  "cross-fade",
+  "element",
+];
+
+static TRANSFORM_FUNCTIONS: [&str; 21] = [
+  "matrix",
+  "matrix3d",
+  "perspective",
+  "rotate",
+  "rotate3d",
+  "rotatex",
+  "rotatey",
+  "rotatez",
+  "scale",
+  "scale3d",
+  "scalex",
+  "scaley",
+  "scalez",
+  "skew",
+  "skewx",
+  "skewy",
+  "translate",
+  "translate3d",
+  "translatex",
+  "translatey",
+  "translatez",
+];
+
+/// Checks whether a value matches the syntax of a typed variable.
+/// Values that can only be resolved at runtime, like `var()`, are always accepted.
+pub(crate) fn matches_css_syntax(value: &str, syntax: &CSSSyntax) -> bool {
+  let value = value.trim();
+
+  if GLOBAL_KEYWORDS.contains(&value) || value.contains("var(") || value.contains("env(") {
+    return true;
+  }
+
+  parse_component_values(value).is_some_and(|values| matches_values(&values, syntax))
+}
+
+fn matches_values(values: &[ComponentValue], syntax: &CSSSyntax) -> bool {
+  match syntax {
+    CSSSyntax::Universal => true,
+    CSSSyntax::Union(syntaxes) => syntaxes.iter().any(|syntax| matches_values(values, syntax)),
+    CSSSyntax::TransformList => {
+      !values.is_empty()
+        && values
+          .iter()
+          .all(|value| matches_syntax(value, &CSSSyntax::TransformFunction))
+    }
+    CSSSyntax::Multiplier(item_syntax, SyntaxMultiplier::SpaceSeparated) => {
+      !values.is_empty()
+        && values
+          .iter()
+          .all(|value| matches_syntax(value, item_syntax))
+    }
+    CSSSyntax::Multiplier(item_syntax, SyntaxMultiplier::CommaSeparated) => values
+      .split(is_comma)
+      .all(|item| matches_values(item, item_syntax)),
+    _ => match values {
+      [value] => matches_syntax(value, syntax),
+      _ => false,
+    },
+  }
+}
+
+/// Checks whether a single component value matches the syntax
+pub(crate) fn matches_syntax(value: &ComponentValue, syntax: &CSSSyntax) -> bool {
+  if let ComponentValue::Function(function) = value {
+    let name = get_function_name(&function.name);
+
+    if MATH_FUNCTIONS.contains(&name.as_str()) {
+      return !matches!(
+        syntax,
+        CSSSyntax::Color
+          | CSSSyntax::Image
+          | CSSSyntax::Url
+          | CSSSyntax::TransformFunction
+          | CSSSyntax::TransformList
+          | CSSSyntax::CustomIdent
+          | CSSSyntax::String
+          | CSSSyntax::Keyword(_)
+      );
+    }
+  }
+
+  match syntax {
+    CSSSyntax::Length => match value {
+      ComponentValue::Dimension(dimension) => matches!(dimension.as_ref(), Dimension::Length(_)),
+      ComponentValue::Number(number) => number.value == 0.0,
+      ComponentValue::Integer(integer) => integer.value == 0,
+      _ => false,
+    },
+    CSSSyntax::Percentage => matches!(value, ComponentValue::Percentage(_)),
+    CSSSyntax::LengthPercentage => {
+      matches_syntax(value, &CSSSyntax::Length) || matches_syntax(value, &CSSSyntax::Percentage)
+    }
+    CSSSyntax::Number => matches!(
+      value,
+      ComponentValue::Number(_) | ComponentValue::Integer(_)
+    ),
+    CSSSyntax::Integer => match value {
+      ComponentValue::Integer(_) => true,
+      ComponentValue::Number(number) => number.value.fract() == 0.0,
+      _ => false,
+    },
+    CSSSyntax::Angle => match value {
+      ComponentValue::Dimension(dimension) => matches!(dimension.as_ref(), Dimension::Angle(_)),
+      ComponentValue::Number(number) => number.value == 0.0,
+      ComponentValue::Integer(integer) => integer.value == 0,
+      _ => false,
+    },
+    CSSSyntax::Time => match value {
+      ComponentValue::Dimension(dimension) => matches!(dimension.as_ref(), Dimension::Time(_)),
+      _ => false,
+    },
+    CSSSyntax::Resolution => match value {
+      ComponentValue::Dimension(dimension) => {
+        matches!(dimension.as_ref(), Dimension::Resolution(_))
+      }
+      _ => false,
+    },
+    CSSSyntax::Color => match value {
+      ComponentValue::Color(_) => true,
+      ComponentValue::Ident(ident) => {
+        let name = ident.value.to_lowercase();
+
+        name == "transparent"
+          || name == "currentcolor"
+          || NAMED_COLORS.contains_key(name.as_str())
+          || SYSTEM_COLORS.contains(&name.as_str())
+      }
+      ComponentValue::Function(function) => {
+        COLOR_FUNCTIONS.contains(&get_function_name(&function.name).as_str())
+      }
+      _ => false,
+    },
+    CSSSyntax::Url => matches!(value, ComponentValue::Url(_)),
+    CSSSyntax::Image => {
+      matches_syntax(value, &CSSSyntax::Url)
+        || matches!(value, ComponentValue::Function(function) if IMAGE_FUNCTIONS.contains(&get_function_name(&function.name).as_str()))
+    }
+    CSSSyntax::TransformFunction | CSSSyntax::TransformList => {
+      matches!(value, ComponentValue::Function(function) if TRANSFORM_FUNCTIONS.contains(&get_function_name(&function.name).as_str()))
+    }
+    CSSSyntax::CustomIdent => match value {
+      ComponentValue::Ident(ident) => {
+        let name = ident.value.to_lowercase();
+
+        name != "default" && !GLOBAL_KEYWORDS.contains(&name.as_str())
+      }
+      _ => false,
+    },
+    CSSSyntax::String => matches!(value, ComponentValue::Str(_)),
+    CSSSyntax::Keyword(keyword) => {
+      matches!(value, ComponentValue::Ident(ident) if ident.value.eq_ignore_ascii_case(keyword))
+    }
+    CSSSyntax::Universal => true,
+    CSSSyntax::Multiplier(item_syntax, _) => matches_syntax(value, item_syntax),
+    CSSSyntax::Union(syntaxes) => syntaxes.iter().any(|syntax| matches_syntax(value, syntax)),
+  }
+}
+
+pub(crate) fn get_function_name(name: &FunctionName) -> String {
+  match name {
+    FunctionName::Ident(ident) => ident.value.to_lowercase(),
+    FunctionName::DashedIdent(ident) => format!("--{}", ident.value),
+  }
+}
+
+fn is_comma(value: &ComponentValue) -> bool {
+  matches!(value, ComponentValue::Delimiter(delimiter) if delimiter.value == DelimiterValue::Comma)
+}
+
+fn parse_component_values(value: &str) -> Option<Vec<ComponentValue>> {
+  let (stylesheet, errors) = swc_parse_css(format!("* {{ x: {} }}", value).as_str());
+
+  if !errors.is_empty() {
+    return None;
+  }
+
+  let Rule::QualifiedRule(rule) = stylesheet.ok()?.rules.pop()? else {
+    return None;
+  };
+
+  match rule.block.value.into_iter().next()? {
+    ComponentValue::Declaration(declaration) => Some(declaration.value),
+    _ => None,
+  }
+}
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod css_tests {
+  use swc_core::common::DUMMY_SP;
+
   use crate::shared::{
     structures::state_manager::StateManager, utils::css::common::transform_value,
   };
@@ -8,7 +10,7 @@ mod css_tests {
   #[should_panic(expected = "Rule contains an unclosed function")]
   fn disallow_unclosed_style_value_functions() {
     assert_eq!(
-      transform_value("color", "var(--foo", &StateManager::default()),
+      transform_value("color", "var(--foo", DUMMY_SP, &StateManager::default()),
       "1px",
     );
   }
@@ -17,7 +19,7 @@ mod css_tests {
   #[should_panic(expected = "Unprefixed custom properties")]
   fn disallow_unprefixed_custom_properties() {
     assert_eq!(
-      transform_value("color", "var(foo)", &StateManager::default()),
+      transform_value("color", "var(foo)", DUMMY_SP, &StateManager::default()),
       "1px",
     );
   }
@@ -25,17 +27,23 @@ mod css_tests {
   #[test]
   fn allow_custom_properties() {
     assert_eq!(
-      transform_value("color", "var(--foo)", &StateManager::default()),
+      transform_value("color", "var(--foo)", DUMMY_SP, &StateManager::default()),
       "var(--foo)",
     );
     assert_eq!(
-      transform_value("backgroundColor", "var(--bar)", &StateManager::default()),
+      transform_value(
+        "backgroundColor",
+        "var(--bar)",
+        DUMMY_SP,
+        &StateManager::default()
+      ),
       "var(--bar)"
     );
     assert_eq!(
       transform_value(
         "transitionProperty",
         "opacity, margin-top",
+        DUMMY_SP,
         &StateManager::default()
       ),
       "opacity,margin-top"
@@ -45,6 +53,7 @@ mod css_tests {
       transform_value(
         "transitionProperty",
         "opacity, marginTop",
+        DUMMY_SP,
         &StateManager::default()
       ),
       "opacity,margin-top"
@@ -54,6 +63,7 @@ mod css_tests {
       transform_value(
         "boxShadow",
         "0px 2px 4px var(--shadow-1)",
+        DUMMY_SP,
         &StateManager::default()
       ),
       "0 2px 4px var(--shadow-1)"
@@ -63,6 +73,7 @@ mod css_tests {
       transform_value(
         "padding",
         "var(--rightpadding, 20px)",
+        DUMMY_SP,
         &StateManager::default()
       ),
       "var(--rightpadding,20px)"
@@ -71,6 +82,7 @@ mod css_tests {
       transform_value(
         "padding",
         "calc((100% - 50px) * 0.5) var(--rightpadding, 20px)",
+        DUMMY_SP,
         &StateManager::default()
       ),
       "calc((100% - 50px) * .5) var(--rightpadding,20px)"
@@ -80,6 +92,7 @@ mod css_tests {
       transform_value(
         "margin",
         "max(0px, (48px - var(--x16dnrjz)) / 2)",
+        DUMMY_SP,
         &StateManager::default()
       ),
       "max(0px,(48px - var(--x16dnrjz)) / 2)"
@@ -89,6 +102,7 @@ mod css_tests {
       transform_value(
         "backgroundColor",
         "var(----__hashed_var__1jqb1tb, revert)",
+        DUMMY_SP,
         &StateManager::default()
       ),
       "var(----__hashed_var__1jqb1tb,revert)"
@@ -98,23 +112,29 @@ mod css_tests {
       transform_value(
         "--__hashed_var__1jqb1tb",
         "var(----__hashed_var__1jqb1tb, revert)",
+        DUMMY_SP,
         &StateManager::default()
       ),
       "var(----__hashed_var__1jqb1tb,revert)"
     );
 
     assert_eq!(
-      transform_value("boxShadow", "1px 1px #000", &StateManager::default()),
+      transform_value(
+        "boxShadow",
+        "1px 1px #000",
+        DUMMY_SP,
+        &StateManager::default()
+      ),
       "1px 1px #000",
     );
 
     assert_eq!(
-      transform_value("quotes", r#""''""#, &StateManager::default()),
+      transform_value("quotes", r#""''""#, DUMMY_SP, &StateManager::default()),
       r#""""#
     );
 
     assert_eq!(
-      transform_value("quotes", r#""'123'""#, &StateManager::default()),
+      transform_value("quotes", r#""'123'""#, DUMMY_SP, &StateManager::default()),
       r#""123""#
     );
 
@@ -122,6 +142,7 @@ mod css_tests {
       transform_value(
         "gridTemplateAreas",
         r#"'"content"'"#,
+        DUMMY_SP,
         &StateManager::default()
       ),
       r#""content""#
@@ -131,6 +152,7 @@ mod css_tests {
       transform_value(
         "gridTemplateAreas",
         r#"'"content" "sidebar"'"#,
+        DUMMY_SP,
         &StateManager::default()
       ),
       r#""content" "sidebar""#
@@ -140,13 +162,19 @@ mod css_tests {
       transform_value(
         "gridTemplateAreas",
         r#"'"content""sidebar"'"#,
+        DUMMY_SP,
         &StateManager::default()
       ),
       r#""content" "sidebar""#
     );
 
     assert_eq!(
-      transform_value("--span-t", r#"translateX(4px)"#, &StateManager::default()),
+      transform_value(
+        "--span-t",
+        r#"translateX(4px)"#,
+        DUMMY_SP,
+        &StateManager::default()
+      ),
       r#"translateX(4px)"#
     );
   }
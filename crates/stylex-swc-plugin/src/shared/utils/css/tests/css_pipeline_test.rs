@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod css_pipeline_tests {
+  use std::rc::Rc;
+
+  use swc_core::{
+    common::{Span, DUMMY_SP},
+    css::{
+      ast::{ComponentValue, DeclarationName, Ident, Rule, Stylesheet},
+      visit::{Fold, FoldWith},
+    },
+  };
+
+  use crate::shared::{
+    structures::stylex_state_options::StyleXStateOptions,
+    utils::css::{
+      common::normalize_css_property_value, normalizers::CssNormalizer, pipeline::CssPipeline,
+      validators::CssValidator,
+    },
+  };
+
+  #[derive(Debug)]
+  struct BannedPropertiesValidator;
+
+  impl CssValidator for BannedPropertiesValidator {
+    fn name(&self) -> &str {
+      "banned-properties"
+    }
+
+    fn validate(
+      &self,
+      _property: &str,
+      ast: &Stylesheet,
+      _span: Span,
+      _options: &StyleXStateOptions,
+    ) {
+      for rule in ast.rules.iter() {
+        let Rule::QualifiedRule(qualified_rule) = rule else {
+          continue;
+        };
+
+        for component_value in qualified_rule.block.value.iter() {
+          if let ComponentValue::Declaration(declaration) = component_value {
+            if let DeclarationName::Ident(ident) = &declaration.name {
+              assert!(ident.value != "float", "Property \"float\" is not allowed");
+            }
+          }
+        }
+      }
+    }
+  }
+
+  #[derive(Debug)]
+  struct ColorAliasNormalizer;
+
+  impl Fold for ColorAliasNormalizer {
+    fn fold_ident(&mut self, ident: Ident) -> Ident {
+      if ident.value == "brand" {
+        return Ident {
+          value: "rebeccapurple".into(),
+          raw: None,
+          ..ident
+        };
+      }
+
+      ident
+    }
+  }
+
+  impl CssNormalizer for ColorAliasNormalizer {
+    fn name(&self) -> &str {
+      "color-alias"
+    }
+
+    fn normalize(
+      &self,
+      _property: &str,
+      ast: Stylesheet,
+      _options: &StyleXStateOptions,
+    ) -> Stylesheet {
+      ast.fold_with(&mut ColorAliasNormalizer)
+    }
+  }
+
+  #[test]
+  fn run_built_in_normalizers_by_default() {
+    let options = StyleXStateOptions::_new();
+
+    assert_eq!(
+      normalize_css_property_value(
+        "margin",
+        "0px",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "0"
+    );
+  }
+
+  #[test]
+  fn disable_built_in_normalizer() {
+    let options = StyleXStateOptions {
+      disabled_css_normalizers: vec!["base".to_string()],
+      ..StyleXStateOptions::_new()
+    };
+
+    assert_eq!(
+      normalize_css_property_value(
+        "margin",
+        "0px",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "0px"
+    );
+  }
+
+  #[test]
+  fn disable_built_in_validator() {
+    let options = StyleXStateOptions {
+      disabled_css_validators: vec!["unprefixed-custom-properties".to_string()],
+      ..StyleXStateOptions::_new()
+    };
+
+    assert_eq!(
+      normalize_css_property_value(
+        "color",
+        "var(foo)",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "var(foo)"
+    );
+  }
+
+  #[test]
+  fn run_custom_normalizer() {
+    let options = StyleXStateOptions {
+      css_normalizers: vec![Rc::new(ColorAliasNormalizer)],
+      ..StyleXStateOptions::_new()
+    };
+
+    assert_eq!(
+      normalize_css_property_value(
+        "color",
+        "brand",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "rebeccapurple"
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "Property \"float\" is not allowed")]
+  fn run_custom_validator() {
+    let options = StyleXStateOptions {
+      css_validators: vec![Rc::new(BannedPropertiesValidator)],
+      ..StyleXStateOptions::_new()
+    };
+
+    normalize_css_property_value(
+      "float",
+      "left",
+      DUMMY_SP,
+      &CssPipeline::new(&options),
+      &options,
+    );
+  }
+
+  #[test]
+  fn disable_custom_validator() {
+    let options = StyleXStateOptions {
+      css_validators: vec![Rc::new(BannedPropertiesValidator)],
+      disabled_css_validators: vec!["banned-properties".to_string()],
+      ..StyleXStateOptions::_new()
+    };
+
+    assert_eq!(
+      normalize_css_property_value(
+        "float",
+        "left",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "left"
+    );
+  }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod css_syntax_tests {
+  use crate::shared::{
+    enums::data_structures::css_syntax::{CSSSyntax, SyntaxMultiplier},
+    utils::css::syntax::matches_css_syntax,
+  };
+
+  fn syntax(value: &str) -> CSSSyntax {
+    CSSSyntax::from(value.to_string())
+  }
+
+  #[test]
+  fn matches_dimensions() {
+    assert!(matches_css_syntax("12px", &CSSSyntax::Length));
+    assert!(matches_css_syntax("0", &CSSSyntax::Length));
+    assert!(matches_css_syntax("50%", &CSSSyntax::LengthPercentage));
+    assert!(matches_css_syntax("10deg", &CSSSyntax::Angle));
+    assert!(matches_css_syntax("150ms", &CSSSyntax::Time));
+    assert!(matches_css_syntax("2dppx", &CSSSyntax::Resolution));
+    assert!(matches_css_syntax("calc(100% - 4px)", &CSSSyntax::Length));
+
+    assert!(!matches_css_syntax("12", &CSSSyntax::Length));
+    assert!(!matches_css_syntax("50%", &CSSSyntax::Length));
+    assert!(!matches_css_syntax("10px", &CSSSyntax::Angle));
+    assert!(!matches_css_syntax("4px 8px", &CSSSyntax::Length));
+  }
+
+  #[test]
+  fn matches_numbers() {
+    assert!(matches_css_syntax("1.5", &CSSSyntax::Number));
+    assert!(matches_css_syntax("3", &CSSSyntax::Integer));
+
+    assert!(!matches_css_syntax("1.5", &CSSSyntax::Integer));
+    assert!(!matches_css_syntax("1px", &CSSSyntax::Number));
+  }
+
+  #[test]
+  fn matches_colors() {
+    assert!(matches_css_syntax("red", &CSSSyntax::Color));
+    assert!(matches_css_syntax("#fff", &CSSSyntax::Color));
+    assert!(matches_css_syntax("rgb(0 0 0 / 50%)", &CSSSyntax::Color));
+    assert!(matches_css_syntax("currentColor", &CSSSyntax::Color));
+    assert!(matches_css_syntax(
+      "color-mix(in srgb, red, blue)",
+      &CSSSyntax::Color
+    ));
+
+    assert!(!matches_css_syntax("12px", &CSSSyntax::Color));
+    assert!(!matches_css_syntax("reddish", &CSSSyntax::Color));
+    assert!(!matches_css_syntax("calc(1px + 2px)", &CSSSyntax::Color));
+  }
+
+  #[test]
+  fn matches_images_and_transforms() {
+    assert!(matches_css_syntax("url(image.png)", &CSSSyntax::Url));
+    assert!(matches_css_syntax(
+      "linear-gradient(red, blue)",
+      &CSSSyntax::Image
+    ));
+    assert!(matches_css_syntax(
+      "rotate(45deg)",
+      &CSSSyntax::TransformFunction
+    ));
+    assert!(matches_css_syntax(
+      "translateX(10px) scale(2)",
+      &CSSSyntax::TransformList
+    ));
+
+    assert!(!matches_css_syntax("red", &CSSSyntax::Image));
+    assert!(!matches_css_syntax(
+      "translateX(10px) scale(2)",
+      &CSSSyntax::TransformFunction
+    ));
+  }
+
+  #[test]
+  fn accepts_runtime_values() {
+    assert!(matches_css_syntax("var(--size)", &CSSSyntax::Length));
+    assert!(matches_css_syntax("inherit", &CSSSyntax::Color));
+  }
+
+  #[test]
+  fn parses_syntax_descriptors() {
+    assert_eq!(syntax("*"), CSSSyntax::Universal);
+    assert_eq!(syntax("<custom-ident>"), CSSSyntax::CustomIdent);
+    assert_eq!(syntax("<length-percentage>"), CSSSyntax::LengthPercentage);
+    assert_eq!(
+      syntax("<length>+"),
+      CSSSyntax::Multiplier(
+        Box::new(CSSSyntax::Length),
+        SyntaxMultiplier::SpaceSeparated
+      )
+    );
+    assert_eq!(
+      syntax("<length> | auto"),
+      CSSSyntax::Union(vec![
+        CSSSyntax::Length,
+        CSSSyntax::Keyword("auto".to_string())
+      ])
+    );
+
+    assert_eq!(
+      syntax("<lengthPercentage>").to_string(),
+      "<length-percentage>"
+    );
+    assert_eq!(syntax("<color>#").to_string(), "<color>#");
+    assert_eq!(
+      syntax("<length>+|<percentage> | none").to_string(),
+      "<length>+ | <percentage> | none"
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = r#"CSSSyntax "<length>++" not found"#)]
+  fn rejects_nested_multipliers() {
+    syntax("<length>++");
+  }
+
+  #[test]
+  fn matches_idents_and_strings() {
+    assert!(matches_css_syntax("main", &CSSSyntax::CustomIdent));
+    assert!(matches_css_syntax("'hello'", &CSSSyntax::String));
+    assert!(matches_css_syntax("anything at all", &CSSSyntax::Universal));
+
+    assert!(!matches_css_syntax("default", &CSSSyntax::CustomIdent));
+    assert!(!matches_css_syntax("12px", &CSSSyntax::CustomIdent));
+    assert!(!matches_css_syntax("hello", &CSSSyntax::String));
+  }
+
+  #[test]
+  fn matches_multipliers_and_unions() {
+    assert!(matches_css_syntax("1px 2px 3px", &syntax("<length>+")));
+    assert!(matches_css_syntax("red, #fff, blue", &syntax("<color>#")));
+    assert!(matches_css_syntax("auto", &syntax("<length> | auto")));
+    assert!(matches_css_syntax("10px", &syntax("<length> | auto")));
+    assert!(matches_css_syntax(
+      "[full-start] 1fr [full-end]",
+      &CSSSyntax::Universal
+    ));
+
+    assert!(!matches_css_syntax("1px, 2px", &syntax("<length>+")));
+    assert!(!matches_css_syntax("red blue", &syntax("<color>#")));
+    assert!(!matches_css_syntax("none", &syntax("<length> | auto")));
+  }
+}
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod common_css_tests {
+  use swc_core::common::DUMMY_SP;
+
   use crate::shared::{
     structures::state_manager::StateManager,
     utils::css::common::{get_number_suffix, transform_value},
@@ -8,11 +10,24 @@ mod common_css_tests {
   #[test]
   fn should_transform_css_property_value() {
     assert_eq!(
-      transform_value("padding", "1", &StateManager::default()),
+      transform_value("padding", "1", DUMMY_SP, &StateManager::default()),
       "1px"
     );
   }
 
+  #[test]
+  fn should_keep_colons_within_values() {
+    assert_eq!(
+      transform_value(
+        "fontFamily",
+        "\"Font: Display\", serif",
+        DUMMY_SP,
+        &StateManager::default()
+      ),
+      "\"Font: Display\",serif"
+    );
+  }
+
   #[test]
   fn should_return_correct_suffix() {
     assert_eq!(get_number_suffix("padding"), "px");
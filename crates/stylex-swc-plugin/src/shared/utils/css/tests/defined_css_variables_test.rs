@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod defined_css_variables_tests {
+  use std::collections::HashMap;
+
+  use swc_core::common::DUMMY_SP;
+
+  use crate::shared::{
+    structures::{
+      state_manager::StateManager,
+      stylex_options::{DefinedCSSVariablesResolution, StyleXOptions},
+    },
+    utils::css::common::transform_value,
+  };
+
+  fn state_with_defined_variables(resolution: DefinedCSSVariablesResolution) -> StateManager {
+    StateManager::new(StyleXOptions {
+      defined_stylex_css_variables: HashMap::from([
+        ("--primary-color".to_string(), "#00f".to_string()),
+        ("spacing-large".to_string(), "24px".to_string()),
+      ]),
+      defined_stylex_css_variables_resolution: resolution,
+      ..StyleXOptions::default()
+    })
+  }
+
+  #[test]
+  fn keep_known_variables_when_validating() {
+    let state = state_with_defined_variables(DefinedCSSVariablesResolution::Validate);
+
+    assert_eq!(
+      transform_value("color", "var(--primary-color)", DUMMY_SP, &state),
+      "var(--primary-color)"
+    );
+    assert_eq!(
+      transform_value("padding", "var(--spacing-large)", DUMMY_SP, &state),
+      "var(--spacing-large)"
+    );
+  }
+
+  #[test]
+  fn keep_unrelated_variables() {
+    let state = state_with_defined_variables(DefinedCSSVariablesResolution::Validate);
+
+    assert_eq!(
+      transform_value("color", "var(--x1xohuxq)", DUMMY_SP, &state),
+      "var(--x1xohuxq)"
+    );
+  }
+
+  #[test]
+  #[should_panic(
+    expected = r#"Unknown CSS variable "--primary-colr". Did you mean "--primary-color"?"#
+  )]
+  fn disallow_misspelled_variables() {
+    let state = state_with_defined_variables(DefinedCSSVariablesResolution::Validate);
+
+    transform_value("color", "var(--primary-colr)", DUMMY_SP, &state);
+  }
+
+  #[test]
+  fn add_fallbacks_for_known_variables() {
+    let state = state_with_defined_variables(DefinedCSSVariablesResolution::Fallback);
+
+    assert_eq!(
+      transform_value("color", "var(--primary-color)", DUMMY_SP, &state),
+      "var(--primary-color,#00f)"
+    );
+    assert_eq!(
+      transform_value("color", "var(--primary-color, red)", DUMMY_SP, &state),
+      "var(--primary-color,red)"
+    );
+  }
+
+  #[test]
+  fn inline_known_variables() {
+    let state = state_with_defined_variables(DefinedCSSVariablesResolution::Inline);
+
+    assert_eq!(
+      transform_value("color", "var(--primary-color)", DUMMY_SP, &state),
+      "#00f"
+    );
+    assert_eq!(
+      transform_value("margin", "var(--spacing-large) 0", DUMMY_SP, &state),
+      "24px 0"
+    );
+  }
+}
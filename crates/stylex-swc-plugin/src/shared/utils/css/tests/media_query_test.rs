@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod media_query_tests {
+  use swc_core::common::DUMMY_SP;
+
+  use crate::shared::{
+    structures::stylex_state_options::StyleXStateOptions,
+    utils::css::media_query::normalize_media_query,
+  };
+
+  fn normalize(query: &str) -> String {
+    normalize_media_query(query, DUMMY_SP, &StyleXStateOptions::_new())
+  }
+
+  fn normalize_legacy(query: &str) -> String {
+    let options = StyleXStateOptions {
+      legacy_media_query_ranges: true,
+      ..StyleXStateOptions::_new()
+    };
+
+    normalize_media_query(query, DUMMY_SP, &options)
+  }
+
+  #[test]
+  fn keeps_canonical_queries() {
+    for query in [
+      "@media (max-width: 700px)",
+      "@media (min-width: 701px) and (max-width: 1120px)",
+      "@media screen and (orientation: landscape)",
+      "@media only print",
+      "@media not all and (monochrome)",
+      "@media (prefers-reduced-motion: reduce)",
+      "@media (hover: hover) or (pointer: fine)",
+      "@media (min-aspect-ratio: 16/9)",
+    ] {
+      assert_eq!(normalize(query), query);
+    }
+  }
+
+  #[test]
+  fn normalizes_whitespace_and_case() {
+    assert_eq!(
+      normalize("@media   SCREEN AND (MIN-WIDTH:768px)and (max-width:1024px)"),
+      "@media screen and (min-width: 768px) and (max-width: 1024px)"
+    );
+    assert_eq!(
+      normalize("@media NOT ALL and (Hover: Hover)"),
+      "@media not all and (hover: Hover)"
+    );
+    assert_eq!(
+      normalize("@media (min-aspect-ratio: 16 / 9) ,print"),
+      "@media (min-aspect-ratio: 16/9), print"
+    );
+  }
+
+  #[test]
+  fn keeps_case_of_values_and_custom_features() {
+    assert_eq!(
+      normalize("@media (--Foo) and (update: Fast)"),
+      "@media (--Foo) and (update: Fast)"
+    );
+    assert_eq!(
+      normalize("@media (WIDTH >= 40EM)"),
+      "@media (width >= 40EM)"
+    );
+  }
+
+  #[test]
+  fn normalizes_order_of_conditions() {
+    assert_eq!(
+      normalize("@media (max-width: 1024px) and (min-width: 768px)"),
+      "@media (min-width: 768px) and (max-width: 1024px)"
+    );
+    assert_eq!(
+      normalize("@media (orientation: portrait) and (hover: hover)"),
+      "@media (hover: hover) and (orientation: portrait)"
+    );
+    assert_eq!(
+      normalize("@media (width <= 1024px) and (width >= 768px)"),
+      "@media (width >= 768px) and (width <= 1024px)"
+    );
+  }
+
+  #[test]
+  fn supports_range_syntax() {
+    assert_eq!(
+      normalize("@media (width>=768px)"),
+      "@media (width >= 768px)"
+    );
+    assert_eq!(
+      normalize("@media (768px <= width)"),
+      "@media (width >= 768px)"
+    );
+    assert_eq!(
+      normalize("@media (1024px > width >= 768px)"),
+      "@media (768px <= width < 1024px)"
+    );
+    assert_eq!(
+      normalize("@media (width >= calc(40em + 1px))"),
+      "@media (width >= calc(40em + 1px))"
+    );
+  }
+
+  #[test]
+  fn supports_nested_conditions() {
+    assert_eq!(
+      normalize("@media not ((pointer: coarse) or (hover: none))"),
+      "@media not ((hover: none) or (pointer: coarse))"
+    );
+    assert_eq!(
+      normalize("@media ((min-width: 768px) and (color)) or (print-color-adjust)"),
+      "@media ((color) and (min-width: 768px)) or (print-color-adjust)"
+    );
+  }
+
+  #[test]
+  fn rewrites_range_syntax_to_legacy_form() {
+    assert_eq!(
+      normalize_legacy("@media (width >= 768px)"),
+      "@media (min-width: 768px)"
+    );
+    assert_eq!(
+      normalize_legacy("@media (768px <= width <= 1024px)"),
+      "@media (min-width: 768px) and (max-width: 1024px)"
+    );
+    assert_eq!(
+      normalize_legacy("@media screen and (height <= 600px) and (orientation: landscape)"),
+      "@media screen and (max-height: 600px) and (orientation: landscape)"
+    );
+    assert_eq!(
+      normalize_legacy("@media (width > 768px)"),
+      "@media (width > 768px)"
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = r#"Invalid media query "@media (min-width: 768px"."#)]
+  fn disallow_unclosed_conditions() {
+    normalize("@media (min-width: 768px");
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid media query")]
+  fn disallow_mixed_and_or() {
+    normalize("@media (color) and (hover: hover) or (pointer: fine)");
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid media query")]
+  fn disallow_missing_values() {
+    normalize("@media (min-width:)");
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid media query")]
+  fn disallow_prefixed_range_features() {
+    normalize("@media (min-width >= 768px)");
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid media query")]
+  fn disallow_conditions_without_and() {
+    normalize("@media screen (color)");
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid media query")]
+  fn disallow_mismatched_range_operators() {
+    normalize("@media (100px < width > 200px)");
+  }
+}
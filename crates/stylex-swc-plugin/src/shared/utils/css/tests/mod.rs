@@ -1,3 +1,9 @@
 pub(crate) mod css_custom_properties_validation_test;
+pub(crate) mod css_pipeline_test;
+pub(crate) mod css_syntax_test;
 pub(crate) mod css_tests;
+pub(crate) mod defined_css_variables_test;
+pub(crate) mod media_query_test;
+pub(crate) mod px_to_rem_test;
 pub(crate) mod split_value_test;
+pub(crate) mod trivial_values_test;
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod convert_px_to_rem_values {
+  use crate::shared::{
+    structures::stylex_options::PxToRemOptions,
+    utils::css::common::{convert_px_to_rem, convert_px_to_rem_in_value},
+  };
+
+  #[test]
+  fn converts_px_with_default_root_font_size() {
+    let options = PxToRemOptions::default();
+
+    assert_eq!(convert_px_to_rem(24.0, &options), 1.5);
+    assert_eq!(convert_px_to_rem(-8.0, &options), -0.5);
+  }
+
+  #[test]
+  fn converts_px_with_custom_root_font_size() {
+    let options = PxToRemOptions {
+      root_font_size: 10.0,
+      ..PxToRemOptions::default()
+    };
+
+    assert_eq!(convert_px_to_rem(24.0, &options), 2.4);
+  }
+
+  #[test]
+  fn rounds_converted_values_to_precision() {
+    let options = PxToRemOptions {
+      precision: Some(3),
+      ..PxToRemOptions::default()
+    };
+
+    assert_eq!(convert_px_to_rem(13.0, &options), 0.813);
+    assert_eq!(convert_px_to_rem(10.0, &options), 0.625);
+  }
+
+  #[test]
+  fn converts_px_values_in_css_values() {
+    let options = PxToRemOptions::default();
+
+    assert_eq!(
+      convert_px_to_rem_in_value("0px 8px -16px 1.5px", &options),
+      "0px 0.5rem -1rem 0.09375rem"
+    );
+    assert_eq!(
+      convert_px_to_rem_in_value("@media (min-width: 768px)", &options),
+      "@media (min-width: 48rem)"
+    );
+    assert_eq!(
+      convert_px_to_rem_in_value("var(--gap-8px) 10em", &options),
+      "var(--gap-8px) 10em"
+    );
+    assert_eq!(
+      convert_px_to_rem_in_value("calc(100% - 32px)", &options),
+      "calc(100% - 2rem)"
+    );
+  }
+
+  #[test]
+  fn keeps_px_in_strings_and_urls() {
+    let options = PxToRemOptions::default();
+
+    assert_eq!(
+      convert_px_to_rem_in_value(r#""16px" 16px"#, &options),
+      r#""16px" 1rem"#
+    );
+    assert_eq!(
+      convert_px_to_rem_in_value("url(icon-16px.png) 16px", &options),
+      "url(icon-16px.png) 1rem"
+    );
+    assert_eq!(
+      convert_px_to_rem_in_value("url('sprite 16px.png')", &options),
+      "url('sprite 16px.png')"
+    );
+  }
+}
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod trivial_values_tests {
+  use std::rc::Rc;
+
+  use swc_core::{
+    common::{Span, DUMMY_SP},
+    css::ast::{ComponentValue, Rule, Stylesheet},
+  };
+
+  use crate::shared::{
+    structures::{state_manager::StateManager, stylex_state_options::StyleXStateOptions},
+    utils::css::{
+      common::{normalize_css_property_value, transform_value},
+      normalizers::CssNormalizer,
+      pipeline::CssPipeline,
+      validators::CssValidator,
+    },
+  };
+
+  #[derive(Debug)]
+  struct IdentityNormalizer;
+
+  impl CssNormalizer for IdentityNormalizer {
+    fn name(&self) -> &str {
+      "identity"
+    }
+
+    fn normalize(
+      &self,
+      _property: &str,
+      ast: Stylesheet,
+      _options: &StyleXStateOptions,
+    ) -> Stylesheet {
+      ast
+    }
+  }
+
+  #[derive(Debug)]
+  struct BannedKeywordValidator;
+
+  impl CssValidator for BannedKeywordValidator {
+    fn name(&self) -> &str {
+      "banned-keyword"
+    }
+
+    fn validate(
+      &self,
+      _property: &str,
+      ast: &Stylesheet,
+      _span: Span,
+      _options: &StyleXStateOptions,
+    ) {
+      for rule in ast.rules.iter() {
+        let Rule::QualifiedRule(qualified_rule) = rule else {
+          continue;
+        };
+
+        for component_value in qualified_rule.block.value.iter() {
+          if let ComponentValue::Declaration(declaration) = component_value {
+            for value in declaration.value.iter() {
+              if let ComponentValue::Ident(ident) = value {
+                assert!(ident.value != "unset", "Keyword \"unset\" is not allowed");
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn trivial_values_match_parsed_values() {
+    let default_options = StyleXStateOptions::_new();
+    let parsed_options = StyleXStateOptions {
+      css_normalizers: vec![Rc::new(IdentityNormalizer)],
+      ..StyleXStateOptions::_new()
+    };
+
+    for (property, value) in [
+      ("zIndex", "0"),
+      ("zIndex", "12"),
+      ("marginTop", "-3px"),
+      ("padding", "10px"),
+      ("width", "50%"),
+      ("fontSize", "2em"),
+      ("height", "100vh"),
+      ("display", "inline-block"),
+      ("color", "red"),
+      ("--gap", "8px"),
+    ] {
+      assert_eq!(
+        normalize_css_property_value(
+          property,
+          value,
+          DUMMY_SP,
+          &CssPipeline::new(&default_options),
+          &default_options
+        ),
+        normalize_css_property_value(
+          property,
+          value,
+          DUMMY_SP,
+          &CssPipeline::new(&parsed_options),
+          &parsed_options
+        ),
+        "{}: {}",
+        property,
+        value
+      );
+    }
+  }
+
+  #[test]
+  fn converts_trivial_px_values_to_rem() {
+    let options = StyleXStateOptions {
+      use_rem_for_font_size: true,
+      ..StyleXStateOptions::_new()
+    };
+
+    assert_eq!(
+      normalize_css_property_value(
+        "fontSize",
+        "24px",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "1.5rem"
+    );
+    assert_eq!(
+      normalize_css_property_value(
+        "padding",
+        "24px",
+        DUMMY_SP,
+        &CssPipeline::new(&options),
+        &options
+      ),
+      "24px"
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "Keyword \"unset\" is not allowed")]
+  fn validates_trivial_values() {
+    let options = StyleXStateOptions {
+      css_validators: vec![Rc::new(BannedKeywordValidator)],
+      ..StyleXStateOptions::_new()
+    };
+
+    normalize_css_property_value(
+      "color",
+      "unset",
+      DUMMY_SP,
+      &CssPipeline::new(&options),
+      &options,
+    );
+  }
+
+  #[test]
+  fn caches_normalized_values() {
+    let state = StateManager::default();
+
+    assert_eq!(transform_value("opacity", "0.5", DUMMY_SP, &state), ".5");
+    assert_eq!(transform_value("opacity", "0.5", DUMMY_SP, &state), ".5");
+    assert_eq!(transform_value("padding", "4", DUMMY_SP, &state), "4px");
+
+    let cache = state.css_value_cache.borrow();
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(
+      cache.get(&("opacity".to_string(), "0.5".to_string())),
+      Some(&".5".to_string())
+    );
+  }
+}
@@ -1 +1,19 @@
+use std::fmt::Debug;
+
+use swc_core::{common::Span, css::ast::Stylesheet};
+
+use crate::shared::structures::stylex_state_options::StyleXStateOptions;
+
+pub(crate) mod property_names;
+pub(crate) mod property_values;
 pub(crate) mod unprefixed_custom_properties;
+
+pub trait CssValidator: Debug {
+  /// Name used to disable the validator with `disabledCssValidators`
+  fn name(&self) -> &str;
+
+  /// Panics with a descriptive message when the declaration is not allowed.
+  /// `property` is the style key as written, custom properties are parsed as `color`.
+  /// `span` points at the style value in the source and is dummy when unknown
+  fn validate(&self, property: &str, ast: &Stylesheet, span: Span, options: &StyleXStateOptions);
+}
@@ -0,0 +1,157 @@
+use convert_case::{Case, Casing};
+use log::warn;
+use phf::phf_set;
+use swc_core::{
+  common::Span,
+  css::ast::{ComponentValue, DeclarationName, Rule, Stylesheet},
+};
+
+use crate::shared::{
+  constants::{
+    long_hand_logical::LONG_HAND_LOGICAL, long_hand_physical::LONG_HAND_PHYSICAL,
+    messages::UNKNOWN_CSS_PROPERTY, shorthands_of_longhands::SHORTHANDS_OF_LONGHANDS,
+    shorthands_of_shorthands::SHORTHANDS_OF_SHORTHANDS,
+  },
+  structures::{stylex_options::PropertyValidationMode, stylex_state_options::StyleXStateOptions},
+  utils::common::{dashify, get_closest_match},
+};
+
+use super::CssValidator;
+
+// Properties that are missing from the priority tables and legacy StyleX aliases
+static ADDITIONAL_PROPERTIES: phf::Set<&'static str> = phf_set! {
+  "all",
+  "appearance",
+  "zoom",
+
+  // Legacy logical aliases
+  "border-end",
+  "border-end-color",
+  "border-end-style",
+  "border-end-width",
+  "border-horizontal",
+  "border-horizontal-color",
+  "border-horizontal-style",
+  "border-horizontal-width",
+  "border-start",
+  "border-start-color",
+  "border-start-style",
+  "border-start-width",
+  "border-vertical",
+  "border-vertical-color",
+  "border-vertical-style",
+  "border-vertical-width",
+  "border-bottom-end-radius",
+  "border-bottom-start-radius",
+  "border-top-end-radius",
+  "border-top-start-radius",
+  "end",
+  "margin-end",
+  "margin-horizontal",
+  "margin-start",
+  "margin-vertical",
+  "padding-end",
+  "padding-horizontal",
+  "padding-start",
+  "padding-vertical",
+  "start",
+};
+
+#[derive(Debug)]
+pub(crate) struct PropertyNamesValidator;
+
+impl CssValidator for PropertyNamesValidator {
+  fn name(&self) -> &str {
+    "property-names"
+  }
+
+  fn validate(&self, _property: &str, ast: &Stylesheet, _span: Span, options: &StyleXStateOptions) {
+    if options.property_validation == PropertyValidationMode::Off {
+      return;
+    }
+
+    for rule in ast.rules.iter() {
+      let Rule::QualifiedRule(qualified_rule) = rule else {
+        continue;
+      };
+
+      for component_value in qualified_rule.block.value.iter() {
+        let ComponentValue::Declaration(declaration) = component_value else {
+          continue;
+        };
+
+        // Custom properties are always allowed
+        let DeclarationName::Ident(ident) = &declaration.name else {
+          continue;
+        };
+
+        validate_property_name(ident.value.as_ref(), options);
+      }
+    }
+  }
+}
+
+static VENDOR_PREFIXES: [&str; 5] = ["-", "webkit-", "moz-", "ms-", "o-"];
+
+pub(crate) fn is_known_property(property: &str) -> bool {
+  let dashed_property = dashify(property);
+
+  // Vendor prefixed properties are not listed in the tables
+  VENDOR_PREFIXES
+    .iter()
+    .any(|prefix| dashed_property.starts_with(prefix))
+    || LONG_HAND_LOGICAL.contains(dashed_property.as_str())
+    || LONG_HAND_PHYSICAL.contains(dashed_property.as_str())
+    || SHORTHANDS_OF_LONGHANDS.contains(dashed_property.as_str())
+    || SHORTHANDS_OF_SHORTHANDS.contains(dashed_property.as_str())
+    || ADDITIONAL_PROPERTIES.contains(dashed_property.as_str())
+}
+
+fn validate_property_name(property: &str, options: &StyleXStateOptions) {
+  if is_known_property(property)
+    || options
+      .allowed_properties
+      .iter()
+      .any(|allowed| dashify(allowed) == dashify(property))
+  {
+    return;
+  }
+
+  let dashed_property = dashify(property);
+
+  let max_distance = (dashed_property.len() / 4).max(2);
+
+  let suggestion = get_closest_match(
+    dashed_property.as_str(),
+    LONG_HAND_LOGICAL
+      .iter()
+      .chain(LONG_HAND_PHYSICAL.iter())
+      .chain(SHORTHANDS_OF_LONGHANDS.iter())
+      .chain(SHORTHANDS_OF_SHORTHANDS.iter())
+      .chain(ADDITIONAL_PROPERTIES.iter())
+      .copied(),
+    max_distance,
+  )
+  .map(|suggestion| {
+    // Suggest the property in the same casing as it was written
+    if property.contains('-') {
+      suggestion
+    } else {
+      suggestion.to_case(Case::Camel)
+    }
+  });
+
+  let message = match suggestion {
+    Some(suggestion) => format!(
+      r#"{} "{}". Did you mean "{}"?"#,
+      UNKNOWN_CSS_PROPERTY, property, suggestion
+    ),
+    None => format!(r#"{} "{}"."#, UNKNOWN_CSS_PROPERTY, property),
+  };
+
+  match options.property_validation {
+    PropertyValidationMode::Error => panic!("{}", message),
+    PropertyValidationMode::Warn => warn!("{}", message),
+    PropertyValidationMode::Off => {}
+  }
+}
@@ -0,0 +1,536 @@
+use log::warn;
+use swc_core::{
+  common::Span,
+  css::{
+    ast::{ComponentValue, DelimiterValue, Rule, Stylesheet},
+    codegen::{
+      writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+      CodeGenerator, CodegenConfig, Emit,
+    },
+  },
+};
+
+use crate::shared::{
+  constants::messages::INVALID_CSS_VALUE,
+  enums::data_structures::css_syntax::CSSSyntax,
+  structures::{stylex_options::PropertyValidationMode, stylex_state_options::StyleXStateOptions},
+  utils::{
+    common::{dashify, panic_with_span},
+    css::syntax::{get_function_name, matches_syntax, GLOBAL_KEYWORDS},
+  },
+};
+
+use super::CssValidator;
+
+// Functions that can resolve to any value at runtime
+static DYNAMIC_FUNCTIONS: [&str; 3] = ["var", "env", "attr"];
+
+static SIZE_KEYWORDS: &[&str] = &[
+  "auto",
+  "min-content",
+  "max-content",
+  "fit-content",
+  "stretch",
+  "-webkit-fill-available",
+  "-moz-available",
+];
+
+static MAX_SIZE_KEYWORDS: &[&str] = &[
+  "none",
+  "min-content",
+  "max-content",
+  "fit-content",
+  "stretch",
+  "-webkit-fill-available",
+  "-moz-available",
+];
+
+static CONTENT_POSITION_KEYWORDS: &[&str] = &[
+  "normal",
+  "stretch",
+  "center",
+  "start",
+  "end",
+  "flex-start",
+  "flex-end",
+  "self-start",
+  "self-end",
+  "left",
+  "right",
+  "baseline",
+  "first",
+  "last",
+  "safe",
+  "unsafe",
+  "space-between",
+  "space-around",
+  "space-evenly",
+  "anchor-center",
+];
+
+static BORDER_STYLE_KEYWORDS: &[&str] = &[
+  "none", "hidden", "dotted", "dashed", "solid", "double", "groove", "ridge", "inset", "outset",
+];
+
+static LENGTH_PERCENTAGE: &[CSSSyntax] = &[CSSSyntax::LengthPercentage];
+
+static LENGTH: &[CSSSyntax] = &[CSSSyntax::Length];
+
+static COLOR: &[CSSSyntax] = &[CSSSyntax::Color];
+
+static TIME: &[CSSSyntax] = &[CSSSyntax::Time];
+
+static NUMBER: &[CSSSyntax] = &[CSSSyntax::Number];
+
+#[derive(Debug)]
+struct ValueGrammar {
+  keywords: &'static [&'static str],
+  syntaxes: &'static [CSSSyntax],
+  max_components: usize,
+  comma_separated: bool,
+}
+
+impl ValueGrammar {
+  const fn new(
+    keywords: &'static [&'static str],
+    syntaxes: &'static [CSSSyntax],
+    max_components: usize,
+  ) -> Self {
+    ValueGrammar {
+      keywords,
+      syntaxes,
+      max_components,
+      comma_separated: false,
+    }
+  }
+
+  const fn list(syntaxes: &'static [CSSSyntax]) -> Self {
+    ValueGrammar {
+      keywords: &[],
+      syntaxes,
+      max_components: 1,
+      comma_separated: true,
+    }
+  }
+
+  fn expected(&self) -> String {
+    self
+      .keywords
+      .iter()
+      .map(|keyword| keyword.to_string())
+      .chain(self.syntaxes.iter().map(|syntax| syntax.to_string()))
+      .collect::<Vec<String>>()
+      .join(", ")
+  }
+}
+
+fn get_value_grammar(property: &str) -> Option<ValueGrammar> {
+  let grammar = match property {
+    "display" => ValueGrammar::new(
+      &[
+        "block",
+        "inline",
+        "inline-block",
+        "flex",
+        "inline-flex",
+        "grid",
+        "inline-grid",
+        "flow",
+        "flow-root",
+        "none",
+        "contents",
+        "table",
+        "inline-table",
+        "table-row",
+        "table-cell",
+        "table-column",
+        "table-column-group",
+        "table-header-group",
+        "table-footer-group",
+        "table-row-group",
+        "table-caption",
+        "list-item",
+        "run-in",
+        "ruby",
+        "ruby-base",
+        "ruby-text",
+        "ruby-base-container",
+        "ruby-text-container",
+        "math",
+        "-webkit-box",
+        "-webkit-inline-box",
+      ],
+      &[],
+      3,
+    ),
+    "position" => ValueGrammar::new(
+      &[
+        "static",
+        "relative",
+        "absolute",
+        "fixed",
+        "sticky",
+        "-webkit-sticky",
+      ],
+      &[],
+      1,
+    ),
+    "visibility" => ValueGrammar::new(&["visible", "hidden", "collapse"], &[], 1),
+    "box-sizing" => ValueGrammar::new(&["content-box", "border-box"], &[], 1),
+    "float" => ValueGrammar::new(
+      &["left", "right", "none", "inline-start", "inline-end"],
+      &[],
+      1,
+    ),
+    "clear" => ValueGrammar::new(
+      &[
+        "none",
+        "left",
+        "right",
+        "both",
+        "inline-start",
+        "inline-end",
+      ],
+      &[],
+      1,
+    ),
+    "overflow" | "overflow-x" | "overflow-y" | "overflow-block" | "overflow-inline" => {
+      ValueGrammar::new(
+        &["visible", "hidden", "clip", "scroll", "auto", "overlay"],
+        &[],
+        if property == "overflow" { 2 } else { 1 },
+      )
+    }
+    "flex-direction" => {
+      ValueGrammar::new(&["row", "row-reverse", "column", "column-reverse"], &[], 1)
+    }
+    "flex-wrap" => ValueGrammar::new(&["nowrap", "wrap", "wrap-reverse"], &[], 1),
+    "align-items" | "align-self" | "align-content" | "justify-content" | "justify-items"
+    | "justify-self" | "place-content" | "place-items" | "place-self" => ValueGrammar::new(
+      match property {
+        "align-self" | "justify-self" | "place-self" | "justify-items" => &[
+          "auto",
+          "normal",
+          "stretch",
+          "center",
+          "start",
+          "end",
+          "flex-start",
+          "flex-end",
+          "self-start",
+          "self-end",
+          "left",
+          "right",
+          "baseline",
+          "first",
+          "last",
+          "safe",
+          "unsafe",
+          "legacy",
+          "anchor-center",
+        ],
+        _ => CONTENT_POSITION_KEYWORDS,
+      },
+      &[],
+      4,
+    ),
+    "text-align" => ValueGrammar::new(
+      &[
+        "start",
+        "end",
+        "left",
+        "right",
+        "center",
+        "justify",
+        "justify-all",
+        "match-parent",
+        "-webkit-center",
+      ],
+      &[],
+      1,
+    ),
+    "text-transform" => ValueGrammar::new(
+      &[
+        "none",
+        "capitalize",
+        "uppercase",
+        "lowercase",
+        "full-width",
+        "full-size-kana",
+      ],
+      &[],
+      2,
+    ),
+    "white-space" => ValueGrammar::new(
+      &[
+        "normal",
+        "nowrap",
+        "pre",
+        "pre-wrap",
+        "pre-line",
+        "break-spaces",
+        "collapse",
+        "preserve",
+        "preserve-breaks",
+        "preserve-spaces",
+        "wrap",
+      ],
+      &[],
+      2,
+    ),
+    "user-select" => ValueGrammar::new(&["auto", "text", "none", "contain", "all"], &[], 1),
+    "object-fit" => ValueGrammar::new(&["fill", "contain", "cover", "none", "scale-down"], &[], 1),
+    "font-style" => ValueGrammar::new(&["normal", "italic", "oblique"], &[CSSSyntax::Angle], 2),
+    "font-weight" => ValueGrammar::new(&["normal", "bold", "bolder", "lighter"], NUMBER, 1),
+    "font-size" => ValueGrammar::new(
+      &[
+        "xx-small",
+        "x-small",
+        "small",
+        "medium",
+        "large",
+        "x-large",
+        "xx-large",
+        "xxx-large",
+        "smaller",
+        "larger",
+        "math",
+      ],
+      LENGTH_PERCENTAGE,
+      1,
+    ),
+    "line-height" => ValueGrammar::new(
+      &["normal"],
+      &[CSSSyntax::Number, CSSSyntax::LengthPercentage],
+      1,
+    ),
+    "opacity" | "fill-opacity" | "stroke-opacity" => {
+      ValueGrammar::new(&[], &[CSSSyntax::Number, CSSSyntax::Percentage], 1)
+    }
+    "z-index" => ValueGrammar::new(&["auto"], &[CSSSyntax::Integer], 1),
+    "order" => ValueGrammar::new(&[], &[CSSSyntax::Integer], 1),
+    "flex-grow" | "flex-shrink" => ValueGrammar::new(&[], NUMBER, 1),
+    "width" | "height" | "min-width" | "min-height" | "inline-size" | "block-size"
+    | "min-inline-size" | "min-block-size" => {
+      ValueGrammar::new(SIZE_KEYWORDS, LENGTH_PERCENTAGE, 1)
+    }
+    "max-width" | "max-height" | "max-inline-size" | "max-block-size" => {
+      ValueGrammar::new(MAX_SIZE_KEYWORDS, LENGTH_PERCENTAGE, 1)
+    }
+    "flex-basis" => ValueGrammar::new(
+      &[
+        "auto",
+        "content",
+        "min-content",
+        "max-content",
+        "fit-content",
+      ],
+      LENGTH_PERCENTAGE,
+      1,
+    ),
+    "margin" | "inset" => ValueGrammar::new(&["auto"], LENGTH_PERCENTAGE, 4),
+    "margin-block" | "margin-inline" | "inset-block" | "inset-inline" => {
+      ValueGrammar::new(&["auto"], LENGTH_PERCENTAGE, 2)
+    }
+    "margin-top"
+    | "margin-right"
+    | "margin-bottom"
+    | "margin-left"
+    | "margin-block-start"
+    | "margin-block-end"
+    | "margin-inline-start"
+    | "margin-inline-end"
+    | "top"
+    | "right"
+    | "bottom"
+    | "left"
+    | "inset-block-start"
+    | "inset-block-end"
+    | "inset-inline-start"
+    | "inset-inline-end" => ValueGrammar::new(&["auto"], LENGTH_PERCENTAGE, 1),
+    "padding" => ValueGrammar::new(&[], LENGTH_PERCENTAGE, 4),
+    "padding-block" | "padding-inline" => ValueGrammar::new(&[], LENGTH_PERCENTAGE, 2),
+    "padding-top"
+    | "padding-right"
+    | "padding-bottom"
+    | "padding-left"
+    | "padding-block-start"
+    | "padding-block-end"
+    | "padding-inline-start"
+    | "padding-inline-end" => ValueGrammar::new(&[], LENGTH_PERCENTAGE, 1),
+    "gap" => ValueGrammar::new(&["normal"], LENGTH_PERCENTAGE, 2),
+    "row-gap" | "column-gap" => ValueGrammar::new(&["normal"], LENGTH_PERCENTAGE, 1),
+    "border-radius" => ValueGrammar::new(&[], LENGTH_PERCENTAGE, 8),
+    "border-top-left-radius"
+    | "border-top-right-radius"
+    | "border-bottom-left-radius"
+    | "border-bottom-right-radius"
+    | "border-start-start-radius"
+    | "border-start-end-radius"
+    | "border-end-start-radius"
+    | "border-end-end-radius" => ValueGrammar::new(&[], LENGTH_PERCENTAGE, 2),
+    "border-style" => ValueGrammar::new(BORDER_STYLE_KEYWORDS, &[], 4),
+    "border-top-style"
+    | "border-right-style"
+    | "border-bottom-style"
+    | "border-left-style"
+    | "outline-style" => ValueGrammar::new(BORDER_STYLE_KEYWORDS, &[], 1),
+    "border-width" => ValueGrammar::new(&["thin", "medium", "thick"], LENGTH, 4),
+    "border-top-width"
+    | "border-right-width"
+    | "border-bottom-width"
+    | "border-left-width"
+    | "outline-width" => ValueGrammar::new(&["thin", "medium", "thick"], LENGTH, 1),
+    "color"
+    | "background-color"
+    | "outline-color"
+    | "text-decoration-color"
+    | "border-top-color"
+    | "border-right-color"
+    | "border-bottom-color"
+    | "border-left-color"
+    | "column-rule-color"
+    | "stop-color"
+    | "flood-color"
+    | "lighting-color" => ValueGrammar::new(&[], COLOR, 1),
+    "border-color" => ValueGrammar::new(&[], COLOR, 4),
+    "caret-color" | "accent-color" => ValueGrammar::new(&["auto"], COLOR, 1),
+    "transition-duration" | "transition-delay" | "animation-duration" | "animation-delay" => {
+      ValueGrammar::list(TIME)
+    }
+    _ => return None,
+  };
+
+  Some(grammar)
+}
+
+#[derive(Debug)]
+pub(crate) struct PropertyValuesValidator;
+
+impl CssValidator for PropertyValuesValidator {
+  fn name(&self) -> &str {
+    "property-values"
+  }
+
+  fn validate(&self, property: &str, ast: &Stylesheet, span: Span, options: &StyleXStateOptions) {
+    if options.value_validation == PropertyValidationMode::Off || property.starts_with("--") {
+      return;
+    }
+
+    let Some(grammar) = get_value_grammar(dashify(property).as_str()) else {
+      return;
+    };
+
+    for rule in ast.rules.iter() {
+      let Rule::QualifiedRule(qualified_rule) = rule else {
+        continue;
+      };
+
+      for component_value in qualified_rule.block.value.iter() {
+        if let ComponentValue::Declaration(declaration) = component_value {
+          validate_property_value(property, &declaration.value, &grammar, span, options);
+        }
+      }
+    }
+  }
+}
+
+fn validate_property_value(
+  property: &str,
+  values: &[ComponentValue],
+  grammar: &ValueGrammar,
+  span: Span,
+  options: &StyleXStateOptions,
+) {
+  if values.iter().any(contains_dynamic_function) {
+    return;
+  }
+
+  if let [ComponentValue::Ident(ident)] = values {
+    if GLOBAL_KEYWORDS.contains(&ident.value.to_lowercase().as_str()) {
+      return;
+    }
+  }
+
+  let mut component_count = 0;
+
+  for (index, value) in values.iter().enumerate() {
+    if let ComponentValue::Delimiter(delimiter) = value {
+      match delimiter.value {
+        DelimiterValue::Comma if grammar.comma_separated => component_count = 0,
+        DelimiterValue::Solidus if property_allows_slash(property) => {}
+        _ => report_invalid_value(property, &values[index], grammar, span, options),
+      }
+
+      continue;
+    }
+
+    component_count += 1;
+
+    if component_count > grammar.max_components || !matches_grammar(value, grammar) {
+      report_invalid_value(property, &values[index], grammar, span, options);
+    }
+  }
+}
+
+fn property_allows_slash(property: &str) -> bool {
+  dashify(property) == "border-radius"
+}
+
+fn matches_grammar(value: &ComponentValue, grammar: &ValueGrammar) -> bool {
+  if let ComponentValue::Ident(ident) = value {
+    if grammar
+      .keywords
+      .contains(&ident.value.to_lowercase().as_str())
+    {
+      return true;
+    }
+  }
+
+  grammar
+    .syntaxes
+    .iter()
+    .any(|syntax| matches_syntax(value, syntax))
+}
+
+fn contains_dynamic_function(value: &ComponentValue) -> bool {
+  match value {
+    ComponentValue::Function(function) => {
+      DYNAMIC_FUNCTIONS.contains(&get_function_name(&function.name).as_str())
+        || function.value.iter().any(contains_dynamic_function)
+    }
+    _ => false,
+  }
+}
+
+fn report_invalid_value(
+  property: &str,
+  value: &ComponentValue,
+  grammar: &ValueGrammar,
+  span: Span,
+  options: &StyleXStateOptions,
+) {
+  let message = format!(
+    "{} \"{}\" for \"{}\". Expected {}.",
+    INVALID_CSS_VALUE,
+    emit_component_value(value),
+    property,
+    grammar.expected()
+  );
+
+  match options.value_validation {
+    PropertyValidationMode::Error => panic_with_span(span, &message),
+    PropertyValidationMode::Warn => warn!("{}", message),
+    PropertyValidationMode::Off => {}
+  }
+}
+
+fn emit_component_value(value: &ComponentValue) -> String {
+  let mut buf = String::new();
+  let writer = BasicCssWriter::new(&mut buf, None, BasicCssWriterConfig::default());
+  let mut codegen = CodeGenerator::new(writer, CodegenConfig { minify: false });
+
+  codegen.emit(value).unwrap();
+
+  buf
+}
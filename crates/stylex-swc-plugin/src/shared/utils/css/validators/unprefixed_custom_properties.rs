@@ -1,11 +1,37 @@
-use swc_core::css::ast::{
-  ComponentValue, Declaration, Function, FunctionName, QualifiedRule, Rule, Stylesheet,
+use swc_core::{
+  common::Span,
+  css::ast::{
+    ComponentValue, Declaration, Function, FunctionName, QualifiedRule, Rule, Stylesheet,
+  },
 };
 
 use crate::shared::constants::messages::UNPREFIXED_CUSTOM_PROPERTIES;
+use crate::shared::structures::stylex_state_options::StyleXStateOptions;
 use crate::shared::utils::css::common::get_value_from_ident;
 #[cfg(test)]
 use crate::shared::utils::css::common::swc_parse_css;
+
+use super::CssValidator;
+
+#[derive(Debug)]
+pub(crate) struct UnprefixedCustomPropertiesValidator;
+
+impl CssValidator for UnprefixedCustomPropertiesValidator {
+  fn name(&self) -> &str {
+    "unprefixed-custom-properties"
+  }
+
+  fn validate(
+    &self,
+    _property: &str,
+    ast: &Stylesheet,
+    _span: Span,
+    _options: &StyleXStateOptions,
+  ) {
+    unprefixed_custom_properties_validator(ast);
+  }
+}
+
 fn process_function(func: &Function) {
   if let FunctionName::Ident(func_name_ident) = &func.name {
     let func_name = get_value_from_ident(func_name_ident);
@@ -34,7 +60,7 @@ fn process_qualified_rule(qualified_rule: &QualifiedRule) {
   }
 }
 
-pub(crate) fn unprefixed_custom_properties_validator(ast: Stylesheet) {
+pub(crate) fn unprefixed_custom_properties_validator(ast: &Stylesheet) {
   for rule in ast.rules.iter() {
     if let Rule::QualifiedRule(qualified_rule) = rule {
       process_qualified_rule(qualified_rule);
@@ -47,5 +73,5 @@ pub(crate) fn unprefixed_custom_properties_validator(ast: Stylesheet) {
 fn disallow_unprefixed_custom_properties() {
   let (result, _) = swc_parse_css("* { color: var(foo); }");
 
-  unprefixed_custom_properties_validator(result.unwrap());
+  unprefixed_custom_properties_validator(&result.unwrap());
 }
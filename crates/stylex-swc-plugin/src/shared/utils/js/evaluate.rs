@@ -22,7 +22,9 @@ use swc_core::{
 use crate::shared::{
   constants::{
     common::{INVALID_METHODS, VALID_CALLEES},
-    messages::{BUILT_IN_FUNCTION, ILLEGAL_PROP_ARRAY_VALUE},
+    messages::{
+      BUILT_IN_FUNCTION, ILLEGAL_PROP_ARRAY_VALUE, OBJECT_ASSIGN_TARGET, TO_FIXED_DIGITS,
+    },
   },
   enums::{
     data_structures::{
@@ -30,9 +32,10 @@ use crate::shared::{
       import_path_resolution::{ImportPathResolution, ImportPathResolutionType},
       value_with_default::ValueWithDefault,
     },
-    js::{ArrayJS, MathJS, ObjectJS, StringJS},
+    js::{ArrayJS, MathJS, NumberJS, ObjectJS, StringJS, TemplateTagJS},
     misc::VarDeclAction,
   },
+  regex::FLOAT_PREFIX_REGEX,
   structures::{
     evaluate_result::EvaluateResult,
     functions::{CallbackType, FunctionConfig, FunctionConfigType, FunctionMap, FunctionType},
@@ -40,6 +43,7 @@ use crate::shared::{
     seen_value::SeenValue,
     state::EvaluationState,
     state_manager::{add_import_expression, StateManager},
+    stylex_options::TemplateTagBehavior,
     theme_ref::ThemeRef,
     types::{FunctionMapIdentifiers, FunctionMapMemberExpression},
   },
@@ -47,16 +51,18 @@ use crate::shared::{
     ast::{
       convertors::{
         big_int_to_expression, binary_expr_to_num, bool_to_expression, expr_to_bool, expr_to_num,
-        expr_to_str, number_to_expression, string_to_expression, transform_shorthand_to_key_values,
+        expr_to_str, ident_to_expression, number_to_expression, string_to_expression,
+        transform_shorthand_to_key_values,
       },
       factories::{array_expression_factory, lit_str_factory, object_expression_factory},
     },
     common::{
       char_code_at, deep_merge_props, get_hash_map_difference, get_hash_map_value_difference,
-      get_import_by_ident, get_key_str, get_string_val_from_lit, get_var_decl_by_ident,
-      get_var_decl_from, normalize_expr, reduce_ident_count, reduce_member_expression_count,
-      remove_duplicates, sort_numbers_factory, sum_hash_map_values,
+      get_import_by_ident, get_import_from, get_key_str, get_string_val_from_lit,
+      get_var_decl_by_ident, get_var_decl_from, normalize_expr, reduce_ident_count,
+      reduce_member_expression_count, remove_duplicates, sort_numbers_factory, sum_hash_map_values,
     },
+    css::common::css_declarations_to_object,
     js::native_functions::{evaluate_filter, evaluate_join, evaluate_map},
   },
 };
@@ -309,17 +315,46 @@ fn _evaluate(
     Expr::Lit(lit_path) => Some(Box::new(EvaluateResultValue::Expr(Box::new(Expr::Lit(
       lit_path.clone(),
     ))))),
-    Expr::Tpl(tpl) => evaluate_quasis(&Expr::Tpl(tpl.clone()), &tpl.quasis, false, state, fns),
-    #[allow(dead_code)]
-    Expr::TaggedTpl(_tagged_tpl) => {
-      unimplemented!("TaggedTpl");
-      // TODO: Uncomment this for implementation of TaggedTpl
-      // evaluate_quasis(
-      //   &Expr::TaggedTpl(_tagged_tpl.clone()),
-      //   &_tagged_tpl.tpl.quasis,
-      //   false,
-      //   state,
-      // )
+    Expr::Tpl(tpl) => evaluate_quasis(
+      &Expr::Tpl(tpl.clone()),
+      &tpl.quasis,
+      &TemplateTagBehavior::Concat,
+      state,
+      fns,
+    ),
+    Expr::TaggedTpl(tagged_tpl) => {
+      let Some(tag) = get_template_tag(&tagged_tpl.tag, state) else {
+        return deopt(path, state);
+      };
+
+      let tagged_tpl_expr = Expr::TaggedTpl(tagged_tpl.clone());
+
+      let behavior = match &tag {
+        TemplateTagJS::Raw => TemplateTagBehavior::Raw,
+        TemplateTagJS::Known(behavior) => behavior.clone(),
+        TemplateTagJS::Css => TemplateTagBehavior::Concat,
+      };
+
+      let result = evaluate_quasis(
+        &tagged_tpl_expr,
+        &tagged_tpl.tpl.quasis,
+        &behavior,
+        state,
+        fns,
+      );
+
+      match tag {
+        TemplateTagJS::Css => {
+          let css_block = result
+            .and_then(|value| value.as_expr().cloned())
+            .and_then(|expr| expr.as_lit().and_then(get_string_val_from_lit))?;
+
+          Some(Box::new(EvaluateResultValue::Expr(Box::new(
+            css_declarations_to_object(css_block.as_str()),
+          ))))
+        }
+        TemplateTagJS::Raw | TemplateTagJS::Known(_) => result,
+      }
     }
     Expr::Cond(cond) => {
       let test_result = match *evaluate_cached(&cond.test, state, fns)
@@ -789,7 +824,20 @@ fn _evaluate(
         if get_binding(callee_expr, &mut state.traversal_state).is_none()
           && is_valid_callee(callee_expr)
         {
-          panic!("{}", BUILT_IN_FUNCTION)
+          let callee_name = get_callee_name(callee_expr);
+
+          func = Some(Box::new(FunctionConfig {
+            fn_ptr: FunctionType::Callback(Box::new(match callee_name.as_str() {
+              "Number" => CallbackType::Number(NumberJS::Constructor),
+              "String" => CallbackType::String(StringJS::Constructor),
+              "parseInt" => CallbackType::Number(NumberJS::ParseInt),
+              "parseFloat" => CallbackType::Number(NumberJS::ParseFloat),
+              _ => panic!("{} - {}", BUILT_IN_FUNCTION, callee_name),
+            })),
+            takes_path: false,
+          }));
+
+          context = Some(Box::new(vec![]));
         } else if let Expr::Ident(ident) = callee_expr.as_ref() {
           let ident_id = ident.to_id();
 
@@ -853,13 +901,17 @@ fn _evaluate(
                           cached_second_arg.map(|arg| *arg),
                         ]))]));
                       }
-                      "round" | "ceil" | "floor" => {
+                      "round" | "ceil" | "floor" | "abs" | "sqrt" | "trunc" | "sign" => {
                         func = Some(Box::new(FunctionConfig {
                           fn_ptr: FunctionType::Callback(Box::new(CallbackType::Math(
                             match method_name.as_ref() {
                               "round" => MathJS::Round,
                               "ceil" => MathJS::Ceil,
                               "floor" => MathJS::Floor,
+                              "abs" => MathJS::Abs,
+                              "sqrt" => MathJS::Sqrt,
+                              "trunc" => MathJS::Trunc,
+                              "sign" => MathJS::Sign,
                               _ => unreachable!("Invalid method: {}", method_name),
                             },
                           ))),
@@ -875,12 +927,13 @@ fn _evaluate(
                         )))]));
                       }
 
-                      "min" | "max" => {
+                      "min" | "max" | "hypot" => {
                         func = Some(Box::new(FunctionConfig {
                           fn_ptr: FunctionType::Callback(Box::new(CallbackType::Math(
                             match method_name.as_ref() {
                               "min" => MathJS::Min,
                               "max" => MathJS::Max,
+                              "hypot" => MathJS::Hypot,
                               _ => unreachable!("Invalid method: {}", method_name),
                             },
                           ))),
@@ -1067,14 +1120,9 @@ fn _evaluate(
                             .as_key_value()
                             .expect("Object.entries requires an object");
 
-                          let value = key_values
-                            .value
-                            .as_lit()
-                            .expect("Object value should be a literal");
-
                           values.push(Some(ExprOrSpread {
                             spread: None,
-                            expr: Box::new(Expr::from(value.clone())),
+                            expr: key_values.value.clone(),
                           }));
                         }
 
@@ -1122,11 +1170,104 @@ fn _evaluate(
 
                         context = Some(Box::new(vec![Some(EvaluateResultValue::Entries(entries))]));
                       }
+                      "assign" => {
+                        let is_empty_target = matches!(
+                          normalize_expr(&mut arg.expr.clone()),
+                          Expr::Object(ObjectLit { props, .. }) if props.is_empty()
+                        );
+
+                        if !is_empty_target {
+                          panic!("{}", OBJECT_ASSIGN_TARGET)
+                        }
+
+                        func = Some(Box::new(FunctionConfig {
+                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Object(
+                            ObjectJS::Assign,
+                          ))),
+                          takes_path: false,
+                        }));
+
+                        let mut props = vec![];
+
+                        for source in args.iter().skip(1) {
+                          if source.spread.is_some() {
+                            return deopt(path, state);
+                          }
+
+                          let object = evaluate_cached(&source.expr, state, fns)
+                            .and_then(|arg| arg.as_expr().cloned())
+                            .and_then(|expr| expr.as_object().cloned())
+                            .expect("Object.assign requires objects as sources");
+
+                          props.extend(object.props);
+                        }
+
+                        context = Some(Box::new(vec![Some(EvaluateResultValue::Expr(Box::new(
+                          object_expression_factory(remove_duplicates(props)),
+                        )))]));
+                      }
+                      _ => {
+                        panic!("{} - {}:{}", BUILT_IN_FUNCTION, callee_name, method_name)
+                      }
+                    }
+                  }
+                  "Array" => {
+                    let Some(first_arg) = call.args.first() else {
+                      panic!("Array.{} requires an argument", method_name)
+                    };
+
+                    if first_arg.spread.is_some() {
+                      return deopt(path, state);
+                    }
+
+                    let cached_first_arg = evaluate_cached(&first_arg.expr, state, fns);
+
+                    match method_name.as_ref() {
+                      "from" => {
+                        func = Some(Box::new(FunctionConfig {
+                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Array(
+                            ArrayJS::From,
+                          ))),
+                          takes_path: false,
+                        }));
+
+                        let map_fn = call
+                          .args
+                          .get(1)
+                          .and_then(|arg| evaluate_cached(&arg.expr, state, fns))
+                          .map(|arg| *arg);
+
+                        context = Some(Box::new(vec![cached_first_arg.map(|arg| *arg), map_fn]));
+                      }
+                      "isArray" => {
+                        func = Some(Box::new(FunctionConfig {
+                          fn_ptr: FunctionType::Callback(Box::new(CallbackType::Array(
+                            ArrayJS::IsArray,
+                          ))),
+                          takes_path: false,
+                        }));
+
+                        context = Some(Box::new(vec![cached_first_arg.map(|arg| *arg)]));
+                      }
                       _ => {
                         panic!("{} - {}:{}", BUILT_IN_FUNCTION, callee_name, method_name)
                       }
                     }
                   }
+                  "Number" => {
+                    func = Some(Box::new(FunctionConfig {
+                      fn_ptr: FunctionType::Callback(Box::new(CallbackType::Number(
+                        match method_name.as_ref() {
+                          "parseInt" => NumberJS::ParseInt,
+                          "parseFloat" => NumberJS::ParseFloat,
+                          _ => panic!("{} - {}:{}", BUILT_IN_FUNCTION, callee_name, method_name),
+                        },
+                      ))),
+                      takes_path: false,
+                    }));
+
+                    context = Some(Box::new(vec![]));
+                  }
                   _ => panic!("{} - {}", BUILT_IN_FUNCTION, callee_name),
                 }
               } else {
@@ -1250,6 +1391,19 @@ fn _evaluate(
 
                       context = Some(Box::new(vec![Some(EvaluateResultValue::Vec(expr))]));
                     }
+                    Expr::Lit(Lit::Num(_)) => {
+                      func = Some(Box::new(FunctionConfig {
+                        fn_ptr: FunctionType::Callback(Box::new(match prop_name.as_str() {
+                          "toFixed" => CallbackType::Number(NumberJS::ToFixed),
+                          _ => panic!("{} - {}", BUILT_IN_FUNCTION, prop_name),
+                        })),
+                        takes_path: false,
+                      }));
+
+                      context = Some(Box::new(vec![Some(EvaluateResultValue::Expr(
+                        expr.clone(),
+                      ))]));
+                    }
                     Expr::Lit(Lit::Str(_)) => {
                       func = Some(Box::new(FunctionConfig {
                         fn_ptr: FunctionType::Callback(Box::new(match prop_name.as_str() {
@@ -1368,44 +1522,46 @@ fn _evaluate(
             FunctionType::StylexTypeFn(func) => {
               let args = evaluate_func_call_args(call, state, fns);
 
-              let mut fn_args = IndexMap::default();
-
-              let expr = args
-                .first()
-                .and_then(|expr| expr.as_expr())
-                .expect("Argument is not an expression");
-
-              match expr {
-                Expr::Object(obj) => {
-                  for prop in obj.props.iter() {
-                    let prop = prop.as_prop().unwrap();
-                    let key_value = prop.as_key_value().unwrap();
-
-                    let key = key_value
-                      .key
-                      .as_ident()
-                      .expect("Key not an ident")
-                      .sym
-                      .to_string();
+              let fn_args = args
+                .iter()
+                .map(|arg| {
+                  let expr = arg.as_expr().expect("Argument is not an expression");
+
+                  match expr {
+                    Expr::Object(obj) => {
+                      let mut value_map = IndexMap::default();
+
+                      for prop in obj.props.iter() {
+                        let prop = prop.as_prop().unwrap();
+                        let key_value = prop.as_key_value().unwrap();
+
+                        let key = key_value
+                          .key
+                          .as_ident()
+                          .expect("Key not an ident")
+                          .sym
+                          .to_string();
+
+                        let value = match key_value.value.as_lit() {
+                          Some(Lit::Bool(value)) => value.value.to_string(),
+                          Some(value) => get_string_val_from_lit(value).unwrap(),
+                          None => panic!("Value not a literal"),
+                        };
 
-                    let value = key_value.value.as_lit().expect("Value not a literal");
+                        value_map.insert(key, ValueWithDefault::String(value));
+                      }
 
-                    fn_args.insert(
-                      key,
-                      ValueWithDefault::String(get_string_val_from_lit(value).unwrap()),
-                    );
+                      ValueWithDefault::Map(value_map)
+                    }
+                    Expr::Lit(lit) => {
+                      ValueWithDefault::String(get_string_val_from_lit(lit).unwrap())
+                    }
+                    _ => ValueWithDefault::Map(IndexMap::default()),
                   }
-                }
-                Expr::Lit(lit) => {
-                  fn_args.insert(
-                    "default".to_string(),
-                    ValueWithDefault::String(get_string_val_from_lit(lit).unwrap()),
-                  );
-                }
-                _ => {}
-              }
+                })
+                .collect::<Vec<ValueWithDefault>>();
 
-              let func_result = (func)(ValueWithDefault::Map(fn_args));
+              let func_result = (func)(fn_args);
 
               let css_type = func_result;
 
@@ -1481,6 +1637,145 @@ fn _evaluate(
 
                   return Some(Box::new(EvaluateResultValue::Expr(values.clone())));
                 }
+                CallbackType::Object(ObjectJS::Assign) => {
+                  let Some(Some(EvaluateResultValue::Expr(object))) = context.first() else {
+                    panic!("Object.assign requires an argument")
+                  };
+
+                  return Some(Box::new(EvaluateResultValue::Expr(object.clone())));
+                }
+                CallbackType::Array(ArrayJS::From) => {
+                  let Some(Some(array_like)) = context.first() else {
+                    panic!("Array.from requires an argument")
+                  };
+
+                  let items = array_like_to_items(array_like, state, fns);
+
+                  let map_fn = context
+                    .get(1)
+                    .and_then(|map_fn| map_fn.as_ref())
+                    .map(|map_fn| {
+                      map_fn
+                        .as_callback()
+                        .expect("Array.from requires a function")
+                    });
+
+                  let elems = items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                      let item = match map_fn {
+                        Some(map_fn) => map_fn(vec![
+                          Some(EvaluateResultValue::Expr(Box::new(item))),
+                          Some(EvaluateResultValue::Expr(Box::new(number_to_expression(
+                            index as f64,
+                          )))),
+                        ]),
+                        None => item,
+                      };
+
+                      Some(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(item),
+                      })
+                    })
+                    .collect();
+
+                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+                    array_expression_factory(elems),
+                  ))));
+                }
+                CallbackType::Array(ArrayJS::IsArray) => {
+                  let is_array = match context.first() {
+                    Some(Some(EvaluateResultValue::Vec(_))) => true,
+                    Some(Some(EvaluateResultValue::Expr(expr))) => expr.is_array(),
+                    _ => false,
+                  };
+
+                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+                    bool_to_expression(is_array),
+                  ))));
+                }
+                CallbackType::Number(NumberJS::Constructor) => {
+                  let args = evaluate_func_call_args(call, state, fns);
+
+                  let result = match args.first().and_then(|arg| arg.as_expr()) {
+                    Some(Expr::Lit(Lit::Str(strng))) => string_to_number(&strng.value),
+                    Some(expr) => expr_to_num(expr, &mut state.traversal_state, fns),
+                    None => 0.0,
+                  };
+
+                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+                    number_to_expression(result),
+                  ))));
+                }
+                CallbackType::Number(NumberJS::ParseInt | NumberJS::ParseFloat) => {
+                  let args = evaluate_func_call_args(call, state, fns);
+
+                  let value = args
+                    .first()
+                    .and_then(|arg| arg.as_expr())
+                    .map(|expr| expr_to_str(expr, &mut state.traversal_state, fns))
+                    .expect("First argument must be a string");
+
+                  let result = match func.as_ref() {
+                    CallbackType::Number(NumberJS::ParseInt) => {
+                      let radix = args
+                        .get(1)
+                        .and_then(|arg| arg.as_expr())
+                        .map(|expr| expr_to_num(expr, &mut state.traversal_state, fns) as u32)
+                        .unwrap_or(10);
+
+                      parse_int(&value, radix)
+                    }
+                    CallbackType::Number(NumberJS::ParseFloat) => parse_float(&value),
+                    _ => unreachable!("Invalid function type"),
+                  };
+
+                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+                    number_to_expression(result),
+                  ))));
+                }
+                CallbackType::Number(NumberJS::ToFixed) => {
+                  let Some(Some(EvaluateResultValue::Expr(num))) = context.first() else {
+                    panic!("Number toFixed requires a number")
+                  };
+
+                  let num = expr_to_num(num, &mut state.traversal_state, fns);
+
+                  let args = evaluate_func_call_args(call, state, fns);
+
+                  let digits = args
+                    .first()
+                    .and_then(|arg| arg.as_expr())
+                    .map(|expr| expr_to_num(expr, &mut state.traversal_state, fns))
+                    .map(|digits| if digits.is_nan() { 0.0 } else { digits.trunc() })
+                    .unwrap_or(0.0);
+
+                  if !(0.0..=100.0).contains(&digits) {
+                    panic!("{}", TO_FIXED_DIGITS)
+                  }
+
+                  let digits = digits as usize;
+
+                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+                    string_to_expression(to_fixed(num, digits).as_str()),
+                  ))));
+                }
+                CallbackType::String(StringJS::Constructor) => {
+                  let args = evaluate_func_call_args(call, state, fns);
+
+                  let result = match args.first().and_then(|arg| arg.as_expr()) {
+                    Some(Expr::Lit(Lit::Bool(boolean))) => boolean.value.to_string(),
+                    Some(Expr::Lit(Lit::Null(_))) => "null".to_string(),
+                    Some(expr) => expr_to_str(expr, &mut state.traversal_state, fns),
+                    None => String::default(),
+                  };
+
+                  return Some(Box::new(EvaluateResultValue::Expr(Box::new(
+                    string_to_expression(result.as_str()),
+                  ))));
+                }
                 CallbackType::Object(ObjectJS::FromEntries) => {
                   let Some(Some(EvaluateResultValue::Entries(entries))) = context.first() else {
                     panic!("Object.fromEntries requires an argument")
@@ -1532,9 +1827,17 @@ fn _evaluate(
                     number_to_expression(result),
                   ))));
                 }
-                CallbackType::Math(MathJS::Round | MathJS::Floor | MathJS::Ceil) => {
+                CallbackType::Math(
+                  MathJS::Round
+                  | MathJS::Floor
+                  | MathJS::Ceil
+                  | MathJS::Abs
+                  | MathJS::Sqrt
+                  | MathJS::Trunc
+                  | MathJS::Sign,
+                ) => {
                   let Some(Some(EvaluateResultValue::Expr(expr))) = context.first() else {
-                    panic!("Math.(round | ceil | floor) requires an argument")
+                    panic!("Math.(round | ceil | floor | abs | sqrt | trunc | sign) requires an argument")
                   };
 
                   let num = expr_to_num(expr.as_ref(), &mut state.traversal_state, fns);
@@ -1543,6 +1846,16 @@ fn _evaluate(
                     CallbackType::Math(MathJS::Round) => num.round(),
                     CallbackType::Math(MathJS::Ceil) => num.ceil(),
                     CallbackType::Math(MathJS::Floor) => num.floor(),
+                    CallbackType::Math(MathJS::Abs) => num.abs(),
+                    CallbackType::Math(MathJS::Sqrt) => num.sqrt(),
+                    CallbackType::Math(MathJS::Trunc) => num.trunc(),
+                    CallbackType::Math(MathJS::Sign) => {
+                      if num == 0.0 || num.is_nan() {
+                        num
+                      } else {
+                        num.signum()
+                      }
+                    }
                     _ => unreachable!("Invalid function type"),
                   };
 
@@ -1550,14 +1863,17 @@ fn _evaluate(
                     number_to_expression(result),
                   ))));
                 }
-                CallbackType::Math(MathJS::Min | MathJS::Max) => {
+                CallbackType::Math(MathJS::Min | MathJS::Max | MathJS::Hypot) => {
                   let Some(Some(EvaluateResultValue::Vec(args))) = context.first() else {
-                    panic!("Math.(min | max) requires an argument")
+                    panic!("Math.(min | max | hypot) requires an argument")
                   };
 
                   let num_args = args_to_numbers(args, state, fns);
 
                   let result = match func.as_ref() {
+                    CallbackType::Math(MathJS::Hypot) => {
+                      Some(num_args.iter().map(|num| num * num).sum::<f64>().sqrt())
+                    }
                     CallbackType::Math(MathJS::Min) => {
                       num_args.iter().cloned().min_by(sort_numbers_factory())
                     }
@@ -1792,6 +2108,208 @@ fn args_to_numbers(
     .collect::<Vec<f64>>()
 }
 
+fn array_like_to_items(
+  array_like: &EvaluateResultValue,
+  state: &mut EvaluationState,
+  fns: &FunctionMap,
+) -> Vec<Expr> {
+  match array_like {
+    EvaluateResultValue::Vec(items) => items
+      .iter()
+      .map(|item| {
+        item
+          .as_ref()
+          .and_then(|item| item.as_expr().cloned())
+          .expect("Array item is not an expression")
+      })
+      .collect(),
+    EvaluateResultValue::Expr(expr) => match expr.as_ref() {
+      Expr::Array(array) => array
+        .elems
+        .iter()
+        .map(|elem| {
+          elem
+            .as_ref()
+            .map(|elem| *elem.expr.clone())
+            .unwrap_or_else(|| ident_to_expression("undefined"))
+        })
+        .collect(),
+      Expr::Lit(Lit::Str(strng)) => strng
+        .value
+        .chars()
+        .map(|chr| string_to_expression(chr.to_string().as_str()))
+        .collect(),
+      Expr::Object(object) => {
+        let length = object
+          .props
+          .iter()
+          .filter_map(|prop| prop.as_prop().and_then(|prop| prop.as_key_value()))
+          .find(|key_value| get_key_str(key_value) == "length")
+          .map(|key_value| expr_to_num(&key_value.value, &mut state.traversal_state, fns))
+          .expect("Array.from requires an array-like object with a length");
+
+        (0..length as usize)
+          .map(|_| ident_to_expression("undefined"))
+          .collect()
+      }
+      _ => panic!("Array.from requires an array-like argument"),
+    },
+    _ => panic!("Array.from requires an array-like argument"),
+  }
+}
+
+/// Converts a string to a number like JS `Number(value)` does
+fn string_to_number(value: &str) -> f64 {
+  let value = value.trim();
+
+  if value.is_empty() {
+    return 0.0;
+  }
+
+  let non_decimal = match value.get(..2) {
+    Some("0x" | "0X") => Some(16),
+    Some("0o" | "0O") => Some(8),
+    Some("0b" | "0B") => Some(2),
+    _ => None,
+  };
+
+  if let Some(radix) = non_decimal {
+    let digits = &value[2..];
+
+    if digits.is_empty() {
+      return f64::NAN;
+    }
+
+    return digits
+      .chars()
+      .try_fold(0.0, |acc, ch| {
+        ch.to_digit(radix)
+          .map(|digit| acc * radix as f64 + digit as f64)
+      })
+      .unwrap_or(f64::NAN);
+  }
+
+  match value {
+    "Infinity" | "+Infinity" => return f64::INFINITY,
+    "-Infinity" => return f64::NEG_INFINITY,
+    _ => {}
+  }
+
+  // Rust also parses `inf`, `nan` and friends, which JS doesn't
+  let is_decimal_literal = value
+    .chars()
+    .all(|ch| ch.is_ascii_digit() || matches!(ch, '.' | 'e' | 'E' | '+' | '-'));
+
+  if !is_decimal_literal {
+    return f64::NAN;
+  }
+
+  value.parse::<f64>().unwrap_or(f64::NAN)
+}
+
+/// Formats a number like JS `Number.prototype.toFixed`, which rounds ties away from zero
+/// based on the exact value of the number
+fn to_fixed(value: f64, digits: usize) -> String {
+  if value.is_nan() {
+    return "NaN".to_string();
+  }
+
+  if value.is_infinite() {
+    return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+  }
+
+  if value.abs() >= 1e21 {
+    return format!("{:e}", value).replace('e', "e+");
+  }
+
+  let sign = if value < 0.0 { "-" } else { "" };
+
+  // A double has at most 1074 fractional digits, so this is its exact decimal expansion
+  let exact = format!("{:.1074}", value.abs());
+
+  let (integer, fraction) = exact.split_once('.').expect("Exact value has a fraction");
+
+  let mut kept = format!("{}{}", integer, &fraction[..digits])
+    .chars()
+    .map(|ch| ch.to_digit(10).expect("Exact value must be decimal"))
+    .collect::<Vec<u32>>();
+
+  if fraction[digits..].starts_with(['5', '6', '7', '8', '9']) {
+    let mut index = kept.len();
+
+    loop {
+      if index == 0 {
+        kept.insert(0, 1);
+        break;
+      }
+
+      index -= 1;
+
+      if kept[index] == 9 {
+        kept[index] = 0;
+      } else {
+        kept[index] += 1;
+        break;
+      }
+    }
+  }
+
+  let kept = kept
+    .iter()
+    .map(|digit| digit.to_string())
+    .collect::<String>();
+
+  let (integer, fraction) = kept.split_at(kept.len() - digits);
+
+  if digits == 0 {
+    format!("{}{}", sign, integer)
+  } else {
+    format!("{}{}.{}", sign, integer, fraction)
+  }
+}
+
+fn parse_int(value: &str, radix: u32) -> f64 {
+  let value = value.trim_start();
+
+  let (sign, digits) = match value.strip_prefix('-') {
+    Some(rest) => (-1.0, rest),
+    None => (1.0, value.strip_prefix('+').unwrap_or(value)),
+  };
+
+  let (radix, digits) = match (radix, digits.get(..2)) {
+    (0 | 16, Some("0x" | "0X")) => (16, &digits[2..]),
+    (0, _) => (10, digits),
+    _ => (radix, digits),
+  };
+
+  if !(2..=36).contains(&radix) {
+    return f64::NAN;
+  }
+
+  let digits = digits
+    .chars()
+    .take_while(|chr| chr.is_digit(radix))
+    .collect::<String>();
+
+  if digits.is_empty() {
+    return f64::NAN;
+  }
+
+  sign
+    * digits.chars().fold(0.0, |acc, chr| {
+      acc * radix as f64 + chr.to_digit(radix).unwrap() as f64
+    })
+}
+
+fn parse_float(value: &str) -> f64 {
+  let value = value.trim_start();
+
+  match FLOAT_PREFIX_REGEX.find(value) {
+    Some(float_match) => float_match.as_str().parse::<f64>().unwrap_or(f64::NAN),
+    None => f64::NAN,
+  }
+}
+
 fn get_binding(callee: &Expr, state: &mut StateManager) -> Option<VarDeclarator> {
   match callee {
     Expr::Ident(ident) => get_var_decl_from(state, ident).cloned(),
@@ -1840,10 +2358,49 @@ fn is_id_prop(prop: &MemberProp) -> Option<Atom> {
   }
 }
 
+fn get_template_tag(tag: &Expr, state: &mut EvaluationState) -> Option<TemplateTagJS> {
+  match tag {
+    Expr::Member(member) => {
+      let obj_ident = member.obj.as_ident()?;
+      let prop_ident = member.prop.as_ident()?;
+
+      if obj_ident.sym == "String"
+        && prop_ident.sym == "raw"
+        && get_var_decl_from(&state.traversal_state, obj_ident).is_none()
+      {
+        Some(TemplateTagJS::Raw)
+      } else {
+        None
+      }
+    }
+    Expr::Ident(ident) => {
+      // Local bindings and imports with the name of a known tag are left to the user code
+      if get_var_decl_from(&state.traversal_state, ident).is_some()
+        || get_import_from(&state.traversal_state, ident).is_some()
+      {
+        return None;
+      }
+
+      let options = &state.traversal_state.options;
+      let tag_name = ident.sym.to_string();
+
+      if options.css_template_tags.contains(&tag_name) {
+        Some(TemplateTagJS::Css)
+      } else {
+        options
+          .known_template_tags
+          .get(&tag_name)
+          .map(|behavior| TemplateTagJS::Known(behavior.clone()))
+      }
+    }
+    _ => None,
+  }
+}
+
 pub(crate) fn evaluate_quasis(
   tpl_expr: &Expr,
   quasis: &[TplElement],
-  raw: bool,
+  behavior: &TemplateTagBehavior,
   state: &mut EvaluationState,
   fns: &FunctionMap,
 ) -> Option<Box<EvaluateResultValue>> {
@@ -1860,16 +2417,11 @@ pub(crate) fn evaluate_quasis(
       return None;
     };
 
-    strng += (if raw {
-      elem.raw.to_string()
-    } else {
-      elem
-        .cooked
-        .clone()
-        .expect("Cooked should be some")
-        .to_string()
-    })
-    .as_str();
+    // Tagged templates with invalid escapes have no cooked value
+    strng += match (behavior, &elem.cooked) {
+      (TemplateTagBehavior::Raw, _) | (_, None) => elem.raw.as_str(),
+      (_, Some(cooked)) => cooked.as_str(),
+    };
 
     let expr = exprs.get(i);
 
@@ -1885,6 +2437,10 @@ pub(crate) fn evaluate_quasis(
 
         if let Some(lit_str) = lit_str {
           strng += &lit_str;
+
+          if let TemplateTagBehavior::Suffix(suffix) = behavior {
+            strng += suffix;
+          }
         }
       }
     }
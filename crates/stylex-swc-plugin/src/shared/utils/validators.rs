@@ -10,9 +10,13 @@ use crate::shared::{
     common::THEME_NAME_KEY,
     messages::{
       DUPLICATE_CONDITIONAL, ILLEGAL_ARGUMENT_LENGTH, ILLEGAL_PROP_ARRAY_VALUE, ILLEGAL_PROP_VALUE,
-      INVALID_PSEUDO_OR_AT_RULE, NON_EXPORT_NAMED_DECLARATION, NON_OBJECT_FOR_STYLEX_CALL,
-      NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL, NON_OBJECT_KEYFRAME, NON_STATIC_KEYFRAME_VALUE,
-      NON_STATIC_VALUE, ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS, ONLY_TOP_LEVEL_INCLUDES,
+      INVALID_KEYFRAMES_AT_RULE, INVALID_POSITION_TRY_PROPERTY, INVALID_POSITION_TRY_VALUE,
+      INVALID_PSEUDO_OR_AT_RULE, INVALID_VIEW_TRANSITION_CLASS_KEY, NON_EXPORT_NAMED_DECLARATION,
+      NON_OBJECT_FOR_STYLEX_CALL, NON_OBJECT_FOR_STYLEX_KEYFRAMES_CALL,
+      NON_OBJECT_FOR_STYLEX_POSITION_TRY_CALL, NON_OBJECT_FOR_STYLEX_VIEW_TRANSITION_CLASS_CALL,
+      NON_OBJECT_KEYFRAME, NON_OBJECT_VIEW_TRANSITION_STYLE, NON_STATIC_KEYFRAME_VALUE,
+      NON_STATIC_POSITION_TRY_VALUE, NON_STATIC_VALUE, NON_STATIC_VIEW_TRANSITION_CLASS_VALUE,
+      ONLY_NAMED_PARAMETERS_IN_DYNAMIC_STYLE_FUNCTIONS, ONLY_TOP_LEVEL_INCLUDES,
       UNBOUND_STYLEX_CALL_VALUE,
     },
   },
@@ -22,12 +26,17 @@ use crate::shared::{
   },
   regex::INCLUDED_IDENT_REGEX,
   structures::state_manager::StateManager,
+  transformers::{
+    stylex_position_try::POSITION_TRY_PROPERTIES,
+    stylex_view_transition_class::VIEW_TRANSITION_PSEUDO_ELEMENTS,
+  },
   utils::{
     ast::{
       convertors::string_to_expression,
       factories::{ident_factory, key_value_factory},
     },
     common::{get_string_val_from_lit, get_var_decl_by_ident_or_member},
+    css::at_rule::validate_at_rule,
   },
 };
 
@@ -98,6 +107,85 @@ pub(crate) fn validate_stylex_keyframes_indent(var_decl: &VarDeclarator, state:
   )
 }
 
+pub(crate) fn validate_stylex_view_transition_class_indent(
+  var_decl: &VarDeclarator,
+  state: &mut StateManager,
+) {
+  let init = match &var_decl.init {
+    Some(init) => init
+      .clone()
+      .call()
+      .expect(NON_STATIC_VIEW_TRANSITION_CLASS_VALUE),
+    None => panic!("{}", NON_STATIC_VIEW_TRANSITION_CLASS_VALUE),
+  };
+
+  if !is_view_transition_class_call(var_decl, state) {
+    return;
+  }
+
+  let ident = ident_factory("viewTransitionClass");
+
+  let expr = Expr::from(init.clone());
+
+  assert!(
+    get_var_decl_by_ident_or_member(state, &ident).is_some()
+      || state
+        .top_level_expressions
+        .iter()
+        .any(|TopLevelExpression(_, call_item, _)| { call_item.eq(&expr) }),
+    "{}",
+    UNBOUND_STYLEX_CALL_VALUE
+  );
+
+  assert!(init.args.len() == 1, "{}", ILLEGAL_ARGUMENT_LENGTH);
+
+  let first_args = &init.args[0];
+
+  assert!(
+    first_args.expr.is_object(),
+    "{}",
+    NON_OBJECT_FOR_STYLEX_VIEW_TRANSITION_CLASS_CALL
+  )
+}
+
+pub(crate) fn validate_stylex_position_try_indent(
+  var_decl: &VarDeclarator,
+  state: &mut StateManager,
+) {
+  let init = match &var_decl.init {
+    Some(init) => init.clone().call().expect(NON_STATIC_POSITION_TRY_VALUE),
+    None => panic!("{}", NON_STATIC_POSITION_TRY_VALUE),
+  };
+
+  if !is_position_try_call(var_decl, state) {
+    return;
+  }
+
+  let ident = ident_factory("positionTry");
+
+  let expr = Expr::from(init.clone());
+
+  assert!(
+    get_var_decl_by_ident_or_member(state, &ident).is_some()
+      || state
+        .top_level_expressions
+        .iter()
+        .any(|TopLevelExpression(_, call_item, _)| { call_item.eq(&expr) }),
+    "{}",
+    UNBOUND_STYLEX_CALL_VALUE
+  );
+
+  assert!(init.args.len() == 1, "{}", ILLEGAL_ARGUMENT_LENGTH);
+
+  let first_args = &init.args[0];
+
+  assert!(
+    first_args.expr.is_object(),
+    "{}",
+    NON_OBJECT_FOR_STYLEX_POSITION_TRY_CALL
+  )
+}
+
 pub(crate) fn validate_stylex_create_theme_indent(
   var_decl: &Option<Box<VarDeclarator>>,
   call: &CallExpr,
@@ -130,7 +218,11 @@ pub(crate) fn validate_stylex_create_theme_indent(
     UNBOUND_STYLEX_CALL_VALUE
   );
 
-  assert!(init.args.len() == 2, "{}", ILLEGAL_ARGUMENT_LENGTH);
+  assert!(
+    init.args.len() == 2 || init.args.len() == 3,
+    "{}",
+    ILLEGAL_ARGUMENT_LENGTH
+  );
 }
 
 pub(crate) fn validate_stylex_define_vars(call: &CallExpr, state: &mut StateManager) {
@@ -188,6 +280,46 @@ pub(crate) fn is_keyframes_call(var_decl: &VarDeclarator, state: &StateManager)
   }
 }
 
+pub(crate) fn is_view_transition_class_call(
+  var_decl: &VarDeclarator,
+  state: &StateManager,
+) -> bool {
+  let init = match &var_decl.init {
+    Some(init) => init.clone().call(),
+    None => None,
+  };
+
+  if let Some(call) = init {
+    is_target_call(
+      (
+        "viewTransitionClass",
+        &state.stylex_view_transition_class_import,
+      ),
+      &call,
+      state,
+    )
+  } else {
+    false
+  }
+}
+
+pub(crate) fn is_position_try_call(var_decl: &VarDeclarator, state: &StateManager) -> bool {
+  let init = match &var_decl.init {
+    Some(init) => init.clone().call(),
+    None => None,
+  };
+
+  if let Some(call) = init {
+    is_target_call(
+      ("positionTry", &state.stylex_position_try_import),
+      &call,
+      state,
+    )
+  } else {
+    false
+  }
+}
+
 pub(crate) fn is_create_theme_call(call: &CallExpr, state: &StateManager) -> bool {
   is_target_call(
     ("createTheme", &state.stylex_create_theme_import),
@@ -283,6 +415,10 @@ pub(crate) fn validate_namespace(namespaces: &[KeyValueProp], conditions: &[Stri
             panic!("{}", DUPLICATE_CONDITIONAL);
           }
 
+          if key.starts_with('@') {
+            validate_at_rule(&key);
+          }
+
           let nested_key_values = get_key_values_from_object(object);
 
           let mut extended_conditions = conditions.to_vec();
@@ -326,6 +462,10 @@ pub(crate) fn validate_conditional_styles(inner_key_value: &KeyValueProp, condit
     panic!("{}", DUPLICATE_CONDITIONAL);
   }
 
+  if inner_key.starts_with('@') {
+    validate_at_rule(&inner_key);
+  }
+
   match inner_value.as_ref() {
     Expr::Lit(_) => {}
     Expr::Array(array) => {
@@ -366,6 +506,15 @@ pub(crate) fn assert_valid_keyframes(obj: &EvaluateResultValue) {
 
         for key_value in key_values.iter() {
           match key_value.value.as_ref() {
+            Expr::Object(frames) if get_key_str(key_value).starts_with('@') => {
+              for frame in get_key_values_from_object(frames).iter() {
+                assert!(
+                  frame.value.is_object() && !get_key_str(frame).starts_with('@'),
+                  "{}",
+                  INVALID_KEYFRAMES_AT_RULE
+                );
+              }
+            }
             Expr::Object(_) => {}
             _ => panic!("{}", NON_OBJECT_KEYFRAME),
           }
@@ -377,6 +526,55 @@ pub(crate) fn assert_valid_keyframes(obj: &EvaluateResultValue) {
   }
 }
 
+pub(crate) fn assert_valid_view_transition_class(obj: &EvaluateResultValue) {
+  let Some(object) = obj.as_expr().and_then(|expr| expr.as_object()) else {
+    panic!("{}", NON_OBJECT_FOR_STYLEX_VIEW_TRANSITION_CLASS_CALL)
+  };
+
+  for key_value in get_key_values_from_object(object).iter() {
+    let key = get_key_str(key_value);
+
+    assert!(
+      VIEW_TRANSITION_PSEUDO_ELEMENTS
+        .iter()
+        .any(|(name, _)| *name == key),
+      "{}",
+      INVALID_VIEW_TRANSITION_CLASS_KEY
+    );
+
+    assert!(
+      key_value.value.is_object(),
+      "{}",
+      NON_OBJECT_VIEW_TRANSITION_STYLE
+    );
+  }
+}
+
+pub(crate) fn assert_valid_position_try(obj: &EvaluateResultValue) {
+  let Some(object) = obj.as_expr().and_then(|expr| expr.as_object()) else {
+    panic!("{}", NON_OBJECT_FOR_STYLEX_POSITION_TRY_CALL)
+  };
+
+  for key_value in get_key_values_from_object(object).iter() {
+    let key = get_key_str(key_value);
+
+    assert!(
+      POSITION_TRY_PROPERTIES.contains(key.as_str()),
+      "{}",
+      INVALID_POSITION_TRY_PROPERTY
+    );
+
+    assert!(
+      matches!(
+        key_value.value.as_lit(),
+        Some(Lit::Str(_)) | Some(Lit::Num(_))
+      ),
+      "{}",
+      INVALID_POSITION_TRY_VALUE
+    );
+  }
+}
+
 pub(crate) fn validate_theme_variables(
   variables: &EvaluateResultValue,
   state: &mut StateManager,
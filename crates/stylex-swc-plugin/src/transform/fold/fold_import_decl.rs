@@ -169,6 +169,18 @@ where
         "types" => {
           self.state.stylex_types_import.insert(local_name_ident_atom);
         }
+        "positionTry" => {
+          self
+            .state
+            .stylex_position_try_import
+            .insert(local_name_ident_atom);
+        }
+        "viewTransitionClass" => {
+          self
+            .state
+            .stylex_view_transition_class_import
+            .insert(local_name_ident_atom);
+        }
         _ => {
           unreachable!("{}", MUST_BE_DEFAULT_IMPORT)
         }
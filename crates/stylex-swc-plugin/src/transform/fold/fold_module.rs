@@ -58,6 +58,30 @@ where
         );
       }
 
+      if !self.state.design_tokens.is_empty() {
+        // Design tokens of the defined variables for design tools and documentation
+        self.comments.add_leading(
+          module.span.lo,
+          Comment {
+            kind: CommentKind::Line,
+            text: format!(
+              "__stylex_design_tokens_start__{}__stylex_design_tokens_end__",
+              serde_json::to_string(
+                &self
+                  .state
+                  .design_tokens
+                  .clone()
+                  .into_iter()
+                  .collect::<serde_json::Map<String, serde_json::Value>>()
+              )
+              .unwrap()
+            )
+            .into(),
+            span: module.span,
+          },
+        );
+      }
+
       if self.state.options.runtime_injection.is_some() {
         self.state.cycle = TransformationCycle::InjectStyles;
         module = module.fold_children_with(self);
@@ -130,6 +130,14 @@ where
     }
   }
 
+  pub(crate) fn new_with_state(comments: C, state: StateManager) -> Self {
+    ModuleTransformVisitor {
+      comments,
+      props_declaration: None,
+      state: Box::new(state),
+    }
+  }
+
   pub(crate) fn process_declaration(&mut self, call_expr: &mut CallExpr) -> Option<(Id, String)> {
     let stylex_imports = self.state.stylex_import_stringified();
     if let Callee::Expr(callee) = &mut call_expr.callee {
@@ -150,6 +158,11 @@ where
               || self.state.stylex_types_import.contains(&ident.sym)
               || self.state.stylex_create_theme_import.contains(&ident.sym)
               || self.state.stylex_define_vars_import.contains(&ident.sym)
+              || self
+                .state
+                .stylex_view_transition_class_import
+                .contains(&ident.sym)
+              || self.state.stylex_position_try_import.contains(&ident.sym)
               || self.state.stylex_attrs_import.contains(&ident.sym))
           {
             increase_ident_count(&mut self.state, ident);
@@ -174,6 +187,11 @@ where
                 || self.state.stylex_create_theme_import.contains(&ident.sym)
                 || self.state.stylex_types_import.contains(&ident.sym)
                 || self.state.stylex_define_vars_import.contains(&ident.sym)
+                || self
+                  .state
+                  .stylex_view_transition_class_import
+                  .contains(&ident.sym)
+                || self.state.stylex_position_try_import.contains(&ident.sym)
                 || self.state.stylex_attrs_import.contains(&ident.sym))
             {
               if let MemberProp::Ident(ident) = &member.prop {
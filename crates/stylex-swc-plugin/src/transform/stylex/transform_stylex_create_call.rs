@@ -24,8 +24,10 @@ use crate::shared::{
 use crate::shared::{
   structures::functions::{FunctionConfig, FunctionMap, FunctionType},
   transformers::{
-    stylex_create::stylex_create_set, stylex_first_that_works::stylex_first_that_works,
-    stylex_include::stylex_include, stylex_keyframes::get_keyframes_fn,
+    stylex_create::stylex_create_set,
+    stylex_first_that_works::stylex_first_that_works,
+    stylex_include::stylex_include,
+    stylex_keyframes::{apply_keyframes_variants, get_keyframes_fn},
   },
 };
 use crate::shared::{
@@ -132,6 +134,8 @@ where
 
       assert!(evaluated_arg.confident, "{}", NON_STATIC_VALUE);
 
+      let value = apply_keyframes_variants(&value, &self.state);
+
       let (mut compiled_styles, injected_styles_sans_keyframes) =
         stylex_create_set(&value, &mut self.state, &function_map);
 
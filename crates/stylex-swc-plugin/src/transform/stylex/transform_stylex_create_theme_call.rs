@@ -3,15 +3,26 @@ use std::collections::HashMap;
 
 use indexmap::IndexMap;
 use swc_core::{
-  common::comments::Comments,
-  ecma::ast::{CallExpr, Expr},
+  common::{comments::Comments, Spanned},
+  ecma::ast::{CallExpr, Expr, KeyValueProp},
 };
 
-use crate::shared::structures::{functions::FunctionMap, types::FunctionMapIdentifiers};
+use crate::shared::enums::data_structures::evaluate_result_value::EvaluateResultValue;
+use crate::shared::structures::{
+  functions::FunctionMap,
+  types::{FunctionMapIdentifiers, ThemeOverrides},
+};
 use crate::shared::{
-  constants::messages::{NON_OBJECT_FOR_STYLEX_CALL, NON_STATIC_VALUE},
+  constants::messages::{
+    BASE_THEME_VARIABLES_MISMATCH, NON_OBJECT_FOR_STYLEX_CALL, NON_STATIC_VALUE,
+    UNRESOLVED_BASE_THEME,
+  },
   utils::{
-    core::js_to_expr::{convert_object_to_ast, NestedStringObject},
+    common::{get_string_val_from_lit, panic_with_span},
+    core::{
+      imported_styles::{get_imported_defined_vars, get_imported_theme_overrides},
+      js_to_expr::{convert_object_to_ast, NestedStringObject},
+    },
     js::evaluate::evaluate,
   },
 };
@@ -29,7 +40,10 @@ use crate::shared::{
   },
 };
 use crate::shared::{
-  transformers::stylex_create_theme::stylex_create_theme,
+  transformers::stylex_create_theme::{
+    merge_theme_overrides, stylex_create_theme, validate_theme_override_keys,
+    validate_theme_overrides,
+  },
   utils::core::dev_class_name::convert_theme_to_test_styles,
 };
 use crate::ModuleTransformVisitor;
@@ -47,7 +61,10 @@ where
       validate_stylex_create_theme_indent(parent_var_decl, call, &mut self.state);
 
       let first_arg = call.args.first();
-      let second_arg = call.args.get(1);
+      let (base_arg, second_arg) = match call.args.len() {
+        3 => (call.args.get(1), call.args.get(2)),
+        _ => (None, call.args.get(1)),
+      };
 
       let first_arg = first_arg.map(|first_arg| match &first_arg.spread {
         Some(_) => unimplemented!("Spread"),
@@ -110,15 +127,23 @@ where
       assert!(evaluated_arg2.confident, "{}", NON_STATIC_VALUE);
 
       let mut variables = match evaluated_arg1.value {
-        Some(value) => {
-          validate_theme_variables(&value, &mut self.state);
-
-          value
-        }
+        Some(value) => value,
         None => {
           panic!("Can only override variables theme created with stylex.defineVars().")
         }
       };
+
+      // Imported variables are only references, the module that defines them
+      // is compiled to know their keys and the syntaxes of its typed variables
+      let defined_vars = match (variables.as_ref(), first_arg.as_ident()) {
+        (EvaluateResultValue::ThemeRef(_), Some(ident)) => {
+          get_imported_defined_vars(ident, &mut self.state).map(EvaluateResultValue::Expr)
+        }
+        _ => None,
+      };
+
+      let theme_name = get_theme_name(&validate_theme_variables(&variables, &mut self.state));
+
       let overrides = match evaluated_arg2.value {
         Some(value) => {
           assert!(
@@ -136,15 +161,47 @@ where
         }
       };
 
+      let base_overrides = base_arg.map(|base_arg| {
+        let (base_theme_name, base_overrides) = self.resolve_base_theme(&base_arg.expr);
+
+        if base_theme_name != theme_name {
+          panic_with_span(base_arg.expr.span(), BASE_THEME_VARIABLES_MISMATCH);
+        }
+
+        base_overrides
+      });
+
+      let merged_overrides = match &base_overrides {
+        Some(base_overrides) => merge_theme_overrides(base_overrides, &overrides),
+        None => *overrides.clone(),
+      };
+
+      validate_theme_override_keys(
+        defined_vars.as_ref().unwrap_or(&variables),
+        &merged_overrides,
+        &second_arg,
+        &get_vars_name(&first_arg),
+        &self.state,
+      );
+
+      validate_theme_overrides(&mut variables, &overrides, &second_arg, &mut self.state);
+
       let (mut overrides_obj, inject_styles) = stylex_create_theme(
         &mut variables,
-        &overrides,
+        &merged_overrides,
         &mut self.state,
         &mut IndexMap::default(),
       );
 
       let (var_name, _) = self.get_call_var_name(call);
 
+      if let (Some(var_name), Some(merged_overrides)) = (&var_name, merged_overrides.as_expr()) {
+        self.state.theme_overrides.insert(
+          var_name.clone(),
+          (theme_name, Box::new(merged_overrides.clone())),
+        );
+      }
+
       if self.state.is_test() {
         overrides_obj =
           convert_theme_to_test_styles(&var_name, &overrides_obj, &self.state.get_filename());
@@ -160,6 +217,8 @@ where
         .state
         .register_styles(call, &inject_styles, &result_ast, &var_name);
 
+      self.state.dedupe_styles_to_inject(&result_ast);
+
       return Some(result_ast);
     } else {
       None
@@ -168,3 +227,52 @@ where
     result
   }
 }
+
+impl<C> ModuleTransformVisitor<C>
+where
+  C: Comments,
+{
+  /// Resolves the base theme of `stylex.createTheme` to the name of its variables and its overrides.
+  /// The base theme is either created in the same module or imported from a theme file.
+  fn resolve_base_theme(&mut self, base: &Expr) -> ThemeOverrides {
+    let theme_overrides = base.as_ident().and_then(|ident| {
+      self
+        .state
+        .theme_overrides
+        .get(ident.sym.as_str())
+        .cloned()
+        .or_else(|| get_imported_theme_overrides(ident, &mut self.state))
+    });
+
+    match theme_overrides {
+      Some(theme_overrides) => theme_overrides,
+      None => panic_with_span(base.span(), UNRESOLVED_BASE_THEME),
+    }
+  }
+}
+
+/// Gets the theme name of the variables, whether they were defined in this module or imported
+fn get_theme_name(theme_name_key_value: &KeyValueProp) -> String {
+  let theme_name = theme_name_key_value
+    .value
+    .as_lit()
+    .and_then(get_string_val_from_lit)
+    .unwrap_or_default();
+
+  theme_name
+    .strip_prefix("var(--")
+    .and_then(|theme_name| theme_name.strip_suffix(')'))
+    .map(|theme_name| theme_name.to_string())
+    .unwrap_or(theme_name)
+}
+
+fn get_vars_name(vars: &Expr) -> String {
+  match vars {
+    Expr::Ident(ident) => ident.sym.to_string(),
+    Expr::Member(member) => match (member.obj.as_ident(), member.prop.as_ident()) {
+      (Some(obj), Some(prop)) => format!("{}.{}", obj.sym, prop.sym),
+      _ => "stylex.defineVars()".to_string(),
+    },
+    _ => "stylex.defineVars()".to_string(),
+  }
+}
@@ -135,6 +135,13 @@ where
       let result_ast =
         convert_object_to_ast(&NestedStringObject::FlatCompiledStylesValues(variables_obj));
 
+      if let Some(var_name) = &var_name {
+        self
+          .state
+          .defined_vars
+          .insert(var_name.clone(), Box::new(result_ast.clone()));
+      }
+
       self
         .state
         .register_styles(call, &injected_styles, &result_ast, &var_name);
@@ -21,7 +21,10 @@ use crate::shared::{
   transformers::stylex_include::stylex_include,
 };
 use crate::shared::{
-  transformers::stylex_keyframes::stylex_keyframes, utils::js::evaluate::evaluate,
+  transformers::stylex_keyframes::{
+    add_keyframes_variants, stylex_keyframes, stylex_keyframes_variants,
+  },
+  utils::js::evaluate::evaluate,
 };
 use crate::ModuleTransformVisitor;
 
@@ -128,10 +131,18 @@ where
 
       let (var_name, _) = &self.get_call_var_name(call);
 
+      let variants = stylex_keyframes_variants(&plain_object, &mut self.state);
+
+      add_keyframes_variants(&animation_name, &variants, &mut self.state);
+
       let mut injected_styles = IndexMap::new();
 
       injected_styles.insert(animation_name.clone(), Box::new(injectable_style));
 
+      for (variant_name, variant_style) in variants.into_values() {
+        injected_styles.insert(variant_name, Box::new(variant_style));
+      }
+
       let result_ast = string_to_expression(animation_name.as_str());
 
       self
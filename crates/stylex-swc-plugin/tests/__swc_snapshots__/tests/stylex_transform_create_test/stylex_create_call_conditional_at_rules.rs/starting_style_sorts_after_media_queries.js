@@ -0,0 +1,7 @@
+//__stylex_metadata_start__[{"class_name":"x1hc1fzr","style":{"rtl":null,"ltr":".x1hc1fzr{opacity:1}"},"priority":3000},{"class_name":"xlx8tg1","style":{"rtl":null,"ltr":"@media (min-width: 768px){.xlx8tg1.xlx8tg1{opacity:.8}}"},"priority":3200},{"class_name":"x4itv7f","style":{"rtl":null,"ltr":"@starting-style{.x4itv7f.x4itv7f{opacity:0}}"},"priority":3400}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1hc1fzr{opacity:1}", 3000);
+_inject2("@media (min-width: 768px){.xlx8tg1.xlx8tg1{opacity:.8}}", 3200);
+_inject2("@starting-style{.x4itv7f.x4itv7f{opacity:0}}", 3400);
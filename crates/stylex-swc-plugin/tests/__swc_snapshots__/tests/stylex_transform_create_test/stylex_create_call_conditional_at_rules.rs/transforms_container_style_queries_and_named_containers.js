@@ -0,0 +1,8 @@
+//__stylex_metadata_start__[{"class_name":"x1mqxbix","style":{"rtl":null,"ltr":".x1mqxbix{color:black}"},"priority":3000},{"class_name":"xs9p6w6","style":{"rtl":null,"ltr":"@container style(--theme: dark){.xs9p6w6.xs9p6w6{color:white}}"},"priority":3300},{"class_name":"xe8ttls","style":{"rtl":null,"ltr":".xe8ttls{padding:8px}"},"priority":1000},{"class_name":"x1uvlsp5","style":{"rtl":null,"ltr":"@container sidebar (min-width: 400px){.x1uvlsp5.x1uvlsp5{padding:16px}}"},"priority":1300}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1mqxbix{color:black}", 3000);
+_inject2("@container style(--theme: dark){.xs9p6w6.xs9p6w6{color:white}}", 3300);
+_inject2(".xe8ttls{padding:8px}", 1000);
+_inject2("@container sidebar (min-width: 400px){.x1uvlsp5.x1uvlsp5{padding:16px}}", 1300);
@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"x1mqxbix","style":{"rtl":null,"ltr":".x1mqxbix{color:black}"},"priority":3000},{"class_name":"x48vq07","style":{"rtl":null,"ltr":"@scope (.card) to (.content){.x48vq07.x48vq07{color:blue}}"},"priority":3250}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1mqxbix{color:black}", 3000);
+_inject2("@scope (.card) to (.content){.x48vq07.x48vq07{color:blue}}", 3250);
@@ -0,0 +1,9 @@
+//__stylex_metadata_start__[{"class_name":"x1hc1fzr","style":{"rtl":null,"ltr":".x1hc1fzr{opacity:1}"},"priority":3000},{"class_name":"x4itv7f","style":{"rtl":null,"ltr":"@starting-style{.x4itv7f.x4itv7f{opacity:0}}"},"priority":3400},{"class_name":"x1c071of","style":{"rtl":null,"ltr":".x1c071of{transform:none}"},"priority":3000},{"class_name":"x1ocxaw1","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: no-preference){.x1ocxaw1.x1ocxaw1{transform:none}}"},"priority":3200},{"class_name":"x18wpiow","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: no-preference){@starting-style{.x18wpiow.x18wpiow.x18wpiow{transform:translateY(8px)}}}"},"priority":3600}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1hc1fzr{opacity:1}", 3000);
+_inject2("@starting-style{.x4itv7f.x4itv7f{opacity:0}}", 3400);
+_inject2(".x1c071of{transform:none}", 3000);
+_inject2("@media (prefers-reduced-motion: no-preference){.x1ocxaw1.x1ocxaw1{transform:none}}", 3200);
+_inject2("@media (prefers-reduced-motion: no-preference){@starting-style{.x18wpiow.x18wpiow.x18wpiow{transform:translateY(8px)}}}", 3600);
@@ -0,0 +1,8 @@
+//__stylex_metadata_start__[{"class_name":"xrkmrrc","style":{"rtl":null,"ltr":".xrkmrrc{background-color:red}"},"priority":3000},{"class_name":"xeieg4p","style":{"rtl":null,"ltr":"@media (min-width: 768px) and (max-width: 1024px){.xeieg4p.xeieg4p{background-color:blue}}"},"priority":3200},{"class_name":"x1mqxbix","style":{"rtl":null,"ltr":".x1mqxbix{color:black}"},"priority":3000},{"class_name":"x1afxwrp","style":{"rtl":null,"ltr":"@media (width >= 768px){.x1afxwrp.x1afxwrp{color:white}}"},"priority":3200}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2("@media (min-width: 768px) and (max-width: 1024px){.xeieg4p.xeieg4p{background-color:blue}}", 3200);
+_inject2(".x1mqxbix{color:black}", 3000);
+_inject2("@media (width >= 768px){.x1afxwrp.x1afxwrp{color:white}}", 3200);
@@ -0,0 +1,7 @@
+//__stylex_metadata_start__[{"class_name":"xrkmrrc","style":{"rtl":null,"ltr":".xrkmrrc{background-color:red}"},"priority":3000},{"class_name":"xeieg4p","style":{"rtl":null,"ltr":"@media (min-width: 768px) and (max-width: 1024px){.xeieg4p.xeieg4p{background-color:blue}}"},"priority":3200},{"class_name":"x173rc1m","style":{"rtl":null,"ltr":"@media (min-width: 1025px){.x173rc1m.x173rc1m{background-color:green}}"},"priority":3200}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2("@media (min-width: 768px) and (max-width: 1024px){.xeieg4p.xeieg4p{background-color:blue}}", 3200);
+_inject2("@media (min-width: 1025px){.x173rc1m.x173rc1m{background-color:green}}", 3200);
@@ -1,5 +1,5 @@
-//__stylex_metadata_start__[{"class_name":"x6yu8oj","style":{"rtl":null,"ltr":".x6yu8oj::placeholder{color:gray}"},"priority":8000}]__stylex_metadata_end__
+//__stylex_metadata_start__[{"class_name":"x6yu8oj","style":{"rtl":null,"ltr":".x6yu8oj::placeholder, .x6yu8oj::-webkit-input-placeholder, .x6yu8oj::-moz-placeholder{color:gray}"},"priority":8000}]__stylex_metadata_end__
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
-_inject2(".x6yu8oj::placeholder{color:gray}", 8000);
+_inject2(".x6yu8oj::placeholder, .x6yu8oj::-webkit-input-placeholder, .x6yu8oj::-moz-placeholder{color:gray}", 8000);
@@ -0,0 +1,8 @@
+//__stylex_metadata_start__[{"class_name":"x91rvt2","style":{"rtl":null,"ltr":".x91rvt2::backdrop, .x91rvt2::-webkit-backdrop{background-color:black}"},"priority":8000},{"class_name":"xbi17ec","style":{"rtl":null,"ltr":".xbi17ec:fullscreen::backdrop, .xbi17ec:fullscreen::-webkit-backdrop, .xbi17ec:-webkit-full-screen::backdrop, .xbi17ec:-webkit-full-screen::-webkit-backdrop, .xbi17ec:-moz-full-screen::backdrop, .xbi17ec:-moz-full-screen::-webkit-backdrop{background-color:gray}"},"priority":8122},{"class_name":"x1en94km","style":{"rtl":null,"ltr":".x1en94km::-webkit-slider-thumb, .x1en94km::-moz-range-thumb, .x1en94km::-ms-thumb{width:16px}"},"priority":9000},{"class_name":"x1wmh1fb","style":{"rtl":null,"ltr":".x1wmh1fb:hover::-webkit-slider-thumb, .x1wmh1fb:hover::-moz-range-thumb, .x1wmh1fb:hover::-ms-thumb{width:20px}"},"priority":9130}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x91rvt2::backdrop, .x91rvt2::-webkit-backdrop{background-color:black}", 8000);
+_inject2(".xbi17ec:fullscreen::backdrop, .xbi17ec:fullscreen::-webkit-backdrop, .xbi17ec:-webkit-full-screen::backdrop, .xbi17ec:-webkit-full-screen::-webkit-backdrop, .xbi17ec:-moz-full-screen::backdrop, .xbi17ec:-moz-full-screen::-webkit-backdrop{background-color:gray}", 8122);
+_inject2(".x1en94km::-webkit-slider-thumb, .x1en94km::-moz-range-thumb, .x1en94km::-ms-thumb{width:16px}", 9000);
+_inject2(".x1wmh1fb:hover::-webkit-slider-thumb, .x1wmh1fb:hover::-moz-range-thumb, .x1wmh1fb:hover::-ms-thumb{width:20px}", 9130);
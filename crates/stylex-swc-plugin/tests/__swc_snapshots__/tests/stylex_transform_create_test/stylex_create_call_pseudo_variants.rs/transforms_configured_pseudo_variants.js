@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"xlkkxqy","style":{"rtl":null,"ltr":".xlkkxqy::file-selector-button, .xlkkxqy::-webkit-file-upload-button{color:blue}"},"priority":8000},{"class_name":"x6yu8oj","style":{"rtl":null,"ltr":".x6yu8oj::placeholder{color:gray}"},"priority":8000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xlkkxqy::file-selector-button, .xlkkxqy::-webkit-file-upload-button{color:blue}", 8000);
+_inject2(".x6yu8oj::placeholder{color:gray}", 8000);
@@ -0,0 +1,7 @@
+//__stylex_metadata_start__[{"class_name":"x1ews2ws","style":{"rtl":null,"ltr":".x1ews2ws::selection, .x1ews2ws::-moz-selection{background-color:yellow}"},"priority":8000},{"class_name":"x15zg5kk","style":{"rtl":null,"ltr":".x15zg5kk::-webkit-slider-runnable-track, .x15zg5kk::-moz-range-track, .x15zg5kk::-ms-track{height:4px}"},"priority":9000},{"class_name":"x1etc8sg","style":{"rtl":null,"ltr":".x1etc8sg::-webkit-scrollbar{width:8px}"},"priority":9000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1ews2ws::selection, .x1ews2ws::-moz-selection{background-color:yellow}", 8000);
+_inject2(".x15zg5kk::-webkit-slider-runnable-track, .x15zg5kk::-moz-range-track, .x15zg5kk::-ms-track{height:4px}", 9000);
+_inject2(".x1etc8sg::-webkit-scrollbar{width:8px}", 9000);
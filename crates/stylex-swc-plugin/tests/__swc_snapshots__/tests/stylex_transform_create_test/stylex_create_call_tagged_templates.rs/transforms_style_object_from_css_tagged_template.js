@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"xrkmrrc","style":{"rtl":null,"ltr":".xrkmrrc{background-color:red}"},"priority":3000},{"class_name":"x1y1aw1k","style":{"rtl":null,"ltr":".x1y1aw1k{padding-top:8px}"},"priority":4000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".x1y1aw1k{padding-top:8px}", 4000);
@@ -0,0 +1,5 @@
+//__stylex_metadata_start__[{"class_name":"x1v4x2nj","style":{"rtl":null,"ltr":".x1v4x2nj{content:\"\\2014\"}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2('.x1v4x2nj{content:"\\2014"}', 3000);
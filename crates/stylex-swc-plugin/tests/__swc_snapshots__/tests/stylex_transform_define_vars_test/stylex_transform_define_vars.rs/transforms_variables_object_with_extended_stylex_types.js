@@ -0,0 +1,21 @@
+//__stylex_metadata_start__[{"class_name":"xx70f5t","style":{"rtl":null,"ltr":"@property --xx70f5t { syntax: \"<custom-ident>\"; inherits: true; initial-value: main }"},"priority":0},{"class_name":"x6t8js7","style":{"rtl":null,"ltr":"@property --x6t8js7 { syntax: \"<string>\"; inherits: true; initial-value: \"Menu\" }"},"priority":0},{"class_name":"x1sdistu","style":{"rtl":null,"ltr":"@property --x1sdistu { syntax: \"*\"; inherits: true; initial-value: [full-start] 1fr [full-end] }"},"priority":0},{"class_name":"xx3qa0d","style":{"rtl":null,"ltr":"@property --xx3qa0d { syntax: \"<length>+\"; inherits: true; initial-value: 4px 8px }"},"priority":0},{"class_name":"xtzqbjg","style":{"rtl":null,"ltr":"@property --xtzqbjg { syntax: \"<color>#\"; inherits: true; initial-value: red, blue }"},"priority":0},{"class_name":"x1fc4rx7","style":{"rtl":null,"ltr":"@property --x1fc4rx7 { syntax: \"<length-percentage> | auto\"; inherits: true; initial-value: auto }"},"priority":0},{"class_name":"x1en9ybc","style":{"rtl":null,"ltr":":root{--xx70f5t:main;--x6t8js7:\"Menu\";--x1sdistu:[full-start] 1fr [full-end];--xx3qa0d:4px 8px;--xtzqbjg:red, blue;--x1fc4rx7:auto;}"},"priority":0},{"class_name":"x1en9ybc-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){:root{--xtzqbjg:white, black;}}"},"priority":0.1}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2('@property --xx70f5t { syntax: "<custom-ident>"; inherits: true; initial-value: main }', 0);
+_inject2('@property --x6t8js7 { syntax: "<string>"; inherits: true; initial-value: "Menu" }', 0);
+_inject2('@property --x1sdistu { syntax: "*"; inherits: true; initial-value: [full-start] 1fr [full-end] }', 0);
+_inject2('@property --xx3qa0d { syntax: "<length>+"; inherits: true; initial-value: 4px 8px }', 0);
+_inject2('@property --xtzqbjg { syntax: "<color>#"; inherits: true; initial-value: red, blue }', 0);
+_inject2('@property --x1fc4rx7 { syntax: "<length-percentage> | auto"; inherits: true; initial-value: auto }', 0);
+_inject2(':root{--xx70f5t:main;--x6t8js7:"Menu";--x1sdistu:[full-start] 1fr [full-end];--xx3qa0d:4px 8px;--xtzqbjg:red, blue;--x1fc4rx7:auto;}', 0);
+_inject2("@media (prefers-color-scheme: dark){:root{--xtzqbjg:white, black;}}", 0.1);
+export const layoutTheme = {
+    areaName: "var(--xx70f5t)",
+    label: "var(--x6t8js7)",
+    gridTemplate: "var(--x1sdistu)",
+    gaps: "var(--xx3qa0d)",
+    shadows: "var(--xtzqbjg)",
+    inset: "var(--x1fc4rx7)",
+    __themeName__: "x1en9ybc"
+};
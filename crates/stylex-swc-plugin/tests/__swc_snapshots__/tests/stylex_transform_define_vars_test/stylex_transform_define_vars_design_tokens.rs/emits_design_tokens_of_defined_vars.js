@@ -0,0 +1,16 @@
+//__stylex_metadata_start__[{"class_name":"xbx9tme","style":{"rtl":null,"ltr":"@property --xbx9tme { syntax: \"<color>\"; inherits: true; initial-value: blue }"},"priority":0},{"class_name":"x11etqoo","style":{"rtl":null,"ltr":"@property --x11etqoo { syntax: \"<length>\"; inherits: true; initial-value: 4px }"},"priority":0},{"class_name":"x1gy6rpn","style":{"rtl":null,"ltr":"@property --x1gy6rpn { syntax: \"<number>\"; inherits: true; initial-value: 0.5 }"},"priority":0},{"class_name":"xir4if5","style":{"rtl":null,"ltr":":root{--xbx9tme:blue;--x25jbin:purple;--x11etqoo:4px;--x1gy6rpn:0.5;--x19rt267:system-ui;--literal:red;}"},"priority":0},{"class_name":"xir4if5-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){:root{--xbx9tme:lightblue;--x25jbin:violet;}}"},"priority":0.1},{"class_name":"xir4if5-1e6ryz3","style":{"rtl":null,"ltr":"@supports (color: oklab(0 0 0)){@media (prefers-color-scheme: dark){:root{--x25jbin:oklab(0.7 -0.3 -0.4);}}}"},"priority":0.2},{"class_name":"x16x6p5c","style":{"rtl":null,"ltr":"@property --x16x6p5c { syntax: \"<time>\"; inherits: true; initial-value: 200ms }"},"priority":0},{"class_name":"x3cpup2","style":{"rtl":null,"ltr":":root{--x16x6p5c:200ms;}"},"priority":0},{"class_name":"x3cpup2-vvb1fc","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: reduce){:root{--x16x6p5c:0s;}}"},"priority":0.1}]__stylex_metadata_end__
+//__stylex_design_tokens_start__{"colors":{"--literal":{"$extensions":{"com.stylexjs":{"variable":"--literal"}},"$value":"red"},"accent":{"$extensions":{"com.stylexjs":{"conditions":{"@media (prefers-color-scheme: dark)":"violet","@media (prefers-color-scheme: dark) @supports (color: oklab(0 0 0))":"oklab(0.7 -0.3 -0.4)"},"variable":"--x25jbin"}},"$value":"purple"},"fontFamily":{"$extensions":{"com.stylexjs":{"variable":"--x19rt267"}},"$value":"system-ui"},"opacity":{"$extensions":{"com.stylexjs":{"syntax":"<number>","variable":"--x1gy6rpn"}},"$type":"number","$value":0.5},"primary":{"$extensions":{"com.stylexjs":{"conditions":{"@media (prefers-color-scheme: dark)":"lightblue"},"syntax":"<color>","variable":"--xbx9tme"}},"$type":"color","$value":"blue"},"radius":{"$extensions":{"com.stylexjs":{"syntax":"<length>","variable":"--x11etqoo"}},"$type":"dimension","$value":"4px"}},"motion":{"duration":{"$extensions":{"com.stylexjs":{"conditions":{"@media (prefers-reduced-motion: reduce)":"0s"},"syntax":"<time>","variable":"--x16x6p5c"}},"$type":"duration","$value":"200ms"}}}__stylex_design_tokens_end__
+import stylex from 'stylex';
+export const colors = {
+    primary: "var(--xbx9tme)",
+    accent: "var(--x25jbin)",
+    radius: "var(--x11etqoo)",
+    opacity: "var(--x1gy6rpn)",
+    fontFamily: "var(--x19rt267)",
+    "--literal": "var(--literal)",
+    __themeName__: "xir4if5"
+};
+export const motion = {
+    duration: "var(--x16x6p5c)",
+    __themeName__: "x3cpup2"
+};
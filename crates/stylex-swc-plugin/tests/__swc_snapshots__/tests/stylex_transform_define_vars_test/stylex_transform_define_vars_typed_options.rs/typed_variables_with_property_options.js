@@ -0,0 +1,16 @@
+//__stylex_metadata_start__[{"class_name":"xjrzwe6","style":{"rtl":null,"ltr":"@property --xjrzwe6 { syntax: \"<color>\"; inherits: false; initial-value: red }"},"priority":0},{"class_name":"x1cazb2m","style":{"rtl":null,"ltr":"@property --x1cazb2m { syntax: \"<length>\"; inherits: true; initial-value: 0px }"},"priority":0},{"class_name":"x1lj7t34","style":{"rtl":null,"ltr":"@property --x1lj7t34 { syntax: \"<length>+\"; inherits: false; initial-value: 0px }"},"priority":0},{"class_name":"xm1nzai","style":{"rtl":null,"ltr":":root{--xjrzwe6:red;--x1cazb2m:4px;--x1lj7t34:4px 8px;--x1b2cdi1:45deg;}"},"priority":0},{"class_name":"xm1nzai-4gmocg","style":{"rtl":null,"ltr":"@media (min-width: 800px){:root{--x1cazb2m:8px;}}"},"priority":0.1}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2('@property --xjrzwe6 { syntax: "<color>"; inherits: false; initial-value: red }', 0);
+_inject2('@property --x1cazb2m { syntax: "<length>"; inherits: true; initial-value: 0px }', 0);
+_inject2('@property --x1lj7t34 { syntax: "<length>+"; inherits: false; initial-value: 0px }', 0);
+_inject2(":root{--xjrzwe6:red;--x1cazb2m:4px;--x1lj7t34:4px 8px;--x1b2cdi1:45deg;}", 0);
+_inject2("@media (min-width: 800px){:root{--x1cazb2m:8px;}}", 0.1);
+export const vars = {
+    color: "var(--xjrzwe6)",
+    radius: "var(--x1cazb2m)",
+    gaps: "var(--x1lj7t34)",
+    rotation: "var(--x1b2cdi1)",
+    __themeName__: "xm1nzai"
+};
@@ -0,0 +1,19 @@
+//__stylex_metadata_start__[{"class_name":"xtsg0op","style":{"rtl":null,"ltr":".xtsg0op, .xtsg0op:root{--xgck17p:ivory;}"},"priority":0.5},{"class_name":"xhmkfym","style":{"rtl":null,"ltr":".xhmkfym, .xhmkfym:root{--xgck17p:ivory;--xpegid5:navy;}"},"priority":0.5},{"class_name":"xhmkfym","style":{"rtl":null,"ltr":".xhmkfym, .xhmkfym:root{--xgck17p:ivory;--xpegid5:navy;}"},"priority":0.5}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xtsg0op, .xtsg0op:root{--xgck17p:ivory;}", 0.5);
+export const brand = {
+    $$css: true,
+    x568ih9: "xtsg0op"
+};
+_inject2(".xhmkfym, .xhmkfym:root{--xgck17p:ivory;--xpegid5:navy;}", 0.5);
+export const derived = {
+    $$css: true,
+    x568ih9: "xhmkfym"
+};
+_inject2(".xhmkfym, .xhmkfym:root{--xgck17p:ivory;--xpegid5:navy;}", 0.5);
+export const flat = {
+    $$css: true,
+    x568ih9: "xhmkfym"
+};
@@ -0,0 +1,21 @@
+//__stylex_metadata_start__[{"class_name":"xhmkfym","style":{"rtl":null,"ltr":".xhmkfym, .xhmkfym:root{--xgck17p:ivory;--xpegid5:navy;}"},"priority":0.5},{"class_name":"x1i38dq8","style":{"rtl":null,"ltr":".x1i38dq8, .x1i38dq8:root{--xgck17p:black;--xpegid5:navy;}"},"priority":0.5},{"class_name":"x1i38dq8-1rxft74","style":{"rtl":null,"ltr":"@media (prefers-contrast: more){.x1i38dq8, .x1i38dq8:root{--xgck17p:darkslategray;}}"},"priority":0.6},{"class_name":"x1ho306z","style":{"rtl":null,"ltr":".x1ho306z, .x1ho306z:root{--xgck17p:black;--xpegid5:white;}"},"priority":0.5},{"class_name":"x1ho306z-1rxft74","style":{"rtl":null,"ltr":"@media (prefers-contrast: more){.x1ho306z, .x1ho306z:root{--xgck17p:darkslategray;}}"},"priority":0.6}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xhmkfym, .xhmkfym:root{--xgck17p:ivory;--xpegid5:navy;}", 0.5);
+export const brand = {
+    $$css: true,
+    x568ih9: "xhmkfym"
+};
+_inject2(".x1i38dq8, .x1i38dq8:root{--xgck17p:black;--xpegid5:navy;}", 0.5);
+_inject2("@media (prefers-contrast: more){.x1i38dq8, .x1i38dq8:root{--xgck17p:darkslategray;}}", 0.6);
+export const brandDark = {
+    $$css: true,
+    x568ih9: "x1i38dq8"
+};
+_inject2(".x1ho306z, .x1ho306z:root{--xgck17p:black;--xpegid5:white;}", 0.5);
+_inject2("@media (prefers-contrast: more){.x1ho306z, .x1ho306z:root{--xgck17p:darkslategray;}}", 0.6);
+export const brandDarker = {
+    $$css: true,
+    x568ih9: "x1ho306z"
+};
@@ -0,0 +1,12 @@
+//__stylex_metadata_start__[{"class_name":"xliw9hf","style":{"rtl":null,"ltr":".xliw9hf, .xliw9hf:root{--x187s0dy:orange;--x1n3lyq4:black;--x1d68rua:white;}"},"priority":0.5},{"class_name":"xliw9hf-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){.xliw9hf, .xliw9hf:root{--x187s0dy:gold;}}"},"priority":0.6}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import "tokens.stylex";
+import stylex from 'stylex';
+import { colors, brand } from 'tokens.stylex';
+_inject2(".xliw9hf, .xliw9hf:root{--x187s0dy:orange;--x1n3lyq4:black;--x1d68rua:white;}", 0.5);
+_inject2("@media (prefers-color-scheme: dark){.xliw9hf, .xliw9hf:root{--x187s0dy:gold;}}", 0.6);
+export const brandDark = {
+    $$css: true,
+    "var(--xr4ttzw)": "xliw9hf"
+};
@@ -0,0 +1,24 @@
+//__stylex_metadata_start__[{"class_name":"xrngkwq-B","style":{"rtl":null,"ltr":"@keyframes xrngkwq-B{from{opacity:0;transform:translateY(8px);}to{opacity:1;transform:none;}}"},"priority":1},{"class_name":"xptagi4-B","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: reduce){@keyframes xptagi4-B{from{opacity:0;}to{opacity:1;}}}"},"priority":1},{"class_name":"x1wc8ddo-B","style":{"rtl":null,"ltr":"@keyframes x1wc8ddo-B{from{transform:rotate(0deg);}to{transform:rotate(360deg);}}"},"priority":1},{"class_name":"xv8cai","style":{"rtl":null,"ltr":".xv8cai{animation-name:xrngkwq-B}"},"priority":3000},{"class_name":"x7nle7f","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: reduce){.x7nle7f.x7nle7f{animation-name:xptagi4-B}}"},"priority":3200},{"class_name":"x1q3qbx4","style":{"rtl":null,"ltr":".x1q3qbx4{animation-duration:1s}"},"priority":3000},{"class_name":"x1aerksh","style":{"rtl":null,"ltr":".x1aerksh{animation-name:x1wc8ddo-B}"},"priority":3000},{"class_name":"x1sh5mn2","style":{"rtl":null,"ltr":".x1sh5mn2:hover{animation-name:xrngkwq-B,x1wc8ddo-B}"},"priority":3130},{"class_name":"x1jv8hua","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: reduce){.x1jv8hua.x1jv8hua:hover{animation-name:xptagi4-B,x1wc8ddo-B}}"},"priority":3330}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes xrngkwq-B{from{opacity:0;transform:translateY(8px);}to{opacity:1;transform:none;}}", 1);
+_inject2("@media (prefers-reduced-motion: reduce){@keyframes xptagi4-B{from{opacity:0;}to{opacity:1;}}}", 1);
+_inject2("@keyframes x1wc8ddo-B{from{transform:rotate(0deg);}to{transform:rotate(360deg);}}", 1);
+_inject2(".xv8cai{animation-name:xrngkwq-B}", 3000);
+_inject2("@media (prefers-reduced-motion: reduce){.x7nle7f.x7nle7f{animation-name:xptagi4-B}}", 3200);
+_inject2(".x1q3qbx4{animation-duration:1s}", 3000);
+_inject2(".x1aerksh{animation-name:x1wc8ddo-B}", 3000);
+_inject2(".x1sh5mn2:hover{animation-name:xrngkwq-B,x1wc8ddo-B}", 3130);
+_inject2("@media (prefers-reduced-motion: reduce){.x1jv8hua.x1jv8hua:hover{animation-name:xptagi4-B,x1wc8ddo-B}}", 3330);
+export const styles = {
+    root: {
+        animationName: "xv8cai x7nle7f",
+        animationDuration: "x1q3qbx4",
+        $$css: true
+    },
+    hovered: {
+        animationName: "x1aerksh x1sh5mn2 x1jv8hua",
+        $$css: true
+    }
+};
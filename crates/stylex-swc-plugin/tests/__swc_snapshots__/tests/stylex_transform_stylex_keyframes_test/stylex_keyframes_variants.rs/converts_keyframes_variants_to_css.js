@@ -0,0 +1,7 @@
+//__stylex_metadata_start__[{"class_name":"xrngkwq-B","style":{"rtl":null,"ltr":"@keyframes xrngkwq-B{from{opacity:0;transform:translateY(8px);}to{opacity:1;transform:none;}}"},"priority":1},{"class_name":"xptagi4-B","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: reduce){@keyframes xptagi4-B{from{opacity:0;}to{opacity:1;}}}"},"priority":1}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes xrngkwq-B{from{opacity:0;transform:translateY(8px);}to{opacity:1;transform:none;}}", 1);
+_inject2("@media (prefers-reduced-motion: reduce){@keyframes xptagi4-B{from{opacity:0;}to{opacity:1;}}}", 1);
+export const fadeIn = "xrngkwq-B";
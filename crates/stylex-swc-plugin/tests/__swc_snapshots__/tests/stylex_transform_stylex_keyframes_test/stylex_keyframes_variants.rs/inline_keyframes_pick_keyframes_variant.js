@@ -0,0 +1,14 @@
+//__stylex_metadata_start__[{"class_name":"xxe9hqe-B","style":{"rtl":null,"ltr":"@keyframes xxe9hqe-B{from{transform:scale(0);}to{transform:scale(1);}}"},"priority":1},{"class_name":"xptagi4-B","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: reduce){@keyframes xptagi4-B{from{opacity:0;}to{opacity:1;}}}"},"priority":1},{"class_name":"x12ivgkk","style":{"rtl":null,"ltr":".x12ivgkk{animation-name:xxe9hqe-B}"},"priority":3000},{"class_name":"x7nle7f","style":{"rtl":null,"ltr":"@media (prefers-reduced-motion: reduce){.x7nle7f.x7nle7f{animation-name:xptagi4-B}}"},"priority":3200}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes xxe9hqe-B{from{transform:scale(0);}to{transform:scale(1);}}", 1);
+_inject2("@media (prefers-reduced-motion: reduce){@keyframes xptagi4-B{from{opacity:0;}to{opacity:1;}}}", 1);
+_inject2(".x12ivgkk{animation-name:xxe9hqe-B}", 3000);
+_inject2("@media (prefers-reduced-motion: reduce){.x7nle7f.x7nle7f{animation-name:xptagi4-B}}", 3200);
+export const styles = {
+    root: {
+        animationName: "x12ivgkk x7nle7f",
+        $$css: true
+    }
+};
@@ -0,0 +1,14 @@
+//__stylex_metadata_start__[{"class_name":"--xcz608a","style":{"rtl":null,"ltr":"@position-try --xcz608a{position-area:top;margin-bottom:4px;}"},"priority":0},{"class_name":"x13xdwq6","style":{"rtl":null,"ltr":".x13xdwq6{position-anchor:--anchor}"},"priority":3000},{"class_name":"x1495zv5","style":{"rtl":null,"ltr":".x1495zv5{position-try-fallbacks:--xcz608a}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import { positionTry, create } from 'stylex';
+_inject2("@position-try --xcz608a{position-area:top;margin-bottom:4px;}", 0);
+_inject2(".x13xdwq6{position-anchor:--anchor}", 3000);
+_inject2(".x1495zv5{position-try-fallbacks:--xcz608a}", 3000);
+export const styles = {
+    popover: {
+        positionAnchor: "x13xdwq6",
+        positionTryFallbacks: "x1495zv5",
+        $$css: true
+    }
+};
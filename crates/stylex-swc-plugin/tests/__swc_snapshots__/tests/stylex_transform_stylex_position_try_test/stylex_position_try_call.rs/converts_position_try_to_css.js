@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"--xqbaekl","style":{"rtl":null,"ltr":"@position-try --xqbaekl{position-anchor:--anchor;top:anchor(bottom);inset-inline-start:anchor(start);width:200px;}"},"priority":0}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@position-try --xqbaekl{position-anchor:--anchor;top:anchor(bottom);inset-inline-start:anchor(start);width:200px;}", 0);
+export const bottomFallback = "--xqbaekl";
@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"--x8pfjre","style":{"rtl":"@position-try --x8pfjre{right:anchor(end);margin-right:8px;}","ltr":"@position-try --x8pfjre{left:anchor(end);margin-left:8px;}"},"priority":0}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@position-try --x8pfjre{left:anchor(end);margin-left:8px;}", 0, "@position-try --x8pfjre{right:anchor(end);margin-right:8px;}");
+export const startFallback = "--x8pfjre";
@@ -0,0 +1,3 @@
+import stylex from '@stylexjs/stylex';
+import { sharedStyles } from './shared_styles';
+stylex.props(sharedStyles.base);
@@ -0,0 +1,3 @@
+import stylex from '@stylexjs/stylex';
+import { sharedStyles } from './missing_styles';
+stylex.props(sharedStyles.base);
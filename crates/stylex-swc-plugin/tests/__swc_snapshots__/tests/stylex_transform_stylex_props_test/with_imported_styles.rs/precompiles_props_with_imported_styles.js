@@ -0,0 +1,14 @@
+//__stylex_metadata_start__[{"class_name":"xfawy5m","style":{"rtl":null,"ltr":".xfawy5m{padding:4px}"},"priority":1000}]__stylex_metadata_end__
+import stylex from '@stylexjs/stylex';
+import { sharedStyles, otherStyles as other } from './shared_styles';
+({
+    className: "xfawy5m x1e2nbdu x117nqv4"
+});
+({
+    0: {
+        className: "x1e2nbdu"
+    },
+    1: {
+        className: "x1e2nbdu x1cu41gw"
+    }
+})[!!isActive << 0];
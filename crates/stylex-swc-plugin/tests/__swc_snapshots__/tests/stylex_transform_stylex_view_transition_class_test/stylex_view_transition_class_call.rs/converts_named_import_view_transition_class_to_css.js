@@ -0,0 +1,15 @@
+//__stylex_metadata_start__[{"class_name":"x1jn504y-B","style":{"rtl":null,"ltr":"@keyframes x1jn504y-B{from{opacity:1;}to{opacity:0;}}"},"priority":1},{"class_name":"xm1576h","style":{"rtl":null,"ltr":"::view-transition-old(*.xm1576h){animation-name:x1jn504y-B;}"},"priority":1},{"class_name":"xnuj4ra","style":{"rtl":null,"ltr":".xnuj4ra{view-transition-class:xm1576h}"},"priority":3000},{"class_name":"x18yeff2","style":{"rtl":null,"ltr":".x18yeff2{view-transition-name:card}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import { viewTransitionClass, keyframes, create } from 'stylex';
+_inject2("@keyframes x1jn504y-B{from{opacity:1;}to{opacity:0;}}", 1);
+_inject2("::view-transition-old(*.xm1576h){animation-name:x1jn504y-B;}", 1);
+_inject2(".xnuj4ra{view-transition-class:xm1576h}", 3000);
+_inject2(".x18yeff2{view-transition-name:card}", 3000);
+export const styles = {
+    card: {
+        viewTransitionClass: "xnuj4ra",
+        viewTransitionName: "x18yeff2",
+        $$css: true
+    }
+};
@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"xv5ohwk","style":{"rtl":null,"ltr":"::view-transition-group(*.xv5ohwk){transition-property:none;}::view-transition-image-pair(*.xv5ohwk){border-radius:16px;}::view-transition-old(*.xv5ohwk){animation-duration:.5s;}::view-transition-new(*.xv5ohwk){animation-timing-function:ease-out;animation-duration:.5s;}"},"priority":1}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("::view-transition-group(*.xv5ohwk){transition-property:none;}::view-transition-image-pair(*.xv5ohwk){border-radius:16px;}::view-transition-old(*.xv5ohwk){animation-duration:.5s;}::view-transition-new(*.xv5ohwk){animation-timing-function:ease-out;animation-duration:.5s;}", 1);
+export const cardTransition = "xv5ohwk";
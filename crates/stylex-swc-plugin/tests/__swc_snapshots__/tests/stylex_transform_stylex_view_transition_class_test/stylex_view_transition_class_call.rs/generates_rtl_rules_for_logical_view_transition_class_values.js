@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"xmipwej","style":{"rtl":"::view-transition-group(*.xmipwej){margin-inline-start:10px;transition-property:none;}::view-transition-new(*.xmipwej){float:right;}","ltr":"::view-transition-group(*.xmipwej){margin-inline-start:10px;transition-property:none;}::view-transition-new(*.xmipwej){float:left;}"},"priority":1}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("::view-transition-group(*.xmipwej){margin-inline-start:10px;transition-property:none;}::view-transition-new(*.xmipwej){float:left;}", 1, "::view-transition-group(*.xmipwej){margin-inline-start:10px;transition-property:none;}::view-transition-new(*.xmipwej){float:right;}");
+export const transitionClass = "xmipwej";
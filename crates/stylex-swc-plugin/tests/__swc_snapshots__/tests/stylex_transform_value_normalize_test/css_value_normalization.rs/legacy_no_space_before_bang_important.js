@@ -1,5 +1,5 @@
-//__stylex_metadata_start__[{"class_name":"xzw3067","style":{"rtl":null,"ltr":".xzw3067{color:red!important}"},"priority":3000}]__stylex_metadata_end__
+//__stylex_metadata_start__[{"class_name":"x1shhdbt","style":{"rtl":null,"ltr":".x1shhdbt{color:red!important}"},"priority":3000.5}]__stylex_metadata_end__
 import _inject from "@stylexjs/stylex/lib/stylex-inject";
 var _inject2 = _inject;
 import stylex from 'stylex';
-_inject2(".xzw3067{color:red!important}", 3000);
+_inject2(".x1shhdbt{color:red!important}", 3000.5);
@@ -0,0 +1,11 @@
+//__stylex_metadata_start__[{"class_name":"x1e2nbdu","style":{"rtl":null,"ltr":".x1e2nbdu{color:red}"},"priority":3000},{"class_name":"x1hm9lzh","style":{"rtl":null,"ltr":".x1hm9lzh{margin-inline-start:10px}"},"priority":3000},{"class_name":"x1shhdbt","style":{"rtl":null,"ltr":".x1shhdbt{color:red!important}"},"priority":3000.5},{"class_name":"xvo6ybj","style":{"rtl":null,"ltr":".xvo6ybj{margin-inline-start:10px!important}"},"priority":3000.5},{"class_name":"x3z49bn","style":{"rtl":null,"ltr":".x3z49bn{display:flex!important;display:grid!important}"},"priority":3000.5},{"class_name":"xgdahwh","style":{"rtl":null,"ltr":".xgdahwh{background-color:blue!important}"},"priority":3000.5},{"class_name":"x3kcl67","style":{"rtl":null,"ltr":".x3kcl67:hover{background-color:green!important}"},"priority":3130.5}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1e2nbdu{color:red}", 3000);
+_inject2(".x1hm9lzh{margin-inline-start:10px}", 3000);
+_inject2(".x1shhdbt{color:red!important}", 3000.5);
+_inject2(".xvo6ybj{margin-inline-start:10px!important}", 3000.5);
+_inject2(".x3z49bn{display:flex!important;display:grid!important}", 3000.5);
+_inject2(".xgdahwh{background-color:blue!important}", 3000.5);
+_inject2(".x3kcl67:hover{background-color:green!important}", 3130.5);
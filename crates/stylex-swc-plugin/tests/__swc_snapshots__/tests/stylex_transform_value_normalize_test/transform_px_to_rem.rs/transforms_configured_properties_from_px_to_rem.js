@@ -0,0 +1,11 @@
+//__stylex_metadata_start__[{"class_name":"x1nbopsb","style":{"rtl":null,"ltr":".x1nbopsb{padding:.8rem 1.2rem}"},"priority":1000},{"class_name":"xdp8wg0","style":{"rtl":null,"ltr":".xdp8wg0{margin-top:1.3rem}"},"priority":4000},{"class_name":"x1aqvgbo","style":{"rtl":null,"ltr":".x1aqvgbo{line-height:2.4rem}"},"priority":3000},{"class_name":"x1exxlbk","style":{"rtl":null,"ltr":".x1exxlbk{width:100px}"},"priority":4000},{"class_name":"x5hrx5z","style":{"rtl":null,"ltr":".x5hrx5z{padding:calc(100% - 1.6rem)}"},"priority":1000},{"class_name":"xdj266r","style":{"rtl":null,"ltr":".xdj266r{margin-top:0}"},"priority":4000},{"class_name":"x1evy7pa","style":{"rtl":null,"ltr":".x1evy7pa{line-height:1.5}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x1nbopsb{padding:.8rem 1.2rem}", 1000);
+_inject2(".xdp8wg0{margin-top:1.3rem}", 4000);
+_inject2(".x1aqvgbo{line-height:2.4rem}", 3000);
+_inject2(".x1exxlbk{width:100px}", 4000);
+_inject2(".x5hrx5z{padding:calc(100% - 1.6rem)}", 1000);
+_inject2(".xdj266r{margin-top:0}", 4000);
+_inject2(".x1evy7pa{line-height:1.5}", 3000);
@@ -0,0 +1,12 @@
+//__stylex_metadata_start__[{"class_name":"xju7dy6","style":{"rtl":null,"ltr":":root{--x4zqer5:0.4rem;--xgl5hcz:0.8rem;--xl1r7p0:1.5;}"},"priority":0},{"class_name":"xju7dy6-ubnrjs","style":{"rtl":null,"ltr":"@media (min-width: 76.8rem){:root{--xgl5hcz:1.6rem;}}"},"priority":0.1}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(":root{--x4zqer5:0.4rem;--xgl5hcz:0.8rem;--xl1r7p0:1.5;}", 0);
+_inject2("@media (min-width: 76.8rem){:root{--xgl5hcz:1.6rem;}}", 0.1);
+export const spacing = {
+    small: "var(--x4zqer5)",
+    medium: "var(--xgl5hcz)",
+    ratio: "var(--xl1r7p0)",
+    __themeName__: "xju7dy6"
+};
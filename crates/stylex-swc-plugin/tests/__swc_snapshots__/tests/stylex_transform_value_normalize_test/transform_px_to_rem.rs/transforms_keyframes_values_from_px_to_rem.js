@@ -0,0 +1,5 @@
+//__stylex_metadata_start__[{"class_name":"x1uzee44-B","style":{"rtl":null,"ltr":"@keyframes x1uzee44-B{from{margin-top:2rem;}to{margin-top:4rem;}}"},"priority":1}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2("@keyframes x1uzee44-B{from{margin-top:2rem;}to{margin-top:4rem;}}", 1);
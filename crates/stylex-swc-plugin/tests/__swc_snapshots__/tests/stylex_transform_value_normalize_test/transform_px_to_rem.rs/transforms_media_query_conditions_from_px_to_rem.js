@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"xh8yej3","style":{"rtl":null,"ltr":".xh8yej3{width:100%}"},"priority":4000},{"class_name":"x83ujgj","style":{"rtl":null,"ltr":"@media (min-width: 76.8rem){.x83ujgj.x83ujgj{width:50%}}"},"priority":4200}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xh8yej3{width:100%}", 4000);
+_inject2("@media (min-width: 76.8rem){.x83ujgj.x83ujgj{width:50%}}", 4200);
@@ -0,0 +1,5 @@
+//__stylex_metadata_start__[{"class_name":"xzgdaqa","style":{"rtl":null,"ltr":".xzgdaqa, .xzgdaqa:root{--bgColorHash:white;--labelColorHash:red;}"},"priority":0.5}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xzgdaqa, .xzgdaqa:root{--bgColorHash:white;--labelColorHash:red;}", 0.5);
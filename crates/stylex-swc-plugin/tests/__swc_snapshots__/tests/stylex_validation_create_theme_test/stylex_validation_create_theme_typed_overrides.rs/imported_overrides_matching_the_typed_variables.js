@@ -0,0 +1,11 @@
+//__stylex_metadata_start__[{"class_name":"x13lok7x","style":{"rtl":null,"ltr":".x13lok7x, .x13lok7x:root{--xk46nqh:white;--x1b2mxn:8px;--x1j371f2:12px;}"},"priority":0.5}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import "typed_tokens.stylex";
+import stylex from 'stylex';
+import { vars } from 'typed_tokens.stylex';
+_inject2(".x13lok7x, .x13lok7x:root{--xk46nqh:white;--x1b2mxn:8px;--x1j371f2:12px;}", 0.5);
+export const theme = {
+    $$css: true,
+    "var(--x12eisuh)": "x13lok7x"
+};
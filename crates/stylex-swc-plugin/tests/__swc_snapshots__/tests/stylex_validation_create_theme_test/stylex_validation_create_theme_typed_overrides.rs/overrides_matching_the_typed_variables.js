@@ -0,0 +1,21 @@
+//__stylex_metadata_start__[{"class_name":"xjrzwe6","style":{"rtl":null,"ltr":"@property --xjrzwe6 { syntax: \"<color>\"; inherits: true; initial-value: red }"},"priority":0},{"class_name":"x1cazb2m","style":{"rtl":null,"ltr":"@property --x1cazb2m { syntax: \"<length>\"; inherits: true; initial-value: 4px }"},"priority":0},{"class_name":"x1b2cdi1","style":{"rtl":null,"ltr":"@property --x1b2cdi1 { syntax: \"<angle>\"; inherits: true; initial-value: 0deg }"},"priority":0},{"class_name":"xm1nzai","style":{"rtl":null,"ltr":":root{--xjrzwe6:red;--x1cazb2m:4px;--x1b2cdi1:0deg;--xk3egxc:blue;}"},"priority":0},{"class_name":"x1gt0soq","style":{"rtl":null,"ltr":".x1gt0soq, .x1gt0soq:root{--xjrzwe6:rgb(0 0 0);--x1cazb2m:calc(2px + 1rem);--x1b2cdi1:var(--rotation);--xk3egxc:12px;}"},"priority":0.5},{"class_name":"x1gt0soq-1lveb7","style":{"rtl":null,"ltr":"@media (prefers-color-scheme: dark){.x1gt0soq, .x1gt0soq:root{--xjrzwe6:white;}}"},"priority":0.6}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2('@property --xjrzwe6 { syntax: "<color>"; inherits: true; initial-value: red }', 0);
+_inject2('@property --x1cazb2m { syntax: "<length>"; inherits: true; initial-value: 4px }', 0);
+_inject2('@property --x1b2cdi1 { syntax: "<angle>"; inherits: true; initial-value: 0deg }', 0);
+_inject2(":root{--xjrzwe6:red;--x1cazb2m:4px;--x1b2cdi1:0deg;--xk3egxc:blue;}", 0);
+export const vars = {
+    color: "var(--xjrzwe6)",
+    radius: "var(--x1cazb2m)",
+    rotation: "var(--x1b2cdi1)",
+    untyped: "var(--xk3egxc)",
+    __themeName__: "xm1nzai"
+};
+_inject2(".x1gt0soq, .x1gt0soq:root{--xjrzwe6:rgb(0 0 0);--x1cazb2m:calc(2px + 1rem);--x1b2cdi1:var(--rotation);--xk3egxc:12px;}", 0.5);
+_inject2("@media (prefers-color-scheme: dark){.x1gt0soq, .x1gt0soq:root{--xjrzwe6:white;}}", 0.6);
+export const theme = {
+    $$css: true,
+    xm1nzai: "x1gt0soq"
+};
@@ -0,0 +1,6 @@
+//__stylex_metadata_start__[{"class_name":"xykt701","style":{"rtl":null,"ltr":".xykt701{border-block-style:solid}"},"priority":3000},{"class_name":"x1kbiq1m","style":{"rtl":null,"ltr":".x1kbiq1m{border-block-width:1px}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xykt701{border-block-style:solid}", 3000);
+_inject2(".x1kbiq1m{border-block-width:1px}", 3000);
@@ -0,0 +1,8 @@
+//__stylex_metadata_start__[{"class_name":"xrkmrrc","style":{"rtl":null,"ltr":".xrkmrrc{background-color:red}"},"priority":3000},{"class_name":"xdwrcjd","style":{"rtl":null,"ltr":".xdwrcjd{margin-inline-start:4px}"},"priority":3000},{"class_name":"x3wpk9n","style":{"rtl":null,"ltr":".x3wpk9n{--brand-color:blue}"},"priority":1},{"class_name":"xrhqi7g","style":{"rtl":null,"ltr":".xrhqi7g{custom-element-property:auto}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xrkmrrc{background-color:red}", 3000);
+_inject2(".xdwrcjd{margin-inline-start:4px}", 3000);
+_inject2(".x3wpk9n{--brand-color:blue}", 1);
+_inject2(".xrhqi7g{custom-element-property:auto}", 3000);
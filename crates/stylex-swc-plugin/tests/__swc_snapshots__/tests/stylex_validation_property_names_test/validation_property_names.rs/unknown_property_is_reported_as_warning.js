@@ -0,0 +1,5 @@
+//__stylex_metadata_start__[{"class_name":"xr6y3ib","style":{"rtl":null,"ltr":".xr6y3ib{background-colour:red}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xr6y3ib{background-colour:red}", 3000);
@@ -0,0 +1,9 @@
+//__stylex_metadata_start__[{"class_name":"x8hvo8v","style":{"rtl":null,"ltr":".x8hvo8v{color:CanvasText}"},"priority":3000},{"class_name":"xeu4i36","style":{"rtl":null,"ltr":".xeu4i36{background-color:Canvas}"},"priority":3000},{"class_name":"x1iscqsp","style":{"rtl":null,"ltr":".x1iscqsp{border-color:ButtonBorder}"},"priority":2000},{"class_name":"x1g21a3s","style":{"rtl":null,"ltr":".x1g21a3s{outline-color:Highlight}"},"priority":3000},{"class_name":"xg0wsox","style":{"rtl":null,"ltr":".xg0wsox{accent-color:AccentColor}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".x8hvo8v{color:CanvasText}", 3000);
+_inject2(".xeu4i36{background-color:Canvas}", 3000);
+_inject2(".x1iscqsp{border-color:ButtonBorder}", 2000);
+_inject2(".x1g21a3s{outline-color:Highlight}", 3000);
+_inject2(".xg0wsox{accent-color:AccentColor}", 3000);
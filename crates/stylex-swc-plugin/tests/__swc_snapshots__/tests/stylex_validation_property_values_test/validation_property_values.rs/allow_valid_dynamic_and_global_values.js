@@ -0,0 +1,16 @@
+//__stylex_metadata_start__[{"class_name":"xgksxd1","style":{"rtl":null,"ltr":".xgksxd1{display:inline flex}"},"priority":3000},{"class_name":"x170k5ml","style":{"rtl":null,"ltr":".x170k5ml{position:inherit}"},"priority":3000},{"class_name":"xbie4d3","style":{"rtl":null,"ltr":".xbie4d3{width:calc(100% - 10px)}"},"priority":4000},{"class_name":"x1f5funs","style":{"rtl":null,"ltr":".x1f5funs{height:var(--height)}"},"priority":4000},{"class_name":"x19bbpc0","style":{"rtl":null,"ltr":".x19bbpc0{margin:0 auto}"},"priority":1000},{"class_name":"x1n0khkq","style":{"rtl":null,"ltr":".x1n0khkq{color:rebeccapurple}"},"priority":3000},{"class_name":"x1v4ufve","style":{"rtl":null,"ltr":".x1v4ufve{background-color:color-mix(in srgb,red,blue)}"},"priority":3000},{"class_name":"xd984w5","style":{"rtl":null,"ltr":".xd984w5{transition-duration:1s,.2s}"},"priority":3000},{"class_name":"x1n327nk","style":{"rtl":null,"ltr":".x1n327nk{z-index:10}"},"priority":3000},{"class_name":"xbyyjgo","style":{"rtl":null,"ltr":".xbyyjgo{opacity:.5}"},"priority":3000},{"class_name":"x11vw4cf","style":{"rtl":null,"ltr":".x11vw4cf{border-radius:4px / 8px}"},"priority":2000},{"class_name":"x117nqv4","style":{"rtl":null,"ltr":".x117nqv4{font-weight:bold}"},"priority":3000}]__stylex_metadata_end__
+import _inject from "@stylexjs/stylex/lib/stylex-inject";
+var _inject2 = _inject;
+import stylex from 'stylex';
+_inject2(".xgksxd1{display:inline flex}", 3000);
+_inject2(".x170k5ml{position:inherit}", 3000);
+_inject2(".xbie4d3{width:calc(100% - 10px)}", 4000);
+_inject2(".x1f5funs{height:var(--height)}", 4000);
+_inject2(".x19bbpc0{margin:0 auto}", 1000);
+_inject2(".x1n0khkq{color:rebeccapurple}", 3000);
+_inject2(".x1v4ufve{background-color:color-mix(in srgb,red,blue)}", 3000);
+_inject2(".xd984w5{transition-duration:1s,.2s}", 3000);
+_inject2(".x1n327nk{z-index:10}", 3000);
+_inject2(".xbyyjgo{opacity:.5}", 3000);
+_inject2(".x11vw4cf{border-radius:4px / 8px}", 2000);
+_inject2(".x117nqv4{font-weight:bold}", 3000);
@@ -5,6 +5,7 @@ use stylex_swc_plugin::shared::{
     functions::{FunctionConfig, FunctionConfigType, FunctionMap, FunctionType},
     named_import_source::ImportSources,
     state_manager::StateManager,
+    stylex_options::{StyleXOptions, TemplateTagBehavior},
   },
   utils::ast::convertors::{ident_to_expression, string_to_expression},
 };
@@ -142,6 +143,193 @@ fn evaluates_built_in_functions() {
   )
 }
 
+#[test]
+fn evaluates_whitelisted_object_and_array_built_ins() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            Object.assign({}, { a: 1, b: 2 }, { b: 3 });
+            Object.values({ a: 'x', b: { c: 1 } });
+            Array.from([1, 2], (x) => x * 2);
+            Array.from({ length: 3 }, (_, i) => i);
+            Array.from('ab');
+            Array.isArray([1]);
+            Array.isArray('a');
+        "#,
+    r#"
+            ({ a: 1, b: 3 });
+            ['x', { c: 1 }];
+            [2, 4];
+            [0, 1, 2];
+            ["a", "b"];
+            true;
+            false;
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_whitelisted_number_and_string_built_ins() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            Number('  12.5 ');
+            Number('');
+            parseInt('42px');
+            parseInt('ff', 16);
+            Number.parseFloat('1.5e2rem');
+            String(10);
+            String(true);
+            (1.2345).toFixed(2);
+            Math.abs(-4);
+            Math.sqrt(16);
+            Math.trunc(-4.7);
+            Math.sign(-3);
+            Math.hypot(3, 4);
+            (2.5).toFixed(0);
+            (-2.5).toFixed(0);
+            (1.005).toFixed(2);
+            (0.000001).toFixed(7);
+            (99.95).toFixed(1);
+            (9.5).toFixed(0);
+            (1 / 0).toFixed(2);
+            (-1 / 0).toFixed(2);
+            (1.5).toFixed(0 / 0);
+            Number('0x10');
+            Number('0b101');
+            Number('-Infinity');
+            Number('inf');
+            Number('-0x10');
+        "#,
+    r#"
+            12.5;
+            0;
+            42;
+            255;
+            150;
+            "10";
+            "true";
+            "1.23";
+            4;
+            4;
+            -4;
+            -1;
+            5;
+            "3";
+            "-3";
+            "1.00";
+            "0.0000010";
+            "100.0";
+            "10";
+            "Infinity";
+            "-Infinity";
+            "2";
+            16;
+            5;
+            -Infinity;
+            NaN;
+            NaN;
+        "#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Evaluation built-in functions not supported - toString")]
+fn fails_on_unsupported_number_methods() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            (1).toString();
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "toFixed() digits argument must be between 0 and 100")]
+fn fails_on_to_fixed_digits_out_of_range() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            (1.5).toFixed(101);
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "toFixed() digits argument must be between 0 and 100")]
+fn fails_on_negative_to_fixed_digits() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            (1.5).toFixed(-1);
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Failed to evaluate expression")]
+fn does_not_evaluate_spread_arguments_of_built_ins() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            Array.from(...[[1, 2]]);
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Object.assign is only supported with an empty object literal as the first argument"
+)]
+fn fails_on_mutating_object_assign() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            Object.assign({ a: 1 }, { b: 2 });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
 #[test]
 fn evaluates_customs_functions() {
   test_transform(
@@ -529,3 +717,155 @@ fn evaluates_condition_value_expressions() {
     false,
   )
 }
+
+#[test]
+fn evaluates_string_raw_tagged_templates() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor::default(),
+    r#"
+            String.raw`a\nb${1 + 2}`
+        "#,
+    r#"
+            "a\\nb3"
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_known_tagged_templates() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor {
+      state: StateManager::new(StyleXOptions {
+        known_template_tags: HashMap::from([("dedent".to_string(), TemplateTagBehavior::Concat)]),
+        ..StyleXOptions::default()
+      }),
+      ..EvaluationModuleTransformVisitor::default()
+    },
+    r#"
+            dedent`calc(${10 * 2}px + 1em)`
+        "#,
+    r#"
+            "calc(20px + 1em)"
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_known_tagged_templates_with_a_suffix() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor {
+      state: StateManager::new(StyleXOptions {
+        known_template_tags: HashMap::from([(
+          "px".to_string(),
+          TemplateTagBehavior::Suffix("px".to_string()),
+        )]),
+        ..StyleXOptions::default()
+      }),
+      ..EvaluationModuleTransformVisitor::default()
+    },
+    r#"
+            px`${2 * 2} ${8}`
+        "#,
+    r#"
+            "4px 8px"
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_known_tagged_templates_with_invalid_escapes() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor {
+      state: StateManager::new(StyleXOptions {
+        known_template_tags: HashMap::from([("dedent".to_string(), TemplateTagBehavior::Concat)]),
+        ..StyleXOptions::default()
+      }),
+      ..EvaluationModuleTransformVisitor::default()
+    },
+    r#"
+            dedent`\unicode ${1}`
+        "#,
+    r#"
+            "\\unicode 1"
+        "#,
+    false,
+  )
+}
+
+#[test]
+fn evaluates_css_tagged_templates() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor {
+      state: StateManager::new(StyleXOptions {
+        css_template_tags: vec!["css".to_string()],
+        ..StyleXOptions::default()
+      }),
+      ..EvaluationModuleTransformVisitor::default()
+    },
+    r#"
+            css`
+              background-color: red;
+              padding-top: ${2 * 4}px;
+              --custom-color: rgb(0, 0, 0);
+              -webkit-appearance: none;
+              margin: 0 auto !important;
+            `
+        "#,
+    r#"
+            ({
+              backgroundColor: "red",
+              paddingTop: "8px",
+              "--custom-color": "rgb(0, 0, 0)",
+              WebkitAppearance: "none",
+              margin: "0 auto !important"
+            })
+        "#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "A CSS tagged template can only contain a block of CSS declarations.")]
+fn fails_on_css_tagged_templates_with_nested_rules() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |_| EvaluationModuleTransformVisitor {
+      state: StateManager::new(StyleXOptions {
+        css_template_tags: vec!["css".to_string()],
+        ..StyleXOptions::default()
+      }),
+      ..EvaluationModuleTransformVisitor::default()
+    },
+    r#"
+            css`color: red; &:hover { color: blue; }`
+        "#,
+    r#""#,
+    false,
+  )
+}
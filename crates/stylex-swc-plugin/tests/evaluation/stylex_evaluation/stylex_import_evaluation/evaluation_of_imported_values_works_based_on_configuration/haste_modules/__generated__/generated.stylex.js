@@ -0,0 +1,5 @@
+import stylex from '@stylexjs/stylex';
+
+export const MyTheme = stylex.defineVars({
+  foreground: 'black',
+});
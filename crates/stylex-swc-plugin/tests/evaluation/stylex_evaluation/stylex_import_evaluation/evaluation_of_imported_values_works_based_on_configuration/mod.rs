@@ -1 +1,2 @@
 mod theme_name_hashing_based_on_filename_alone_works;
+mod theme_name_hashing_with_haste_module_map_works;
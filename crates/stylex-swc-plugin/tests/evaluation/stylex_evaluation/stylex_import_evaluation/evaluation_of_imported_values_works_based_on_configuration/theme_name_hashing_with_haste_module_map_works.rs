@@ -0,0 +1,199 @@
+use serde_json::json;
+use stylex_swc_plugin::shared::structures::stylex_options::{
+  ModuleResolution, StyleXOptionsParams,
+};
+use stylex_swc_plugin::shared::utils::common::create_hash;
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::common::FileName;
+use swc_core::ecma::parser::{Syntax, TsSyntax};
+
+use crate::utils::transform::stringify_js;
+
+static HASTE_MODULES_DIR: &str = concat!(
+  env!("CARGO_MANIFEST_DIR"),
+  "/tests/evaluation/stylex_evaluation/stylex_import_evaluation/evaluation_of_imported_values_works_based_on_configuration/haste_modules"
+);
+
+fn tranform(input: &str, ignore_patterns: Option<Vec<String>>) -> String {
+  tranform_file(
+    input,
+    HASTE_MODULES_DIR,
+    &format!("{}/src/component.js", HASTE_MODULES_DIR),
+    ignore_patterns,
+  )
+}
+
+fn tranform_file(
+  input: &str,
+  root_dir: &str,
+  filename: &str,
+  ignore_patterns: Option<Vec<String>>,
+) -> String {
+  stringify_js(
+    input,
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      let module_resolution: ModuleResolution = serde_json::from_value(json!({
+        "type": "haste",
+        "rootDir": root_dir,
+        "ignorePatterns": ignore_patterns,
+      }))
+      .unwrap();
+
+      let mut config = StyleXOptionsParams {
+        class_name_prefix: Some("__hashed_var__".to_string()),
+        runtime_injection: Some(true),
+        treeshake_compensation: Some(true),
+        unstable_module_resolution: Some(module_resolution),
+        ..Default::default()
+      };
+
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass {
+          filename: FileName::Real(filename.into()),
+          ..Default::default()
+        },
+        Some(&mut config),
+      )
+    },
+  )
+}
+
+#[test]
+fn importing_module_from_nested_directory_uses_its_own_extension() {
+  let input = r#"import stylex from 'stylex';
+    import { MyTheme } from 'otherFile.stylex';
+    const styles = stylex.create({
+        red: {
+            color: MyTheme.foreground,
+        }
+    });
+    stylex(styles.red);"#;
+
+  let transformation = tranform(input, None);
+
+  let expected_var_name = format!(
+    "var(--__hashed_var__{})",
+    create_hash("otherFile.stylex.ts//MyTheme.foreground")
+  );
+
+  assert!(transformation.contains(&expected_var_name));
+}
+
+#[test]
+fn importing_unknown_module_falls_back_to_source_extension() {
+  let input = r#"import stylex from 'stylex';
+    import { MyTheme } from 'unknownFile.stylex';
+    const styles = stylex.create({
+        red: {
+            color: MyTheme.foreground,
+        }
+    });
+    stylex(styles.red);"#;
+
+  let transformation = tranform(input, None);
+
+  let expected_var_name = format!(
+    "var(--__hashed_var__{})",
+    create_hash("unknownFile.stylex.js//MyTheme.foreground")
+  );
+
+  assert!(transformation.contains(&expected_var_name));
+}
+
+#[test]
+#[should_panic(expected = "Found multiple files for Haste module \"duplicated.stylex\"")]
+fn importing_duplicated_module_fails() {
+  let input = r#"import stylex from 'stylex';
+    import { MyTheme } from 'duplicated.stylex';
+    const styles = stylex.create({
+        red: {
+            color: MyTheme.foreground,
+        }
+    });
+    stylex(styles.red);"#;
+
+  tranform(input, None);
+}
+
+#[test]
+fn ignored_files_are_not_part_of_module_map() {
+  let input = r#"import stylex from 'stylex';
+    import { MyTheme } from 'generated.stylex';
+    const styles = stylex.create({
+        red: {
+            color: MyTheme.foreground,
+        }
+    });
+    stylex(styles.red);"#;
+
+  let transformation = tranform(input, Some(vec!["**/__generated__/**".to_string()]));
+
+  let expected_var_name = format!(
+    "var(--__hashed_var__{})",
+    create_hash("generated.stylex.js//MyTheme.foreground")
+  );
+
+  assert!(transformation.contains(&expected_var_name));
+}
+
+#[test]
+fn defining_module_hashes_the_resolved_module_file_name() {
+  let input = r#"import stylex from 'stylex';
+    export const MyTheme = stylex.defineVars({
+        foreground: 'red',
+    });"#;
+
+  let transformation = tranform_file(
+    input,
+    HASTE_MODULES_DIR,
+    &format!("{}/src/nested/otherFile.stylex.ts", HASTE_MODULES_DIR),
+    None,
+  );
+
+  let expected_var_name = format!(
+    "--__hashed_var__{}",
+    create_hash("otherFile.stylex.ts//MyTheme.foreground")
+  );
+
+  assert!(transformation.contains(&expected_var_name));
+}
+
+#[test]
+fn transforms_reuse_one_scan_of_the_module_map() {
+  let root_dir = std::env::temp_dir().join(format!("stylex_haste_{}", std::process::id()));
+  let root_dir_str = root_dir.display().to_string();
+  let filename = format!("{}/component.js", root_dir_str);
+
+  let _ = std::fs::remove_dir_all(&root_dir);
+  std::fs::create_dir_all(&root_dir).unwrap();
+
+  let input = r#"import stylex from 'stylex';
+    import { MyTheme } from 'late.stylex';
+    const styles = stylex.create({
+        red: {
+            color: MyTheme.foreground,
+        }
+    });
+    stylex(styles.red);"#;
+
+  let before = tranform_file(input, &root_dir_str, &filename, None);
+
+  std::fs::write(
+    root_dir.join("late.stylex.ts"),
+    "export const MyTheme = {};",
+  )
+  .unwrap();
+
+  let after = tranform_file(input, &root_dir_str, &filename, None);
+
+  std::fs::remove_dir_all(&root_dir).unwrap();
+
+  // The second transform resolves through the map scanned by the first one
+  assert!(before.contains(&create_hash("late.stylex.js//MyTheme.foreground")));
+  assert!(after.contains(&create_hash("late.stylex.js//MyTheme.foreground")));
+}
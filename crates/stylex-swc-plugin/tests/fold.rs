@@ -1,4 +1,5 @@
 mod evaluation;
+mod stylex_design_tokens_test;
 mod stylex_metadata_test;
 mod stylex_transform_call_test;
 mod stylex_transform_create_test;
@@ -12,7 +13,9 @@ mod stylex_transform_polyfills_test;
 mod stylex_transform_stylex_attrs_test;
 mod stylex_transform_stylex_create_theme_test;
 mod stylex_transform_stylex_keyframes_test;
+mod stylex_transform_stylex_position_try_test;
 mod stylex_transform_stylex_props_test;
+mod stylex_transform_stylex_view_transition_class_test;
 mod stylex_transform_value_normalize_test;
 mod stylex_transform_variable_removal_test;
 mod stylex_validation_create_test;
@@ -22,5 +25,9 @@ mod stylex_validation_declarations_test;
 mod stylex_validation_define_vars_test;
 mod stylex_validation_import_test;
 mod stylex_validation_keyframes_test;
+mod stylex_validation_position_try_test;
+mod stylex_validation_property_names_test;
+mod stylex_validation_property_values_test;
 mod stylex_validation_regular_css;
+mod stylex_validation_view_transition_class_test;
 pub(crate) mod utils;
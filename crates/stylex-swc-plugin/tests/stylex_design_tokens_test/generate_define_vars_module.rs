@@ -0,0 +1,145 @@
+use insta::assert_snapshot;
+use stylex_swc_plugin::shared::utils::core::design_tokens_codegen::{
+  generate_define_vars_module, DefineVarsModuleOptions,
+};
+
+static TOKENS: &str = r##"{
+  "color": {
+    "$type": "color",
+    "brand": {
+      "primary": {
+        "$value": "#0a66c2",
+        "$modes": { "dark": "#70b5f9" }
+      },
+      "secondary": { "$value": "{color.brand.primary}" }
+    },
+    "surface": {
+      "$value": "white",
+      "$modes": { "dark": "#1d2226", "highContrast": "black" }
+    }
+  },
+  "space": {
+    "$type": "dimension",
+    "small": { "$value": { "value": 4, "unit": "px" } },
+    "large": { "$value": "16px" }
+  },
+  "motion": {
+    "duration": {
+      "$type": "duration",
+      "$value": "200ms",
+      "$modes": { "reducedMotion": "0s" }
+    },
+    "easing": {
+      "$type": "cubicBezier",
+      "$value": [0.4, 0, 0.2, 1]
+    }
+  },
+  "font-families": {
+    "body": {
+      "$type": "fontFamily",
+      "$value": ["Segoe UI", "system-ui", "sans-serif"]
+    }
+  }
+}"##;
+
+#[test]
+fn generates_define_vars_groups() {
+  let output = generate_define_vars_module(TOKENS, &DefineVarsModuleOptions::default());
+
+  assert_snapshot!(output);
+}
+
+#[test]
+fn regeneration_is_stable() {
+  let options = DefineVarsModuleOptions::default();
+
+  assert_eq!(
+    generate_define_vars_module(TOKENS, &options),
+    generate_define_vars_module(TOKENS, &options)
+  );
+}
+
+#[test]
+fn restores_exported_conditions_and_syntaxes() {
+  let tokens = r##"{
+    "layout": {
+      "accent": {
+        "$value": "purple",
+        "$extensions": {
+          "com.stylexjs": {
+            "variable": "--x25jbin",
+            "conditions": {
+              "@media (prefers-color-scheme: dark)": "violet",
+              "@media (prefers-color-scheme: dark) @supports (color: oklab(0 0 0))": "oklab(0.7 -0.3 -0.4)"
+            }
+          }
+        }
+      },
+      "gaps": {
+        "$value": "4px 8px",
+        "$extensions": { "com.stylexjs": { "syntax": "<length>+" } }
+      },
+      "rotation": {
+        "$value": "0deg",
+        "$extensions": { "com.stylexjs": { "syntax": "<angle>" } }
+      }
+    }
+  }"##;
+
+  let output = generate_define_vars_module(tokens, &DefineVarsModuleOptions::default());
+
+  assert_snapshot!(output);
+}
+
+#[test]
+fn uses_custom_import_source_and_modes() {
+  let tokens = r##"{
+    "colors": {
+      "text": {
+        "$type": "color",
+        "$value": "black",
+        "$modes": { "print": "black", "wide": "#333" }
+      }
+    }
+  }"##;
+
+  let mut options = DefineVarsModuleOptions {
+    import_source: "@acme/stylex".to_string(),
+    ..DefineVarsModuleOptions::default()
+  };
+
+  options
+    .modes
+    .insert("print".to_string(), "@media print".to_string());
+  options
+    .modes
+    .insert("wide".to_string(), "@media (min-width: 1200px)".to_string());
+
+  let output = generate_define_vars_module(tokens, &options);
+
+  assert_snapshot!(output);
+}
+
+#[test]
+#[should_panic(expected = r#"Unknown design token mode: "sepia"."#)]
+fn rejects_unknown_modes() {
+  let tokens = r##"{
+    "colors": {
+      "text": { "$value": "black", "$modes": { "sepia": "brown" } }
+    }
+  }"##;
+
+  generate_define_vars_module(tokens, &DefineVarsModuleOptions::default());
+}
+
+#[test]
+#[should_panic(expected = r#"Design token alias cannot be resolved: "{colors.missing}"."#)]
+fn rejects_unresolved_aliases() {
+  let tokens = r##"{
+    "colors": {
+      "text": { "$value": "{colors.missing}" }
+    }
+  }"##;
+
+  generate_define_vars_module(tokens, &DefineVarsModuleOptions::default());
+}
@@ -0,0 +1,2 @@
+#[cfg(feature = "design-tokens-codegen")]
+mod generate_define_vars_module;
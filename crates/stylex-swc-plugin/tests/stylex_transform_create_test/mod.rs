@@ -1,7 +1,11 @@
 mod stylex_create_call;
+mod stylex_create_call_conditional_at_rules;
+mod stylex_create_call_normalized_queries;
 mod stylex_create_call_pseudo_classes;
 mod stylex_create_call_pseudo_classes_within_properties;
 mod stylex_create_call_pseudo_elements;
+mod stylex_create_call_pseudo_variants;
 mod stylex_create_call_queries;
 mod stylex_create_call_queries_with_functions;
 mod stylex_create_call_queries_with_properties;
+mod stylex_create_call_tagged_templates;
@@ -0,0 +1,237 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_starting_style,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            dialog: {
+                opacity: {
+                    default: 1,
+                    '@starting-style': 0,
+                },
+                transform: {
+                    default: 'none',
+                    '@media (prefers-reduced-motion: no-preference)': {
+                        default: 'none',
+                        '@starting-style': 'translateY(8px)',
+                    },
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_scope,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            title: {
+                color: {
+                    default: 'black',
+                    '@scope (.card) to (.content)': 'blue',
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  starting_style_sorts_after_media_queries,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            dialog: {
+                opacity: {
+                    default: 1,
+                    '@media (min-width: 768px)': 0.8,
+                    '@starting-style': 0,
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_container_style_queries_and_named_containers,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            card: {
+                color: {
+                    default: 'black',
+                    '@container style(--theme: dark)': 'white',
+                },
+                padding: {
+                    default: 8,
+                    '@container sidebar (min-width: 400px)': 16,
+                },
+            },
+        });
+    "#
+);
+
+#[test]
+#[should_panic(expected = r#"Invalid at-rule "@starting-style (opacity: 0)"."#)]
+fn disallow_starting_style_with_prelude() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            dialog: {
+                opacity: {
+                    default: 1,
+                    '@starting-style (opacity: 0)': 0,
+                },
+            },
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid at-rule "@scope (.card"."#)]
+fn disallow_invalid_scope_prelude() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            title: {
+                color: {
+                    default: 'black',
+                    '@scope (.card': 'blue',
+                },
+            },
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid at-rule "@container sidebar"."#)]
+fn disallow_container_without_condition() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            card: {
+                color: {
+                    default: 'black',
+                    '@container sidebar': 'white',
+                },
+            },
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Invalid pseudo or at-rule.")]
+fn disallow_unknown_at_rules() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            card: {
+                color: {
+                    default: 'black',
+                    '@layer base': 'white',
+                },
+            },
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
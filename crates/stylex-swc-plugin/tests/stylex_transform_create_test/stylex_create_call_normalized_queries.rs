@@ -0,0 +1,103 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_equivalent_media_queries_to_the_same_class_name,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            foo: {
+                backgroundColor: {
+                    default: 'red',
+                    '@media (min-width: 768px) and (max-width: 1024px)': 'blue',
+                },
+            },
+            bar: {
+                backgroundColor: {
+                    default: 'red',
+                    '@media (MAX-WIDTH:1024px)  and (min-width:768px)': 'blue',
+                },
+            },
+            baz: {
+                color: {
+                    default: 'black',
+                    '@media (768px <= width)': 'white',
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      legacy_media_query_ranges: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_media_query_ranges_to_legacy_syntax,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            foo: {
+                backgroundColor: {
+                    default: 'red',
+                    '@media (768px <= width <= 1024px)': 'blue',
+                    '@media (width >= 1025px)': 'green',
+                },
+            },
+        });
+    "#
+);
+
+#[test]
+#[should_panic(expected = r#"Invalid media query "@media (min-width: 768px) or screen"."#)]
+fn disallow_invalid_media_queries() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            foo: {
+                backgroundColor: {
+                    default: 'red',
+                    '@media (min-width: 768px) or screen': 'blue',
+                },
+            },
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
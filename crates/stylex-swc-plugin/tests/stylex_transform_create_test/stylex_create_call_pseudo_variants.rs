@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_pseudo_elements_with_vendor_variants,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            foo: {
+                '::selection': {
+                    backgroundColor: 'yellow',
+                },
+                '::track': {
+                    height: 4,
+                },
+                '::scrollbar': {
+                    width: 8,
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  transforms_combined_pseudo_variants,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            foo: {
+                '::backdrop': {
+                    backgroundColor: {
+                        default: 'black',
+                        ':fullscreen': 'gray',
+                    },
+                },
+                '::thumb': {
+                    width: {
+                        default: 16,
+                        ':hover': 20,
+                    },
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      pseudo_variants: Some(HashMap::from([
+        (
+          "::file-selector-button".to_string(),
+          vec![
+            "::file-selector-button".to_string(),
+            "::-webkit-file-upload-button".to_string(),
+          ],
+        ),
+        (
+          "::placeholder".to_string(),
+          vec!["::placeholder".to_string()],
+        ),
+      ])),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_configured_pseudo_variants,
+  r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            foo: {
+                '::file-selector-button': {
+                    color: 'blue',
+                },
+                '::placeholder': {
+                    color: 'gray',
+                },
+            },
+        });
+    "#
+);
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptionsParams, TemplateTagBehavior},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      css_template_tags: Some(vec!["css".to_string()]),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_style_object_from_css_tagged_template,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        default: css`
+          background-color: red;
+          padding-top: ${4 * 2}px;
+        `
+      });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_style_value_from_string_raw_tagged_template,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        default: {
+          content: String.raw`"\2014"`,
+        }
+      });
+    "#
+);
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn does_not_take_over_imported_bindings_named_like_css_tags() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          runtime_injection: Some(true),
+          css_template_tags: Some(vec!["css".to_string()]),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      import { css } from 'styled-components';
+      const styles = stylex.create({
+        default: css`
+          background-color: red;
+        `
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = "Only static values are allowed inside of a stylex.create() call.")]
+fn does_not_take_over_local_bindings_named_like_known_tags() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          runtime_injection: Some(true),
+          known_template_tags: Some(HashMap::from([(
+            "px".to_string(),
+            TemplateTagBehavior::Suffix("px".to_string()),
+          )])),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      const px = (strings) => strings.join('rem');
+      const styles = stylex.create({
+        default: {
+          width: px`${4}`,
+        }
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
@@ -1 +1,3 @@
 mod stylex_transform_define_vars;
+mod stylex_transform_define_vars_design_tokens;
+mod stylex_transform_define_vars_typed_options;
@@ -656,6 +656,42 @@ test!(
   "#
 );
 
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/stylex/packages/utils/NestedTheme.stylex.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      dev: Some(true),
+      unstable_module_resolution: Some(StyleXOptions::get_common_js_module_resolution(Some(
+        ROOT_DIR.to_string()
+      ))),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_variables_object_with_extended_stylex_types,
+  r#"
+    import stylex from 'stylex';
+    export const layoutTheme = stylex.defineVars({
+      areaName: stylex.types.customIdent('main'),
+      label: stylex.types.string('"Menu"'),
+      gridTemplate: stylex.types.any('[full-start] 1fr [full-end]'),
+      gaps: stylex.types.syntax('<length>+', '4px 8px'),
+      shadows: stylex.types.syntax('<color>#', {
+        default: 'red, blue',
+        '@media (prefers-color-scheme: dark)': 'white, black',
+      }),
+      inset: stylex.types.syntax('<length-percentage> | auto', 'auto'),
+    });
+  "#
+);
+
 test!(
   Syntax::Typescript(TsSyntax {
     tsx: true,
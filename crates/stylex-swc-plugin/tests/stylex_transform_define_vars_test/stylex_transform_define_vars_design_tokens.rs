@@ -0,0 +1,68 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+fn get_default_opts() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    design_tokens: Some(true),
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+fn get_plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &get_plugin_pass(),
+    Some(&mut get_default_opts())
+  ),
+  emits_design_tokens_of_defined_vars,
+  r#"
+    import stylex from 'stylex';
+    export const colors = stylex.defineVars({
+      primary: stylex.types.color({
+        default: 'blue',
+        '@media (prefers-color-scheme: dark)': 'lightblue',
+      }),
+      accent: {
+        default: 'purple',
+        '@media (prefers-color-scheme: dark)': {
+          default: 'violet',
+          '@supports (color: oklab(0 0 0))': 'oklab(0.7 -0.3 -0.4)',
+        },
+      },
+      radius: stylex.types.length('4px'),
+      opacity: stylex.types.number('0.5'),
+      fontFamily: 'system-ui',
+      '--literal': 'red',
+    });
+    export const motion = stylex.defineVars({
+      duration: stylex.types.time({
+        default: '200ms',
+        '@media (prefers-reduced-motion: reduce)': '0s',
+      }),
+    });
+  "#
+);
@@ -0,0 +1,142 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::{test, test_transform},
+  },
+};
+
+fn get_default_opts() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    dev: Some(true),
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+fn get_plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &get_plugin_pass(),
+    Some(&mut get_default_opts())
+  ),
+  typed_variables_with_property_options,
+  r#"
+    import stylex from 'stylex';
+    export const vars = stylex.defineVars({
+      color: stylex.types.color('red', { inherits: false }),
+      radius: stylex.types.length(
+        {
+          default: '4px',
+          '@media (min-width: 800px)': '8px',
+        },
+        { initialValue: '0px' }
+      ),
+      gaps: stylex.types.syntax('<length>+', '4px 8px', {
+        inherits: false,
+        initialValue: '0px',
+      }),
+      rotation: stylex.types.angle('45deg', { register: false }),
+    });
+  "#
+);
+
+#[test]
+#[should_panic(
+  expected = r#"initialValue of a typed variable must match its syntax and cannot reference other variables: "blue" is not a valid initial value for "<length>"."#
+)]
+fn initial_value_must_match_syntax() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_plugin_pass(),
+        Some(&mut get_default_opts()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      export const vars = stylex.defineVars({
+        radius: stylex.types.length('4px', { initialValue: 'blue' }),
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"initialValue of a typed variable must match its syntax and cannot reference other variables: "var(--size)" is not a valid initial value for "<length>"."#
+)]
+fn initial_value_cannot_reference_variables() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_plugin_pass(),
+        Some(&mut get_default_opts()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      export const vars = stylex.defineVars({
+        radius: stylex.types.length('4px', { initialValue: 'var(--size)' }),
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid option for a typed variable: "inherits" must be a boolean."#)]
+fn inherits_must_be_a_boolean() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_plugin_pass(),
+        Some(&mut get_default_opts()),
+      )
+    },
+    r#"
+      import stylex from 'stylex';
+      export const vars = stylex.defineVars({
+        color: stylex.types.color('red', { inherits: 'no' }),
+      });
+    "#,
+    r#""#,
+    false,
+  )
+}
@@ -1 +1,2 @@
 mod stylex_create_theme_call;
+mod stylex_create_theme_from_base_theme;
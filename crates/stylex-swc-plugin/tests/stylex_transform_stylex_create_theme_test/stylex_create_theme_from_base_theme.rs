@@ -0,0 +1,123 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+fn get_root_dir() -> String {
+  format!(
+    "{}/tests/stylex_transform_stylex_create_theme_test/themes",
+    env!("CARGO_MANIFEST_DIR")
+  )
+}
+
+fn get_plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(format!("{}/component.js", get_root_dir()).into()),
+  }
+}
+
+fn get_options() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(Some(
+      get_root_dir(),
+    ))),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  stylex_create_theme_from_base_theme_in_same_file,
+  r#"
+        import stylex from 'stylex';
+        const vars = {
+            __themeName__: 'x568ih9',
+            bgColor: 'var(--xgck17p)',
+            textColor: 'var(--xpegid5)',
+        };
+        export const brand = stylex.createTheme(vars, {
+            bgColor: 'ivory',
+            textColor: 'navy',
+        });
+        export const brandDark = stylex.createTheme(vars, brand, {
+            bgColor: {
+                default: 'black',
+                '@media (prefers-contrast: more)': 'darkslategray',
+            },
+        });
+        export const brandDarker = stylex.createTheme(vars, brandDark, {
+            textColor: 'white',
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  stylex_create_theme_from_base_theme_dedupes_identical_themes,
+  r#"
+        import stylex from 'stylex';
+        const vars = {
+            __themeName__: 'x568ih9',
+            bgColor: 'var(--xgck17p)',
+            textColor: 'var(--xpegid5)',
+        };
+        export const brand = stylex.createTheme(vars, {
+            bgColor: 'ivory',
+        });
+        export const derived = stylex.createTheme(vars, brand, {
+            textColor: 'navy',
+        });
+        export const flat = stylex.createTheme(vars, {
+            bgColor: 'ivory',
+            textColor: 'navy',
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &get_plugin_pass(),
+    Some(&mut get_options())
+  ),
+  stylex_create_theme_from_base_theme_in_theme_file,
+  r#"
+        import stylex from 'stylex';
+        import { colors, brand } from 'tokens.stylex';
+        export const brandDark = stylex.createTheme(colors, brand, {
+            bgColor: 'black',
+            textColor: 'white',
+        });
+    "#
+);
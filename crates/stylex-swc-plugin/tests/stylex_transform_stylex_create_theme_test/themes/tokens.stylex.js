@@ -0,0 +1,15 @@
+import stylex from 'stylex';
+
+export const colors = stylex.defineVars({
+  bgColor: 'white',
+  textColor: 'black',
+  accentColor: 'blue',
+});
+
+export const brand = stylex.createTheme(colors, {
+  bgColor: 'ivory',
+  accentColor: {
+    default: 'orange',
+    '@media (prefers-color-scheme: dark)': 'gold',
+  },
+});
@@ -1 +1,2 @@
 mod stylex_keyframes_call;
+mod stylex_keyframes_variants;
@@ -0,0 +1,129 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  converts_keyframes_variants_to_css,
+  r#"
+        import stylex from 'stylex';
+        export const fadeIn = stylex.keyframes({
+            from: {
+                opacity: 0,
+                transform: 'translateY(8px)',
+            },
+            to: {
+                opacity: 1,
+                transform: 'none',
+            },
+            '@media (prefers-reduced-motion: reduce)': {
+                from: {
+                    opacity: 0,
+                },
+                to: {
+                    opacity: 1,
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  animation_name_picks_keyframes_variant,
+  r#"
+        import stylex from 'stylex';
+        const fadeIn = stylex.keyframes({
+            from: {
+                opacity: 0,
+                transform: 'translateY(8px)',
+            },
+            to: {
+                opacity: 1,
+                transform: 'none',
+            },
+            '@media (prefers-reduced-motion: reduce)': {
+                from: {
+                    opacity: 0,
+                },
+                to: {
+                    opacity: 1,
+                },
+            },
+        });
+        const spin = stylex.keyframes({
+            from: {
+                transform: 'rotate(0deg)',
+            },
+            to: {
+                transform: 'rotate(360deg)',
+            },
+        });
+        export const styles = stylex.create({
+            root: {
+                animationName: fadeIn,
+                animationDuration: '1s',
+            },
+            hovered: {
+                animationName: {
+                    default: spin,
+                    ':hover': `${fadeIn}, ${spin}`,
+                },
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  inline_keyframes_pick_keyframes_variant,
+  r#"
+        import stylex from 'stylex';
+        export const styles = stylex.create({
+            root: {
+                animationName: stylex.keyframes({
+                    from: {
+                        transform: 'scale(0)',
+                    },
+                    to: {
+                        transform: 'scale(1)',
+                    },
+                    '@media (prefers-reduced-motion: reduce)': {
+                        from: {
+                            opacity: 0,
+                        },
+                        to: {
+                            opacity: 1,
+                        },
+                    },
+                }),
+            },
+        });
+    "#
+);
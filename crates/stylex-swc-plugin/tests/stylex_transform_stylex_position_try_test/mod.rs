@@ -0,0 +1 @@
+mod stylex_position_try_call;
@@ -0,0 +1,87 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleResolution, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  converts_position_try_to_css,
+  r#"
+        import stylex from 'stylex';
+        export const bottomFallback = stylex.positionTry({
+            positionAnchor: '--anchor',
+            top: 'anchor(bottom)',
+            insetInlineStart: 'anchor(start)',
+            width: 200,
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| {
+    let mut config = StyleXOptionsParams {
+      runtime_injection: Some(true),
+      style_resolution: Some(StyleResolution::LegacyExpandShorthands),
+      ..StyleXOptionsParams::default()
+    };
+
+    ModuleTransformVisitor::new_test_force_runtime_injection(
+      tr.comments.clone(),
+      &PluginPass::default(),
+      Some(&mut config),
+    )
+  },
+  generates_rtl_position_try_for_logical_properties,
+  r#"
+        import stylex from 'stylex';
+        export const startFallback = stylex.positionTry({
+            start: 'anchor(end)',
+            marginStart: 8,
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  converts_named_import_position_try_used_in_create,
+  r#"
+        import { positionTry, create } from 'stylex';
+        const topFallback = positionTry({
+            positionArea: 'top',
+            marginBottom: 4,
+        });
+        export const styles = create({
+            popover: {
+                positionAnchor: '--anchor',
+                positionTryFallbacks: topFallback,
+            },
+        });
+    "#
+);
@@ -0,0 +1,18 @@
+import stylex from '@stylexjs/stylex';
+
+const styles = stylex.create({
+  base: {
+    color: 'red',
+  },
+  highlighted: {
+    backgroundColor: 'yellow',
+  },
+});
+
+export const otherStyles = stylex.create({
+  bold: {
+    fontWeight: 'bold',
+  },
+});
+
+export { styles as sharedStyles };
@@ -4,4 +4,5 @@ mod setting_custom_import_paths;
 mod specific_edge_case_bugs;
 mod stylex_props_call;
 mod with_conditional_styles_and_collisions;
+mod with_imported_styles;
 mod with_plugin_options;
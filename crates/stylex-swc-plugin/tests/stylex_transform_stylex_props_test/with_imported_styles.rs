@@ -0,0 +1,91 @@
+use stylex_swc_plugin::{
+  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+fn get_plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(
+      format!(
+        "{}/tests/stylex_transform_stylex_props_test/imported_styles/component.js",
+        env!("CARGO_MANIFEST_DIR")
+      )
+      .into(),
+    ),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &get_plugin_pass(),
+    Some(&mut StyleXOptionsParams {
+      resolve_imported_styles: Some(true),
+      gen_conditional_classes: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  precompiles_props_with_imported_styles,
+  r#"
+        import stylex from '@stylexjs/stylex';
+        import { sharedStyles, otherStyles as other } from './shared_styles';
+
+        const styles = stylex.create({
+            local: {
+                padding: 4,
+            },
+        });
+
+        stylex.props(styles.local, sharedStyles.base, other.bold);
+        stylex.props(sharedStyles.base, isActive && sharedStyles['highlighted']);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(tr.comments.clone(), &get_plugin_pass(), None),
+  keeps_runtime_props_with_imported_styles_by_default,
+  r#"
+        import stylex from '@stylexjs/stylex';
+        import { sharedStyles } from './shared_styles';
+
+        stylex.props(sharedStyles.base);
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &get_plugin_pass(),
+    Some(&mut StyleXOptionsParams {
+      resolve_imported_styles: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  keeps_runtime_props_with_unresolvable_imports,
+  r#"
+        import stylex from '@stylexjs/stylex';
+        import { sharedStyles } from './missing_styles';
+
+        stylex.props(sharedStyles.base);
+    "#
+);
@@ -0,0 +1 @@
+mod stylex_view_transition_class_call;
@@ -0,0 +1,96 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test,
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  converts_view_transition_class_to_css,
+  r#"
+        import stylex from 'stylex';
+        export const cardTransition = stylex.viewTransitionClass({
+            group: {
+                transitionProperty: 'none',
+            },
+            imagePair: {
+                borderRadius: 16,
+            },
+            old: {
+                animationDuration: '0.5s',
+            },
+            new: {
+                animationTimingFunction: 'ease-out',
+                animationDuration: '0.5s',
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  converts_named_import_view_transition_class_to_css,
+  r#"
+        import { viewTransitionClass, keyframes, create } from 'stylex';
+        const fadeOut = keyframes({
+            from: {
+                opacity: 1,
+            },
+            to: {
+                opacity: 0,
+            },
+        });
+        const fade = viewTransitionClass({
+            old: {
+                animationName: fadeOut,
+            },
+        });
+        export const styles = create({
+            card: {
+                viewTransitionClass: fade,
+                viewTransitionName: 'card',
+            },
+        });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    None
+  ),
+  generates_rtl_rules_for_logical_view_transition_class_values,
+  r#"
+        import stylex from 'stylex';
+        export const transitionClass = stylex.viewTransitionClass({
+            group: {
+                marginStart: '10px',
+                transitionProperty: 'none',
+            },
+            new: {
+                float: 'start',
+            },
+        });
+    "#
+);
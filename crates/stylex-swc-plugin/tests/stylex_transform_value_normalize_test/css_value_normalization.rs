@@ -1,5 +1,8 @@
 use stylex_swc_plugin::{
-  shared::structures::{plugin_pass::PluginPass, stylex_options::StyleXOptionsParams},
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{ImportantPolicy, StyleXOptionsParams},
+  },
   ModuleTransformVisitor,
 };
 use swc_core::ecma::{
@@ -309,6 +312,7 @@ test!(
     Some(&mut StyleXOptionsParams {
       runtime_injection: Some(true),
       use_rem_for_font_size: Some(true),
+      important_policy: Some(ImportantPolicy::Allow),
       ..StyleXOptionsParams::default()
     })
   ),
@@ -1,2 +1,4 @@
 mod css_value_normalization;
 mod transform_font_size_with;
+mod transform_important;
+mod transform_px_to_rem;
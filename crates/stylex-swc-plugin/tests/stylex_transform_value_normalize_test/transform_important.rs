@@ -0,0 +1,107 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{ImportantPolicy, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      important_policy: Some(ImportantPolicy::Allow),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  emits_important_declarations_when_allowed,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        normal: {
+          color: 'red',
+          marginInlineStart: 10,
+        },
+        important: {
+          color: 'red !important',
+          marginInlineStart: '10px!IMPORTANT',
+          display: ['flex ! important', 'grid !important'],
+          backgroundColor: {
+            default: 'blue !important',
+            ':hover': 'green !important',
+          },
+        },
+      });
+    "#
+);
+
+#[test]
+#[should_panic(expected = r#"!important is not allowed in "color: red !important"."#)]
+fn rejects_important_declarations_by_default() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            x: {
+                color: 'red !important',
+            },
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"!important must be set on every fallback value or on none of them in "display: [flex, grid !important]"."#
+)]
+fn rejects_important_on_some_fallback_values() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          important_policy: Some(ImportantPolicy::Allow),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        const styles = stylex.create({
+            x: {
+                display: ['flex', 'grid !important'],
+            },
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
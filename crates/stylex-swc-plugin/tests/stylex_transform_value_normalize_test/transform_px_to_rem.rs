@@ -0,0 +1,145 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{PxToRemOptions, StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::test,
+  },
+};
+
+fn px_to_rem_options() -> PxToRemOptions {
+  PxToRemOptions {
+    properties: vec![
+      "padding".to_string(),
+      "marginTop".to_string(),
+      "line-height".to_string(),
+    ],
+    media_queries: true,
+    define_vars: true,
+    root_font_size: 10.0,
+    precision: Some(3),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      px_to_rem: Some(px_to_rem_options()),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_configured_properties_from_px_to_rem,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        foo: {
+          padding: '8px 12px',
+          marginTop: 13,
+          lineHeight: '24px',
+          width: '100px',
+        },
+        bar: {
+          padding: 'calc(100% - 16px)',
+          marginTop: 0,
+          lineHeight: 1.5,
+        }
+      });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      px_to_rem: Some(px_to_rem_options()),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_media_query_conditions_from_px_to_rem,
+  r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({
+        foo: {
+          width: {
+            default: '100%',
+            '@media (min-width: 768px)': '50%',
+          },
+        },
+      });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      px_to_rem: Some(px_to_rem_options()),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_keyframes_values_from_px_to_rem,
+  r#"
+      import stylex from 'stylex';
+      const slide = stylex.keyframes({
+        from: { marginTop: '20px' },
+        to: { marginTop: '40px' },
+      });
+    "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass {
+      cwd: None,
+      filename: FileName::Real("/stylex/packages/vars.stylex.js".into()),
+    },
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+      px_to_rem: Some(px_to_rem_options()),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  transforms_define_vars_values_from_px_to_rem,
+  r#"
+      import stylex from 'stylex';
+      export const spacing = stylex.defineVars({
+        small: '4px',
+        medium: {
+          default: '8px',
+          '@media (min-width: 768px)': '16px',
+        },
+        ratio: '1.5',
+      });
+    "#
+);
@@ -1 +1,2 @@
 mod stylex_validation_create_theme;
+mod stylex_validation_create_theme_typed_overrides;
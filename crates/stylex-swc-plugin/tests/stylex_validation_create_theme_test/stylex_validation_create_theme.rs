@@ -1,9 +1,32 @@
-use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
-use swc_core::ecma::{
-  parser::{Syntax, TsSyntax},
-  transforms::testing::{test, test_transform},
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::{test, test_transform},
+  },
 };
 
+fn get_themes_dir() -> String {
+  format!(
+    "{}/tests/stylex_validation_create_theme_test/themes",
+    env!("CARGO_MANIFEST_DIR")
+  )
+}
+
+fn get_imported_vars_plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(format!("{}/component.js", get_themes_dir()).into()),
+  }
+}
+
 #[test]
 #[should_panic(expected = "stylex.create calls must be bound to a bare variable.")]
 fn must_be_bound_to_a_variable() {
@@ -261,3 +284,273 @@ fn values_must_be_static_number_or_string_in_stylex_create_theme_fn() {
     false,
   )
 }
+
+#[test]
+#[should_panic(
+  expected = r#"Unknown theme variable "labelColour" in "vars". Did you mean "labelColor"?"#
+)]
+fn unknown_override_key_suggests_closest_key() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const vars = {__themeName__: 'x568ih9', labelColor: 'var(--labelColorHash)'};
+            const theme = stylex.createTheme(vars, {labelColour: 'red'});
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Unknown theme variable "spacing" in "vars"."#)]
+fn unknown_override_key() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const vars = {__themeName__: 'x568ih9', labelColor: 'var(--labelColorHash)'};
+            const theme = stylex.createTheme(vars, {spacing: '4px'});
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Theme does not override every variable of "vars". Missing: "bgColor", "fgColor"."#
+)]
+fn strict_themes_report_missing_keys() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut StyleXOptionsParams {
+          runtime_injection: Some(true),
+          strict_themes: Some(true),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const vars = {
+                __themeName__: 'x568ih9',
+                bgColor: 'var(--bgColorHash)',
+                fgColor: 'var(--fgColorHash)',
+                labelColor: 'var(--labelColorHash)',
+            };
+            const theme = stylex.createTheme(vars, {labelColor: 'red'});
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Unknown theme variable "radiuss" in "vars". Did you mean "radius"?"#)]
+fn unknown_override_key_of_imported_vars() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_imported_vars_plugin_pass(),
+        Some(&mut StyleXOptionsParams {
+          runtime_injection: Some(true),
+          unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(Some(
+            get_themes_dir(),
+          ))),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            import { vars } from 'typed_tokens.stylex';
+            const theme = stylex.createTheme(vars, {radiuss: '8px'});
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Theme does not override every variable of "vars". Missing: "radius", "untyped"."#
+)]
+fn strict_themes_report_missing_keys_of_imported_vars() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_imported_vars_plugin_pass(),
+        Some(&mut StyleXOptionsParams {
+          runtime_injection: Some(true),
+          strict_themes: Some(true),
+          unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(Some(
+            get_themes_dir(),
+          ))),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            import { vars } from 'typed_tokens.stylex';
+            const theme = stylex.createTheme(vars, {color: 'white'});
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Theme overrides cannot be checked, as the module that defines the variables cannot be resolved "vars"."#
+)]
+fn strict_themes_reject_unresolved_imported_vars() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_imported_vars_plugin_pass(),
+        Some(&mut StyleXOptionsParams {
+          runtime_injection: Some(true),
+          strict_themes: Some(true),
+          unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(Some(
+            get_themes_dir(),
+          ))),
+          ..StyleXOptionsParams::default()
+        }),
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            import { vars } from 'missing_tokens.stylex';
+            const theme = stylex.createTheme(vars, {colr: 'white'});
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      runtime_injection: Some(true),
+      strict_themes: Some(true),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  strict_themes_allow_complete_overrides,
+  r#"
+        import stylex from 'stylex';
+        const vars = {
+            __themeName__: 'x568ih9',
+            bgColor: 'var(--bgColorHash)',
+            labelColor: 'var(--labelColorHash)',
+        };
+        const theme = stylex.createTheme(vars, {bgColor: 'white', labelColor: 'red'});
+    "#
+);
+
+#[test]
+#[should_panic(
+  expected = "Base theme must be a stylex.createTheme() result that can be resolved at compile time"
+)]
+fn base_theme_must_be_resolvable() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            import { brand } from './themes';
+            const vars = {__themeName__: 'x568ih9', bgColor: 'var(--xgck17p)'};
+            const variables = stylex.createTheme(vars, brand, {bgColor: 'black'});
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Base theme must override the same variables as the theme created from it"
+)]
+fn base_theme_must_override_same_variables() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const colors = {__themeName__: 'x568ih9', bgColor: 'var(--xgck17p)'};
+            const spacing = {__themeName__: 'x1xohuxq', small: 'var(--x1c4kd2u)'};
+            const compact = stylex.createTheme(spacing, {small: '2px'});
+            const variables = stylex.createTheme(colors, compact, {bgColor: 'black'});
+        "#,
+    r#""#,
+    false,
+  )
+}
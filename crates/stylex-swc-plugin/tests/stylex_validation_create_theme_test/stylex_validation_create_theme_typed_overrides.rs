@@ -0,0 +1,200 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{StyleXOptions, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::{
+  common::FileName,
+  ecma::{
+    parser::{Syntax, TsSyntax},
+    transforms::testing::{test, test_transform},
+  },
+};
+
+fn get_default_opts() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    runtime_injection: Some(true),
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(None)),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+fn get_plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real("/stylex/packages/TestTheme.stylex.js".into()),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &get_plugin_pass(),
+    Some(&mut get_default_opts())
+  ),
+  overrides_matching_the_typed_variables,
+  r#"
+        import stylex from 'stylex';
+        export const vars = stylex.defineVars({
+            color: stylex.types.color('red'),
+            radius: stylex.types.length('4px'),
+            rotation: stylex.types.angle('0deg'),
+            untyped: 'blue',
+        });
+        export const theme = stylex.createTheme(vars, {
+            color: {
+                default: 'rgb(0 0 0)',
+                '@media (prefers-color-scheme: dark)': 'white',
+            },
+            radius: 'calc(2px + 1rem)',
+            rotation: 'var(--rotation)',
+            untyped: '12px',
+        });
+    "#
+);
+
+#[test]
+#[should_panic(
+  expected = r#"Theme override value does not match the type of the variable: "color" expects <color>, got "12px"."#
+)]
+fn color_variable_overridden_with_length() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_plugin_pass(),
+        Some(&mut get_default_opts()),
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        export const vars = stylex.defineVars({
+            color: stylex.types.color('red'),
+        });
+        export const theme = stylex.createTheme(vars, {
+            color: '12px',
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Theme override value does not match the type of the variable: "radius" under "@media (min-width: 800px)" expects <length>, got "red"."#
+)]
+fn conditional_override_with_invalid_value() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_plugin_pass(),
+        Some(&mut get_default_opts()),
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        export const vars = stylex.defineVars({
+            radius: stylex.types.length('4px'),
+        });
+        export const theme = stylex.createTheme(vars, {
+            radius: {
+                default: '8px',
+                '@media (min-width: 800px)': 'red',
+            },
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
+
+fn get_themes_dir() -> String {
+  format!(
+    "{}/tests/stylex_validation_create_theme_test/themes",
+    env!("CARGO_MANIFEST_DIR")
+  )
+}
+
+fn get_imported_vars_opts() -> StyleXOptionsParams {
+  StyleXOptionsParams {
+    runtime_injection: Some(true),
+    unstable_module_resolution: Some(StyleXOptions::get_haste_module_resolution(Some(
+      get_themes_dir(),
+    ))),
+    ..StyleXOptionsParams::default()
+  }
+}
+
+fn get_imported_vars_plugin_pass() -> PluginPass {
+  PluginPass {
+    cwd: None,
+    filename: FileName::Real(format!("{}/component.js", get_themes_dir()).into()),
+  }
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test(
+    tr.comments.clone(),
+    &get_imported_vars_plugin_pass(),
+    Some(&mut get_imported_vars_opts())
+  ),
+  imported_overrides_matching_the_typed_variables,
+  r#"
+        import stylex from 'stylex';
+        import { vars } from 'typed_tokens.stylex';
+        export const theme = stylex.createTheme(vars, {
+            color: 'white',
+            radius: '8px',
+            untyped: '12px',
+        });
+    "#
+);
+
+#[test]
+#[should_panic(
+  expected = r#"Theme override value does not match the type of the variable: "radius" expects <length>, got "red"."#
+)]
+fn imported_variable_overridden_with_invalid_value() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test(
+        tr.comments.clone(),
+        &get_imported_vars_plugin_pass(),
+        Some(&mut get_imported_vars_opts()),
+      )
+    },
+    r#"
+        import stylex from 'stylex';
+        import { vars } from 'typed_tokens.stylex';
+        export const theme = stylex.createTheme(vars, {
+            radius: 'red',
+        });
+    "#,
+    r#""#,
+    false,
+  )
+}
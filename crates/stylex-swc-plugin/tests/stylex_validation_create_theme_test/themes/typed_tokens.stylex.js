@@ -0,0 +1,7 @@
+import stylex from 'stylex';
+
+export const vars = stylex.defineVars({
+  color: stylex.types.color('red'),
+  radius: stylex.types.length('4px'),
+  untyped: 'blue',
+});
@@ -136,3 +136,33 @@ test!(
     });
     "#
 );
+
+#[test]
+#[should_panic(expected = "Keyframes under an at-rule must be an object of frames.")]
+fn at_rule_keyframes_must_be_an_object_of_frames() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const name = stylex.keyframes({
+                from: {opacity: 0},
+                to: {opacity: 1},
+                '@media (prefers-reduced-motion: reduce)': {
+                    from: 'none',
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
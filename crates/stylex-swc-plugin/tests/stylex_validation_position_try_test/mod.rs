@@ -0,0 +1 @@
+mod stylex_validation_position_try;
@@ -0,0 +1,88 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test_transform,
+};
+
+#[test]
+#[should_panic(expected = "stylex.positionTry() can only accept an object.")]
+fn only_argument_must_be_an_object() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const name = stylex.positionTry(null);
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "stylex.positionTry() only accepts anchor positioning, inset, margin, sizing and self-alignment properties."
+)]
+fn only_allowed_properties_are_accepted() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const name = stylex.positionTry({
+                color: 'red',
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Every value within a stylex.positionTry() call must be a string or a number."
+)]
+fn values_must_be_strings_or_numbers() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const name = stylex.positionTry({
+                top: {
+                    default: 'anchor(bottom)',
+                    ':hover': 'anchor(top)',
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
@@ -0,0 +1,160 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{PropertyValidationMode, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+fn transform_with_property_validation(input: &str, mode: PropertyValidationMode) {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      let mut config = StyleXOptionsParams {
+        property_validation: Some(mode),
+        ..StyleXOptionsParams::default()
+      };
+
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut config),
+      )
+    },
+    input,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Unknown CSS property "backgroundColour". Did you mean "backgroundColor"?"#
+)]
+fn disallow_misspelled_property() {
+  transform_with_property_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {backgroundColour: 'red'}});
+    "#,
+    PropertyValidationMode::Error,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Unknown CSS property "paddingInlineStrat". Did you mean "paddingInlineStart"?"#
+)]
+fn disallow_misspelled_logical_property() {
+  transform_with_property_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {paddingInlineStrat: 4}});
+    "#,
+    PropertyValidationMode::Error,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Unknown CSS property "fooBarBaz"."#)]
+fn disallow_unknown_property_without_suggestion() {
+  transform_with_property_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {fooBarBaz: 'red'}});
+    "#,
+    PropertyValidationMode::Error,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Unknown CSS property "colr". Did you mean "color"?"#)]
+fn disallow_unknown_property_within_pseudo_class() {
+  transform_with_property_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {':hover': {colr: 'red'}}});
+    "#,
+    PropertyValidationMode::Error,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      property_validation: Some(PropertyValidationMode::Error),
+      allowed_properties: Some(vec!["customElementProperty".to_string()]),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  allow_known_custom_and_allowlisted_properties,
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      default: {
+        backgroundColor: 'red',
+        marginStart: 4,
+        '--brand-color': 'blue',
+        customElementProperty: 'auto',
+      }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      property_validation: Some(PropertyValidationMode::Warn),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  unknown_property_is_reported_as_warning,
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({default: {backgroundColour: 'red'}});
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      property_validation: Some(PropertyValidationMode::Error),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  allow_border_block_style,
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      default: {
+        borderBlockStyle: 'solid',
+        borderBlockWidth: 1,
+      }
+    });
+  "#
+);
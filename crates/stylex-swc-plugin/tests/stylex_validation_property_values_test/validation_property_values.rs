@@ -0,0 +1,166 @@
+use stylex_swc_plugin::{
+  shared::structures::{
+    plugin_pass::PluginPass,
+    stylex_options::{PropertyValidationMode, StyleXOptionsParams},
+  },
+  ModuleTransformVisitor,
+};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::{test, test_transform},
+};
+
+fn transform_with_value_validation(input: &str) {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      let mut config = StyleXOptionsParams {
+        value_validation: Some(PropertyValidationMode::Error),
+        ..StyleXOptionsParams::default()
+      };
+
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        Some(&mut config),
+      )
+    },
+    input,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid CSS value "flexx" for "display"."#)]
+fn disallow_invalid_keyword() {
+  transform_with_value_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {display: 'flexx'}});
+    "#,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"Invalid CSS value "absolut" for "position". Expected static, relative, absolute, fixed, sticky, -webkit-sticky."#
+)]
+fn disallow_misspelled_keyword() {
+  transform_with_value_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {position: 'absolut'}});
+    "#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid CSS value "red" for "width"."#)]
+fn disallow_value_of_wrong_type() {
+  transform_with_value_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {width: 'red'}});
+    "#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid CSS value "10px" for "backgroundColor"."#)]
+fn disallow_length_for_color() {
+  transform_with_value_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {backgroundColor: '10px'}});
+    "#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid CSS value "5px" for "margin"."#)]
+fn report_offending_component() {
+  transform_with_value_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {margin: '1px 2px 3px 4px 5px'}});
+    "#,
+  )
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid CSS value "2px" for "transitionDelay"."#)]
+fn disallow_invalid_value_within_pseudo_class() {
+  transform_with_value_validation(
+    r#"
+      import stylex from 'stylex';
+      const styles = stylex.create({default: {transitionDelay: {default: '1s', ':hover': '2px'}}});
+    "#,
+  )
+}
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      value_validation: Some(PropertyValidationMode::Error),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  allow_valid_dynamic_and_global_values,
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      default: {
+        display: 'inline flex',
+        position: 'inherit',
+        width: 'calc(100% - 10px)',
+        height: 'var(--height)',
+        margin: '0 auto',
+        color: 'rebeccapurple',
+        backgroundColor: 'color-mix(in srgb, red, blue)',
+        transitionDuration: '1s, 200ms',
+        zIndex: 10,
+        opacity: 0.5,
+        borderRadius: '4px / 8px',
+        fontWeight: 'bold',
+      }
+    });
+  "#
+);
+
+test!(
+  Syntax::Typescript(TsSyntax {
+    tsx: true,
+    ..Default::default()
+  }),
+  |tr| ModuleTransformVisitor::new_test_force_runtime_injection(
+    tr.comments.clone(),
+    &PluginPass::default(),
+    Some(&mut StyleXOptionsParams {
+      value_validation: Some(PropertyValidationMode::Error),
+      ..StyleXOptionsParams::default()
+    })
+  ),
+  allow_system_colors,
+  r#"
+    import stylex from 'stylex';
+    const styles = stylex.create({
+      default: {
+        color: 'CanvasText',
+        backgroundColor: 'Canvas',
+        borderColor: 'ButtonBorder',
+        outlineColor: 'Highlight',
+        accentColor: 'AccentColor',
+      }
+    });
+  "#
+);
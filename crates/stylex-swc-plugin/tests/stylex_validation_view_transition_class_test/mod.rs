@@ -0,0 +1 @@
+mod stylex_validation_view_transition_class;
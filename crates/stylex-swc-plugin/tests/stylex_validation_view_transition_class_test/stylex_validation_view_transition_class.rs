@@ -0,0 +1,87 @@
+use stylex_swc_plugin::{shared::structures::plugin_pass::PluginPass, ModuleTransformVisitor};
+use swc_core::ecma::{
+  parser::{Syntax, TsSyntax},
+  transforms::testing::test_transform,
+};
+
+#[test]
+#[should_panic(expected = "stylex.viewTransitionClass() can only accept an object.")]
+fn only_argument_must_be_an_object() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const name = stylex.viewTransitionClass(null);
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = r#"stylex.viewTransitionClass() only accepts the "group", "imagePair", "old" and "new" keys."#
+)]
+fn only_view_transition_keys_are_allowed() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const name = stylex.viewTransitionClass({
+                image: {
+                    borderRadius: 16,
+                },
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}
+
+#[test]
+#[should_panic(
+  expected = "Every value within a stylex.viewTransitionClass() call must be an object of styles."
+)]
+fn values_must_be_objects() {
+  test_transform(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    |tr| {
+      ModuleTransformVisitor::new_test_force_runtime_injection(
+        tr.comments.clone(),
+        &PluginPass::default(),
+        None,
+      )
+    },
+    r#"
+            import stylex from 'stylex';
+            const name = stylex.viewTransitionClass({
+                old: 'none',
+            });
+        "#,
+    r#""#,
+    false,
+  )
+}